@@ -0,0 +1,132 @@
+//! Newtypes to switch code written against [`str::parse`] and [`core::fmt`] over to this crate.
+use core::{fmt, str::FromStr};
+
+use crate::{
+    constants::MAX_INT_LEN,
+    error::ParseIntErr,
+    from_ascii::FromAscii,
+    into_ascii::IntoAscii,
+};
+
+/// A wrapper that parses with [`FromAscii`] through [`FromStr`], and formats with [`IntoAscii`] through [`Display`](core::fmt::Display).
+///
+/// Code that calls `parse::<T>()` switches to this crate by calling `parse::<Ascii<T>>()` instead.
+/// Like [`FromAscii::atoi`], unsigned integers wrap around on overflow.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     ascii::Ascii,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let Ascii(port) = "8080".parse::<Ascii<u16>>().unwrap();
+///     assert_eq!(port, 8080);
+///
+///     assert_eq!("-12".parse(), Ok(Ascii(-12i32)));
+///     assert_eq!("1x".parse::<Ascii<i32>>(), Err(ParseIntErr::with_byte_at(b'x', 1)));
+///
+///     assert_eq!(Ascii(-12i32).to_string(), "-12");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ascii<T>(pub T);
+
+impl<T: FromAscii> FromStr for Ascii<T> {
+    type Err = ParseIntErr;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseIntErr> {
+        T::bytes_to_int(s.as_bytes()).map(Ascii)
+    }
+}
+
+impl<T: IntoAscii + Copy> fmt::Display for Ascii<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&AsciiDisplay(self.0), f)
+    }
+}
+
+impl<T> From<T> for Ascii<T> {
+    #[inline]
+    fn from(n: T) -> Self {
+        Ascii(n)
+    }
+}
+
+/// A wrapper that formats with [`IntoAscii`] through [`fmt::Display`],
+/// and respects the width, fill, alignment, `+` and `0` flags of the formatter, like the integers themselves.
+///
+/// The digits are formatted into a buffer on the stack, and padded by [`fmt::Formatter::pad_integral`].
+///
+/// # Examples
+/// ```
+/// use byte_num::ascii::AsciiDisplay;
+///
+/// fn main() {
+///     assert_eq!(format!("{}", AsciiDisplay(-42i32)), "-42");
+///     assert_eq!(format!("{:>6}", AsciiDisplay(42u8)), "    42");
+///     assert_eq!(format!("{:06}", AsciiDisplay(-42i64)), "-00042");
+///     assert_eq!(format!("{:+}", AsciiDisplay(42u16)), "+42");
+///     assert_eq!(format!("{:*^7}", AsciiDisplay(42usize)), "**42***");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AsciiDisplay<N>(pub N);
+
+impl<N: IntoAscii + Copy> fmt::Display for AsciiDisplay<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut digits = [0; MAX_INT_LEN];
+
+        let len = match self.0.try_int_to_bytes(&mut digits) {
+            Ok(len) => len,
+            Err(_) => unreachable!("integer longer than {} bytes", MAX_INT_LEN),
+        };
+
+        let (is_nonnegative, digits) = match &digits[..len] {
+            [b'-', digits @ ..] => (false, digits),
+            digits => (true, digits),
+        };
+
+        // Safety: the digits are all ASCII.
+        f.pad_integral(is_nonnegative, "", unsafe {
+            core::str::from_utf8_unchecked(digits)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ascii;
+    use crate::error::ParseIntErr;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("0".parse(), Ok(Ascii(0u8)));
+        assert_eq!("-128".parse(), Ok(Ascii(i8::MIN)));
+        assert_eq!("128".parse::<Ascii<i8>>(), Err(ParseIntErr::PosOverflow));
+        assert_eq!("".parse::<Ascii<u64>>(), Err(ParseIntErr::Empty));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display() {
+        assert_eq!(Ascii(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(format!("[{}]", Ascii(i64::MIN)), format!("[{}]", i64::MIN));
+        assert_eq!(format!("{:<5}|", Ascii(7u8)), "7    |");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn padding() {
+        use super::AsciiDisplay;
+
+        for n in [0i32, 7, -7, i32::MIN, i32::MAX] {
+            assert_eq!(format!("{:+012}", AsciiDisplay(n)), format!("{:+012}", n));
+            assert_eq!(format!("{:_<9}", AsciiDisplay(n)), format!("{:_<9}", n));
+            assert_eq!(format!("{:>3}", AsciiDisplay(n)), format!("{:>3}", n));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Reading numbers from the asynchronous readers of [`tokio`], as a [`Stream`].
+//!
+//! This module is only available with the `tokio` feature.
+use core::{
+    convert::TryFrom,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::io;
+
+use futures_core::Stream;
+use tokio::io::AsyncBufRead;
+
+use crate::{
+    error::ParseIntErr,
+    io::{invalid_data, separator_set, WHITESPACE},
+    stream::StreamParser,
+};
+
+/// Reads numbers separated by any of a set of separator bytes from an [`AsyncBufRead`],
+/// the asynchronous version of [`crate::io::NumberReader`].
+///
+/// The numbers are parsed straight from the buffer of the reader, with a [`StreamParser`]
+/// putting together the numbers that are split over two fills of that buffer.
+///
+/// # Examples
+/// ```
+/// use byte_num::async_io::AsyncNumberReader;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let mut numbers = AsyncNumberReader::<_, u32>::new(&b"1 2\n3\n"[..]);
+///     let mut sum = 0;
+///
+///     while let Some(n) = numbers.next_number().await {
+///         sum += n.unwrap();
+///     }
+///
+///     assert_eq!(sum, 6);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsyncNumberReader<R, T> {
+    reader: R,
+    separators: [bool; 256],
+    parser: StreamParser<T>,
+    in_number: bool,
+    result: Result<(), ParseIntErr>,
+}
+
+impl<R: AsyncBufRead + Unpin, T: TryFrom<i128>> AsyncNumberReader<R, T> {
+    /// Creates a reader for numbers separated by ASCII whitespace.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self::with_separators(reader, WHITESPACE)
+    }
+
+    /// Creates a reader for numbers separated by any of the bytes in `separators`.
+    #[inline]
+    pub fn with_separators(reader: R, separators: &[u8]) -> Self {
+        Self {
+            reader,
+            separators: separator_set(separators),
+            parser: StreamParser::new(),
+            in_number: false,
+            result: Ok(()),
+        }
+    }
+
+    /// Returns the next number, or `None` at the end of the input.
+    #[inline]
+    pub async fn next_number(&mut self) -> Option<io::Result<T>> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Ends the current number.
+    #[inline(always)]
+    fn finish(&mut self) -> io::Result<T> {
+        self.in_number = false;
+
+        self.result.and_then(|()| self.parser.finish()).map_err(invalid_data)
+    }
+}
+
+impl<R: AsyncBufRead + Unpin, T: TryFrom<i128>> Stream for AsyncNumberReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let buf = match Pin::new(&mut this.reader).poll_fill_buf(cx) {
+                Poll::Ready(Ok(buf)) => buf,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(err))),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if buf.is_empty() {
+                return Poll::Ready(if this.in_number { Some(this.finish()) } else { None });
+            }
+
+            let separators = &this.separators;
+
+            if !this.in_number {
+                let start = buf.iter().position(|b| !separators[*b as usize]);
+                let skip = start.unwrap_or(buf.len());
+
+                if start.is_some() {
+                    this.in_number = true;
+                    this.parser.reset();
+                    this.result = Ok(());
+                }
+
+                Pin::new(&mut this.reader).consume(skip);
+                continue;
+            }
+
+            let end = buf.iter().position(|b| separators[*b as usize]).unwrap_or(buf.len());
+            let done = end < buf.len();
+
+            // After an error, the rest of the number is skipped.
+            if this.result.is_ok() {
+                this.result = this.parser.feed(&buf[..end]);
+            }
+
+            Pin::new(&mut this.reader).consume(end);
+
+            if done {
+                return Poll::Ready(Some(this.finish()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsyncNumberReader;
+    use crate::error::ParseIntErr;
+    use tokio::io::BufReader;
+
+    #[tokio::test]
+    async fn numbers() {
+        // A tiny buffer splits most numbers over several fills.
+        let input = BufReader::with_capacity(3, &b" 123456,-42,,x1,300,7"[..]);
+        let mut numbers = AsyncNumberReader::<_, i32>::with_separators(input, b", ");
+        let mut results = std::vec::Vec::new();
+
+        while let Some(result) = numbers.next_number().await {
+            results.push(result.map_err(|err| *err.get_ref().unwrap().downcast_ref::<ParseIntErr>().unwrap()));
+        }
+
+        assert_eq!(
+            results,
+            [Ok(123_456), Ok(-42), Err(ParseIntErr::with_byte_at(b'x', 0)), Ok(300), Ok(7)]
+        );
+        assert!(numbers.next_number().await.is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! Parsing numbers from a [`Buf`], and formatting them into a [`BufMut`], of the [`bytes`] crate.
+//!
+//! This module is only available with the `bytes` feature.
+use core::convert::TryFrom;
+
+use bytes::{Buf, BufMut};
+
+use crate::{constants::MAX_INT_LEN, error::ParseIntErr, into_ascii::IntoAscii, stream::StreamParser};
+
+/// Parses all remaining bytes of `buf` as a decimal number, advancing it to the end.
+///
+/// The bytes may be spread over any number of chunks, like in a chain of buffers.
+/// Numbers are converted exactly, see [`StreamParser`], and error positions count from the start of `buf`.
+///
+/// # Examples
+/// ```
+/// use bytes::Buf;
+/// use byte_num::{
+///     buf::atoi_buf,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let chain = (&b"-12"[..]).chain(&b"34"[..]);
+///     assert_eq!(atoi_buf::<i32>(chain), Ok(-1234));
+///
+///     let chain = (&b"12"[..]).chain(&b"3x"[..]);
+///     assert_eq!(atoi_buf::<i32>(chain), Err(ParseIntErr::with_byte_at(b'x', 3)));
+/// }
+/// ```
+#[inline]
+pub fn atoi_buf<T: TryFrom<i128>>(mut buf: impl Buf) -> Result<T, ParseIntErr> {
+    let mut parser = StreamParser::new();
+
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+
+        parser.feed(chunk)?;
+        buf.advance(len);
+    }
+
+    parser.finish()
+}
+
+/// Appends `n` in decimal to `buf`.
+///
+/// The digits are formatted on the stack, and copied into `buf` in one go.
+///
+/// # Panics
+/// Like [`BufMut::put_slice`], this panics if `buf` doesn't have room for the digits.
+///
+/// # Examples
+/// ```
+/// use bytes::BytesMut;
+/// use byte_num::buf::put_int;
+///
+/// fn main() {
+///     let mut buf = BytesMut::new();
+///
+///     put_int(&mut buf, -42i32);
+///     put_int(&mut buf, 7u8);
+///
+///     assert_eq!(&buf[..], b"-427");
+/// }
+/// ```
+#[inline]
+pub fn put_int<N: IntoAscii + Copy>(buf: &mut impl BufMut, n: N) {
+    let mut digits = [0; MAX_INT_LEN];
+
+    match n.try_int_to_bytes(&mut digits) {
+        Ok(len) => buf.put_slice(&digits[..len]),
+        Err(_) => unreachable!("integer longer than {} bytes", MAX_INT_LEN),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{atoi_buf, put_int};
+    use crate::error::ParseIntErr;
+    use bytes::{Buf, BytesMut};
+
+    #[test]
+    fn from_buf() {
+        let chain = (&b"1844674407"[..]).chain(&b""[..]).chain(&b"3709551615"[..]);
+        assert_eq!(atoi_buf::<u64>(chain), Ok(u64::MAX));
+
+        let mut buf = &b"255"[..];
+        assert_eq!(atoi_buf::<u8>(&mut buf), Ok(255));
+        assert!(buf.is_empty());
+
+        assert_eq!(atoi_buf::<u8>(&b""[..]), Err(ParseIntErr::Empty));
+        assert_eq!(atoi_buf::<u8>(&b"256"[..]), Err(ParseIntErr::PosOverflow));
+        assert_eq!(atoi_buf::<i8>((&b"-"[..]).chain(&b"-1"[..])), Err(ParseIntErr::with_byte_at(b'-', 1)));
+    }
+
+    #[test]
+    fn into_buf_mut() {
+        let mut buf = BytesMut::new();
+
+        for n in [i64::MIN, -1, 0, 1, i64::MAX].iter() {
+            put_int(&mut buf, *n);
+            buf.extend_from_slice(b",");
+        }
+        put_int(&mut buf, u64::MAX);
+
+        assert_eq!(
+            &buf[..],
+            &b"-9223372036854775808,-1,0,1,9223372036854775807,18446744073709551615"[..]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Parsing of hexadecimal color codes, like the `#RRGGBB` colors of CSS.
+use crate::{constants::DIGIT_VALUES, error::ParseIntErr};
+
+/// Parses a color code of 3, 4, 6 or 8 hexadecimal digits, with an optional `#` in front,
+/// into its red, green, blue and alpha channels.
+///
+/// The forms are `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA`. In the short forms every digit is repeated,
+/// so `f80` is the same as `ff8800`. Without an alpha channel, the color is fully opaque.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     color,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(color::parse_hex_color(b"#ff8800"), Ok([0xff, 0x88, 0x00, 0xff]));
+///     assert_eq!(color::parse_hex_color(b"f808"), Ok([0xff, 0x88, 0x00, 0x88]));
+///     assert_eq!(color::parse_hex_color(b"#ff88zz"), Err(ParseIntErr::with_byte_at(b'z', 5)));
+/// }
+/// ```
+#[inline]
+pub fn parse_hex_color(bytes: &[u8]) -> Result<[u8; 4], ParseIntErr> {
+    let offset = (bytes.first() == Some(&b'#')) as usize;
+    let digits = &bytes[offset..];
+
+    let mut nibbles = [0xF; 8];
+    let mut invalid = false;
+
+    for (nibble, &byte) in nibbles.iter_mut().zip(digits) {
+        *nibble = DIGIT_VALUES[byte as usize];
+        invalid |= *nibble >= 16;
+    }
+
+    if invalid || !matches!(digits.len(), 3 | 4 | 6 | 8) {
+        return Err(color_error(bytes, offset));
+    }
+
+    let [r, g, b, a, ..] = nibbles;
+
+    Ok(match digits.len() {
+        3 | 4 => [r * 17, g * 17, b * 17, a * 17],
+        _ => {
+            let [r1, r2, g1, g2, b1, b2, a1, a2] = nibbles;
+            [r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, a1 << 4 | a2]
+        }
+    })
+}
+
+/// Like [`parse_hex_color`], but returns the color packed as `0xRRGGBBAA`.
+///
+/// # Examples
+/// ```
+/// use byte_num::color;
+///
+/// fn main() {
+///     assert_eq!(color::parse_hex_color_u32(b"#ff8800"), Ok(0xff88_00ff));
+///     assert_eq!(color::parse_hex_color_u32(b"#0000"), Ok(0));
+/// }
+/// ```
+#[inline]
+pub fn parse_hex_color_u32(bytes: &[u8]) -> Result<u32, ParseIntErr> {
+    parse_hex_color(bytes).map(u32::from_be_bytes)
+}
+
+/// Finds the first byte that isn't a hexadecimal digit. Only if there is none, the length is reported.
+#[cold]
+fn color_error(bytes: &[u8], offset: usize) -> ParseIntErr {
+    ParseIntErr::first_misfit(bytes, offset + 8, |idx, byte| idx < offset || DIGIT_VALUES[byte as usize] < 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hex_color, parse_hex_color_u32, ParseIntErr};
+
+    #[test]
+    fn forms() {
+        assert_eq!(parse_hex_color(b"#abc"), Ok([0xaa, 0xbb, 0xcc, 0xff]));
+        assert_eq!(parse_hex_color(b"ABCD"), Ok([0xaa, 0xbb, 0xcc, 0xdd]));
+        assert_eq!(parse_hex_color(b"#0a1B2c"), Ok([0x0a, 0x1b, 0x2c, 0xff]));
+        assert_eq!(parse_hex_color(b"0a1b2c3d"), Ok([0x0a, 0x1b, 0x2c, 0x3d]));
+        assert_eq!(parse_hex_color_u32(b"#FFFFFFFF"), Ok(u32::MAX));
+        assert_eq!(parse_hex_color_u32(b"123"), Ok(0x1122_33ff));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse_hex_color(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_hex_color(b"#"), Err(ParseIntErr::with_byte_at(b'#', 0)));
+        assert_eq!(parse_hex_color(b"#ab"), Err(ParseIntErr::with_byte_at(b'b', 2)));
+        assert_eq!(parse_hex_color(b"abcde"), Err(ParseIntErr::with_byte_at(b'e', 4)));
+        assert_eq!(parse_hex_color(b"#abcdef0"), Err(ParseIntErr::with_byte_at(b'0', 7)));
+        assert_eq!(parse_hex_color(b"#abcdef012"), Err(ParseIntErr::with_byte_at(b'2', 9)));
+        assert_eq!(parse_hex_color(b"##abc"), Err(ParseIntErr::with_byte_at(b'#', 1)));
+        assert_eq!(parse_hex_color(b"#abg"), Err(ParseIntErr::with_byte_at(b'g', 3)));
+        assert_eq!(parse_hex_color(b"0xabcdef"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(parse_hex_color(b"#abcdef012g"), Err(ParseIntErr::with_byte_at(b'g', 10)));
+    }
+}
@@ -0,0 +1,148 @@
+//! Parsing whole columns of numbers at once, for columnar engines in the style of Apache Arrow.
+//!
+//! Instead of stopping at the first error, [`parse_column`] parses every value,
+//! and marks the empty and invalid ones in a validity [`Bitmap`].
+use crate::from_ascii_radix::FromAsciiRadix;
+
+/// A validity bitmap, with a set bit for every valid value.
+///
+/// The bits are packed least significant bit first, in the layout of Apache Arrow:
+/// the validity of value `i` is bit `i % 8` of byte `i / 8`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bitmap {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl Bitmap {
+    /// Returns the number of values in the bitmap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the bitmap has no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether value `idx` is valid.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn is_valid(&self, idx: usize) -> bool {
+        assert!(
+            idx < self.len,
+            "index {} out of bounds for a bitmap of length {}",
+            idx,
+            self.len
+        );
+
+        self.bytes[idx / 8] & (1 << (idx % 8)) != 0
+    }
+
+    /// Returns the number of values that are not valid.
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        // The padding bits of the last byte are never set.
+        self.len
+            - self
+                .bytes
+                .iter()
+                .map(|b| b.count_ones() as usize)
+                .sum::<usize>()
+    }
+
+    /// Returns the packed bits. The padding bits of the last byte are 0.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Parses every value of `values` as a decimal integer, exactly, like [`FromAsciiRadix::bytes_to_int_radix`].
+///
+/// Empty values, and values that fail to parse, are `T::default()` in the returned values,
+/// and marked as not valid in the returned bitmap.
+/// The values are parsed 8 at a time, filling one byte of the bitmap without branching on the results.
+///
+/// # Examples
+/// ```
+/// use byte_num::column::parse_column;
+///
+/// fn main() {
+///     let (values, validity) = parse_column::<i32>(&[b"12", b"", b"-7", b"x", b"2147483648"]);
+///
+///     assert_eq!(values, [12, 0, -7, 0, 0]);
+///     assert_eq!(validity.as_bytes(), [0b00101]);
+///     assert_eq!(validity.null_count(), 3);
+///     assert!(!validity.is_valid(1));
+/// }
+/// ```
+pub fn parse_column<T: FromAsciiRadix + Default>(values: &[&[u8]]) -> (Vec<T>, Bitmap) {
+    let mut parsed = Vec::with_capacity(values.len());
+    let mut bytes = Vec::with_capacity(values.len().div_ceil(8));
+
+    for rows in values.chunks(8) {
+        let mut byte = 0;
+
+        for (bit, value) in rows.iter().enumerate() {
+            let result = T::bytes_to_int_radix(value, 10);
+
+            byte |= (result.is_ok() as u8) << bit;
+            parsed.push(result.unwrap_or_default());
+        }
+
+        bytes.push(byte);
+    }
+
+    let bitmap = Bitmap {
+        bytes,
+        len: values.len(),
+    };
+
+    (parsed, bitmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_column;
+
+    #[test]
+    fn column() {
+        let values = (0..20u32)
+            .map(|n| n.to_string().into_bytes())
+            .collect::<Vec<_>>();
+        let mut values = values.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        values[3] = b"";
+        values[9] = b"+";
+        values[19] = b"300";
+
+        let (parsed, validity) = parse_column::<u8>(&values);
+
+        assert_eq!(validity.len(), 20);
+        assert_eq!(validity.as_bytes(), [0b1111_0111, 0b1111_1101, 0b0111]);
+        assert_eq!(validity.null_count(), 3);
+        assert_eq!(parsed[..5], [0, 1, 2, 0, 4]);
+        assert_eq!(parsed[19], 0);
+        assert!(validity.is_valid(18));
+    }
+
+    #[test]
+    fn empty_column() {
+        let (parsed, validity) = parse_column::<i64>(&[]);
+
+        assert!(parsed.is_empty());
+        assert!(validity.is_empty());
+        assert_eq!(validity.null_count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let (_, validity) = parse_column::<i64>(&[b"1"]);
+        validity.is_valid(1);
+    }
+}
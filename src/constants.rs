@@ -1,2 +1,78 @@
 /// The value for converting from ascii to a digit
-pub(crate) const ASCII_TO_INT_FACTOR: u8 = 48;
\ No newline at end of file
+pub(crate) const ASCII_TO_INT_FACTOR: u8 = 48;
+
+/// A 1 in every byte of a u64, for working on 8 bytes at once.
+pub(crate) const ONES: u64 = 0x0101_0101_0101_0101;
+
+/// The high bit of every byte of a u64.
+pub(crate) const HIGH: u64 = ONES * 0x80;
+
+/// Room for any integer, with its sign, when formatting on the stack.
+pub(crate) const MAX_INT_LEN: usize = 40;
+
+/// The two digits of every number below 100, from `00` to `99`, for formatting two digits at a time.
+/// The digits of `n` are at `2 * n` and `2 * n + 1`.
+pub(crate) const DIGIT_PAIRS: [u8; 200] = {
+    let mut table = [0; 200];
+    let mut n = 0;
+
+    while n < 100 {
+        table[2 * n] = b'0' + (n / 10) as u8;
+        table[2 * n + 1] = b'0' + (n % 10) as u8;
+        n += 1;
+    }
+
+    table
+};
+
+/// The digits used for formatting in bases up to 36, indexed by their value.
+#[cfg(feature = "std")]
+pub(crate) const DIGITS_LOWER: [u8; 36] = *b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Like [`DIGITS_LOWER`], but with uppercase letters.
+#[cfg(feature = "std")]
+pub(crate) const DIGITS_UPPER: [u8; 36] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Marks a byte that is not a digit in any base, in [`DIGIT_VALUES`].
+pub(crate) const INVALID_DIGIT: u8 = u8::MAX;
+
+/// Maps each byte to its value as a digit in bases up to 36 (`0-9`, then `a-z` or `A-Z`),
+/// or to [`INVALID_DIGIT`] if it isn't one.
+pub(crate) const DIGIT_VALUES: [u8; 256] = {
+    let mut table = [INVALID_DIGIT; 256];
+    let mut idx = 0;
+
+    while idx < 36 {
+        let value = idx as u8;
+
+        if idx < 10 {
+            table[(b'0' + value) as usize] = value;
+        } else {
+            table[(b'a' + value - 10) as usize] = value;
+            table[(b'A' + value - 10) as usize] = value;
+        }
+
+        idx += 1;
+    }
+
+    table
+};
+
+/// The digits used for base 62, indexed by their value.
+#[cfg(feature = "std")]
+pub(crate) const DIGITS_62: [u8; 62] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Maps each byte to its value as a digit in base 62, or to [`INVALID_DIGIT`] if it isn't one.
+/// Unlike [`DIGIT_VALUES`], uppercase and lowercase letters are different digits.
+#[cfg(feature = "std")]
+pub(crate) const DIGIT_VALUES_62: [u8; 256] = {
+    let mut table = [INVALID_DIGIT; 256];
+    let mut idx = 0;
+
+    while idx < 62 {
+        table[DIGITS_62[idx] as usize] = idx as u8;
+        idx += 1;
+    }
+
+    table
+};
@@ -0,0 +1,216 @@
+//! Extracting and parsing numeric columns of CSV records.
+//!
+//! Fields may be quoted, and a delimiter inside quotes doesn't end the field.
+//! The quotes aren't part of the extracted field, but a doubled quote inside one stays doubled,
+//! since no number contains a quote anyway.
+//! A trailing `\n` or `\r\n` of the record is ignored.
+use crate::{
+    error::{ParseFieldErr, ParseIntErr},
+    from_ascii_radix::FromAsciiRadix,
+};
+
+/// The content of a field, and the position of that content in the field.
+type Field<'a> = (&'a [u8], usize);
+
+/// Splits the first field off `bytes`, returning it and everything after its delimiter.
+#[inline]
+fn next_field(bytes: &[u8], delimiter: u8) -> Result<(Field<'_>, Option<&[u8]>), ParseIntErr> {
+    if bytes.first() != Some(&b'"') {
+        return Ok(match bytes.iter().position(|&b| b == delimiter) {
+            Some(end) => ((&bytes[..end], 0), Some(&bytes[end + 1..])),
+            None => ((bytes, 0), None),
+        });
+    }
+
+    let mut idx = 1;
+    loop {
+        match bytes.get(idx) {
+            Some(b'"') if bytes.get(idx + 1) == Some(&b'"') => idx += 2,
+            Some(b'"') => break,
+            Some(_) => idx += 1,
+            // An unterminated quote, reported at the quote that opened it.
+            None => return Err(ParseIntErr::with_byte_at(b'"', 0)),
+        }
+    }
+
+    let content = (&bytes[1..idx], 1);
+
+    match bytes.get(idx + 1) {
+        None => Ok((content, None)),
+        Some(&b) if b == delimiter => Ok((content, Some(&bytes[idx + 2..]))),
+        Some(&b) => Err(ParseIntErr::with_byte_at(b, idx + 1)),
+    }
+}
+
+#[inline]
+fn find_field(record: &[u8], column: usize, delimiter: u8) -> Result<Field<'_>, ParseFieldErr> {
+    let record = record
+        .strip_suffix(b"\n")
+        .map_or(record, |r| r.strip_suffix(b"\r").unwrap_or(r));
+    let mut rest = Some(record);
+
+    for field in 0..=column {
+        let bytes = rest.ok_or(ParseFieldErr {
+            field,
+            error: ParseIntErr::Empty,
+        })?;
+
+        let (content, next) =
+            next_field(bytes, delimiter).map_err(|error| ParseFieldErr { field, error })?;
+
+        if field == column {
+            return Ok(content);
+        }
+
+        rest = next;
+    }
+
+    unreachable!()
+}
+
+/// Returns field `column` of `record`, counting from 0, without its quotes.
+///
+/// If the record has fewer fields, the error is [`ParseIntErr::Empty`].
+/// A quote that isn't closed is an invalid digit at position 0 of its field,
+/// and so is a byte between a closing quote and the delimiter, at its own position.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     csv,
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let record = b"id,\"name, first\",\"42\"\r\n";
+///
+///     assert_eq!(csv::field(record, 1, b','), Ok(&b"name, first"[..]));
+///     assert_eq!(csv::field(record, 2, b','), Ok(&b"42"[..]));
+///     assert_eq!(csv::field(record, 3, b','), Err(ParseFieldErr { field: 3, error: ParseIntErr::Empty }));
+///     assert_eq!(
+///         csv::field(b"1,\"2", 1, b','),
+///         Err(ParseFieldErr { field: 1, error: ParseIntErr::with_byte_at(b'"', 0) })
+///     );
+/// }
+/// ```
+#[inline]
+pub fn field(record: &[u8], column: usize, delimiter: u8) -> Result<&[u8], ParseFieldErr> {
+    find_field(record, column, delimiter).map(|(content, _)| content)
+}
+
+/// Parses field `column` of `record` as a decimal integer, returning `None` if the field is empty,
+/// or an empty pair of quotes.
+///
+/// Unlike [`FromAscii::atoi`](crate::from_ascii::FromAscii::atoi), this never wraps around.
+/// Error positions count from the start of the field, opening quote included.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     csv,
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let record = b"2024,\"-17\",,\"\",1x\n";
+///
+///     assert_eq!(csv::parse_field::<u16>(record, 0, b','), Ok(Some(2024)));
+///     assert_eq!(csv::parse_field::<i8>(record, 1, b','), Ok(Some(-17)));
+///     assert_eq!(csv::parse_field::<i8>(record, 2, b','), Ok(None));
+///     assert_eq!(csv::parse_field::<i8>(record, 3, b','), Ok(None));
+///     assert_eq!(
+///         csv::parse_field::<i8>(record, 4, b','),
+///         Err(ParseFieldErr { field: 4, error: ParseIntErr::with_byte_at(b'x', 1) })
+///     );
+/// }
+/// ```
+#[inline]
+pub fn parse_field<T: FromAsciiRadix>(
+    record: &[u8],
+    column: usize,
+    delimiter: u8,
+) -> Result<Option<T>, ParseFieldErr> {
+    let (content, offset) = find_field(record, column, delimiter)?;
+
+    if content.is_empty() {
+        return Ok(None);
+    }
+
+    T::bytes_to_int_radix(content, 10)
+        .map(Some)
+        .map_err(|error| ParseFieldErr {
+            field: column,
+            error: match error {
+                ParseIntErr::InvalidDigit([c], position) => {
+                    ParseIntErr::with_byte_at(c, position + offset)
+                }
+                error => error,
+            },
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field, parse_field};
+    use crate::error::{ParseFieldErr, ParseIntErr};
+
+    #[test]
+    fn quoted_fields() {
+        let record = b"\"a,\"\"b\"\"\";\"1;2\";3";
+
+        assert_eq!(
+            field(record, 0, b','),
+            Err(ParseFieldErr {
+                field: 0,
+                error: ParseIntErr::with_byte_at(b';', 9)
+            })
+        );
+        assert_eq!(field(record, 0, b';'), Ok(&b"a,\"\"b\"\""[..]));
+        assert_eq!(field(record, 1, b';'), Ok(&b"1;2"[..]));
+        assert_eq!(field(record, 2, b';'), Ok(&b"3"[..]));
+        assert_eq!(
+            field(b"\"1\"2,3", 1, b','),
+            Err(ParseFieldErr {
+                field: 0,
+                error: ParseIntErr::with_byte_at(b'2', 3)
+            })
+        );
+    }
+
+    #[test]
+    fn empty_fields() {
+        assert_eq!(field(b"", 0, b','), Ok(&b""[..]));
+        assert_eq!(field(b",", 1, b','), Ok(&b""[..]));
+        assert_eq!(field(b"\r\n", 0, b','), Ok(&b""[..]));
+        assert_eq!(parse_field::<u8>(b",\n", 1, b','), Ok(None));
+        assert_eq!(
+            parse_field::<u8>(b",\n", 2, b','),
+            Err(ParseFieldErr {
+                field: 2,
+                error: ParseIntErr::Empty
+            })
+        );
+    }
+
+    #[test]
+    fn parse_fields() {
+        assert_eq!(
+            parse_field::<u64>(b"x,\"18446744073709551615\"", 1, b','),
+            Ok(Some(u64::MAX))
+        );
+        assert_eq!(
+            parse_field::<u8>(b"x,\"256\"", 1, b','),
+            Err(ParseFieldErr {
+                field: 1,
+                error: ParseIntErr::PosOverflow
+            })
+        );
+        assert_eq!(
+            parse_field::<u32>(b"\"1\"\"2\"", 0, b','),
+            Err(ParseFieldErr {
+                field: 0,
+                error: ParseIntErr::with_byte_at(b'"', 2)
+            })
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Reading integers from environment variables.
+use std::{env, ffi::OsStr};
+
+use crate::{error::EnvErr, from_ascii_radix::FromAsciiRadix};
+
+/// Reads the environment variable `key`, and converts it in base 10 with [`FromAsciiRadix`].
+///
+/// The conversion is exact, so a value out of range for `T` is an overflow error, instead of wrapping around.
+///
+/// On unix, the value is parsed straight from its bytes. Elsewhere, a value that isn't valid unicode
+/// can't be a number either, and reports the first replacement character as the invalid digit.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     env,
+///     error::{EnvErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     std::env::set_var("BYTE_NUM_EXAMPLE_PORT", "8080");
+///     assert_eq!(env::get::<u16>("BYTE_NUM_EXAMPLE_PORT"), Ok(8080));
+///
+///     std::env::set_var("BYTE_NUM_EXAMPLE_PORT", "80x");
+///     assert_eq!(
+///         env::get::<u16>("BYTE_NUM_EXAMPLE_PORT"),
+///         Err(EnvErr::Parse("BYTE_NUM_EXAMPLE_PORT".into(), ParseIntErr::with_byte_at(b'x', 2)))
+///     );
+/// }
+/// ```
+pub fn get<T: FromAsciiRadix>(key: impl AsRef<OsStr>) -> Result<T, EnvErr> {
+    let key = key.as_ref();
+    let name = || key.to_string_lossy().into_owned();
+
+    let value = env::var_os(key).ok_or_else(|| EnvErr::NotPresent(name()))?;
+
+    parse_os_str(&value).map_err(|error| EnvErr::Parse(name(), error))
+}
+
+#[cfg(unix)]
+fn parse_os_str<T: FromAsciiRadix>(value: &OsStr) -> Result<T, crate::error::ParseIntErr> {
+    use std::os::unix::ffi::OsStrExt;
+
+    T::bytes_to_int_radix(value.as_bytes(), 10)
+}
+
+#[cfg(not(unix))]
+fn parse_os_str<T: FromAsciiRadix>(value: &OsStr) -> Result<T, crate::error::ParseIntErr> {
+    T::bytes_to_int_radix(value.to_string_lossy().as_bytes(), 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get;
+    use crate::error::{EnvErr, ParseIntErr};
+
+    #[test]
+    fn env() {
+        std::env::set_var("BYTE_NUM_TEST_THREADS", "-12");
+        std::env::remove_var("BYTE_NUM_TEST_UNSET");
+
+        assert_eq!(get::<i32>("BYTE_NUM_TEST_THREADS"), Ok(-12));
+        assert_eq!(
+            get::<i32>("BYTE_NUM_TEST_UNSET"),
+            Err(EnvErr::NotPresent("BYTE_NUM_TEST_UNSET".into()))
+        );
+        assert_eq!(
+            get::<u32>("BYTE_NUM_TEST_THREADS").unwrap_err().to_string(),
+            "environment variable `BYTE_NUM_TEST_THREADS` is not a valid number: ParseIntErr::NegOverflow"
+        );
+
+        std::env::set_var("BYTE_NUM_TEST_PORT", "65536");
+        assert_eq!(
+            get::<u16>("BYTE_NUM_TEST_PORT"),
+            Err(EnvErr::Parse("BYTE_NUM_TEST_PORT".into(), ParseIntErr::PosOverflow))
+        );
+
+        std::env::set_var("BYTE_NUM_TEST_EMPTY", "");
+        assert_eq!(
+            get::<i32>("BYTE_NUM_TEST_EMPTY"),
+            Err(EnvErr::Parse("BYTE_NUM_TEST_EMPTY".into(), ParseIntErr::Empty))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn not_unicode() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        std::env::set_var("BYTE_NUM_TEST_BYTES", OsStr::from_bytes(b"12\xff"));
+        assert_eq!(
+            get::<i32>("BYTE_NUM_TEST_BYTES"),
+            Err(EnvErr::Parse("BYTE_NUM_TEST_BYTES".into(), ParseIntErr::with_byte_at(0xff, 2)))
+        );
+    }
+}
@@ -1,20 +1,33 @@
-use std::{error::Error, fmt, str};
+use core::{error::Error, fmt};
 
 /// An enum representing the possible Errors encountered while parsing a slice of bytes to an integer.
+/// The variants map onto [`core::num::IntErrorKind`], see [`ParseIntErr::kind`].
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum ParseIntErr {
-    /// Represents a character that could not be converted to a number.
-    InvalidDigit([u8; 1]),
+    /// Represents an empty slice.
+    Empty,
 
-    /// Represents that parsing of the slice could not be started, the slice was too large.
-    Overflow,
+    /// Represents a character that could not be converted to a number,
+    /// together with its index within the input.
+    InvalidDigit([u8; 1], usize),
+
+    /// Represents a number that is too large to fit in the target type.
+    PosOverflow,
+
+    /// Represents a negative number that is too small to fit in the target type,
+    /// for example any negative number for an unsigned type.
+    NegOverflow,
 }
 
 impl fmt::Display for ParseIntErr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ParseIntErr::InvalidDigit([ref c]) => write!(f, "ParseIntErr::InvalidDigit({})", c),
-            ParseIntErr::Overflow => f.pad("ParseIntErr::Overflow"),
+            ParseIntErr::Empty => f.pad("ParseIntErr::Empty"),
+            ParseIntErr::InvalidDigit([ref c], ref position) => {
+                write!(f, "ParseIntErr::InvalidDigit({}, {})", c, position)
+            }
+            ParseIntErr::PosOverflow => f.pad("ParseIntErr::PosOverflow"),
+            ParseIntErr::NegOverflow => f.pad("ParseIntErr::NegOverflow"),
         }
     }
 }
@@ -22,14 +35,275 @@ impl fmt::Display for ParseIntErr {
 impl Error for ParseIntErr {
     fn description(&self) -> &str {
         match *self {
-            ParseIntErr::InvalidDigit(ref c) => str::from_utf8(c).unwrap(),
-            ParseIntErr::Overflow => "number too large to fit in the target type",
+            ParseIntErr::Empty => "cannot parse integer from empty slice",
+            ParseIntErr::InvalidDigit(..) => "invalid digit found in slice",
+            ParseIntErr::PosOverflow => "number too large to fit in the target type",
+            ParseIntErr::NegOverflow => "number too small to fit in the target type",
+        }
+    }
+}
+
+/// The kind of a [`ParseIntErr`], mirroring [`core::num::IntErrorKind`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum IntErrorKind {
+    /// The input was empty.
+    Empty,
+
+    /// The input contained a byte that is not a digit.
+    InvalidDigit,
+
+    /// The number is too large to fit in the target type.
+    PosOverflow,
+
+    /// The number is too small to fit in the target type.
+    NegOverflow,
+}
+
+impl From<IntErrorKind> for core::num::IntErrorKind {
+    fn from(kind: IntErrorKind) -> Self {
+        match kind {
+            IntErrorKind::Empty => core::num::IntErrorKind::Empty,
+            IntErrorKind::InvalidDigit => core::num::IntErrorKind::InvalidDigit,
+            IntErrorKind::PosOverflow => core::num::IntErrorKind::PosOverflow,
+            IntErrorKind::NegOverflow => core::num::IntErrorKind::NegOverflow,
         }
     }
 }
 
 impl ParseIntErr {
+    /// Creates an [`ParseIntErr::InvalidDigit`] for `c`, at index 0.
     pub fn with_byte(c: u8) -> Self {
-        ParseIntErr::InvalidDigit([c])
+        Self::with_byte_at(c, 0)
+    }
+
+    /// Creates an [`ParseIntErr::InvalidDigit`] for `c`, found at index `position` of the input.
+    pub fn with_byte_at(c: u8, position: usize) -> Self {
+        ParseIntErr::InvalidDigit([c], position)
+    }
+
+    /// Reports the byte at `idx` of `bytes`, or the last byte if the input ended before it.
+    #[cold]
+    pub(crate) fn at_or_last(bytes: &[u8], idx: usize) -> Self {
+        match bytes.len() {
+            0 => ParseIntErr::Empty,
+            len => Self::with_byte_at(bytes[idx.min(len - 1)], idx.min(len - 1)),
+        }
+    }
+
+    /// Reports the first byte of `bytes` that doesn't fit, and only if all of them do, the expected length `len`:
+    /// input that is too short at its last byte, and input that is too long at the first byte too many.
+    #[cold]
+    pub(crate) fn first_misfit(bytes: &[u8], len: usize, fits: impl Fn(usize, u8) -> bool) -> Self {
+        match bytes.iter().enumerate().position(|(idx, byte)| !fits(idx, *byte)) {
+            Some(idx) => Self::with_byte_at(bytes[idx], idx),
+            None => Self::at_or_last(bytes, len),
+        }
+    }
+
+    /// Returns the kind of this error, so it can be handled the same way as a [`core::num::ParseIntError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::IntErrorKind,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u8::atoi("").unwrap_err().kind(), IntErrorKind::Empty);
+    ///     assert_eq!(u8::atoi("1x").unwrap_err().kind(), IntErrorKind::InvalidDigit);
+    ///     assert_eq!(u8::atoi("1000").unwrap_err().kind(), IntErrorKind::PosOverflow);
+    ///     assert_eq!(i8::atoi("-1000").unwrap_err().kind(), IntErrorKind::NegOverflow);
+    /// }
+    /// ```
+    pub fn kind(&self) -> IntErrorKind {
+        match *self {
+            ParseIntErr::Empty => IntErrorKind::Empty,
+            ParseIntErr::InvalidDigit(..) => IntErrorKind::InvalidDigit,
+            ParseIntErr::PosOverflow => IntErrorKind::PosOverflow,
+            ParseIntErr::NegOverflow => IntErrorKind::NegOverflow,
+        }
+    }
+
+    /// Returns the index of the invalid digit within the input,
+    /// or `None` if this error is not an [`ParseIntErr::InvalidDigit`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     let err = u32::atoi("12e3").unwrap_err();
+    ///     assert_eq!(err.position(), Some(2));
+    /// }
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ParseIntErr::InvalidDigit(_, position) => Some(position),
+            _ => None,
+        }
+    }
+}
+
+/// An enum representing the possible Errors encountered while writing an integer into a slice of bytes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum FormatErr {
+    /// Represents a buffer too small to hold all bytes of the number.
+    /// Holds the number of bytes that are needed.
+    BufferTooSmall(usize),
+}
+
+impl fmt::Display for FormatErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatErr::BufferTooSmall(ref needed) => write!(f, "FormatErr::BufferTooSmall({})", needed),
+        }
+    }
+}
+
+impl Error for FormatErr {
+    fn description(&self) -> &str {
+        match *self {
+            FormatErr::BufferTooSmall(_) => "buffer too small to hold the number",
+        }
+    }
+}
+
+/// An enum representing the possible Errors encountered while parsing a slice of bytes to a float.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ParseFloatErr {
+    /// Represents an empty slice.
+    Empty,
+
+    /// Represents a byte that does not belong in a float at its position,
+    /// together with its index within the input.
+    /// If the input ended too early, for example after an exponent marker, this is its last byte.
+    Invalid([u8; 1], usize),
+}
+
+impl fmt::Display for ParseFloatErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseFloatErr::Empty => f.pad("ParseFloatErr::Empty"),
+            ParseFloatErr::Invalid([ref c], ref position) => {
+                write!(f, "ParseFloatErr::Invalid({}, {})", c, position)
+            }
+        }
+    }
+}
+
+impl Error for ParseFloatErr {
+    fn description(&self) -> &str {
+        match *self {
+            ParseFloatErr::Empty => "cannot parse float from empty slice",
+            ParseFloatErr::Invalid(..) => "invalid float literal",
+        }
+    }
+}
+
+impl ParseFloatErr {
+    /// Creates an [`ParseFloatErr::Invalid`] for `c`, found at index `position` of the input.
+    pub fn with_byte_at(c: u8, position: usize) -> Self {
+        ParseFloatErr::Invalid([c], position)
+    }
+
+    /// Reports the byte at `idx` of `bytes`, or the last byte if the input ended before it.
+    #[cold]
+    pub(crate) fn at_or_last(bytes: &[u8], idx: usize) -> Self {
+        match bytes.len() {
+            0 => ParseFloatErr::Empty,
+            len => Self::with_byte_at(bytes[idx.min(len - 1)], idx.min(len - 1)),
+        }
+    }
+
+    /// Returns the index of the invalid byte within the input,
+    /// or `None` if this error is not an [`ParseFloatErr::Invalid`].
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            ParseFloatErr::Invalid(_, position) => Some(position),
+            _ => None,
+        }
+    }
+}
+
+/// An error encountered while parsing a field of a delimited record,
+/// together with the index of that field, counting from 0.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ParseFieldErr {
+    /// The index of the field within the record.
+    pub field: usize,
+
+    /// The error within the field. Positions are relative to the start of the field.
+    /// A field that is missing altogether is reported as [`ParseIntErr::Empty`].
+    pub error: ParseIntErr,
+}
+
+impl fmt::Display for ParseFieldErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ParseFieldErr({}, {})", self.field, self.error)
+    }
+}
+
+impl Error for ParseFieldErr {
+    fn description(&self) -> &str {
+        "cannot parse integer field of record"
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error encountered while reading an integer from an environment variable, with [`crate::env::get`].
+/// Both variants hold the name of the variable.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnvErr {
+    /// Represents a variable that is not set.
+    NotPresent(String),
+
+    /// Represents a variable that is set, but not to a number.
+    Parse(String, ParseIntErr),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for EnvErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EnvErr::NotPresent(ref name) => write!(f, "environment variable `{}` is not set", name),
+            EnvErr::Parse(ref name, ref error) => {
+                write!(f, "environment variable `{}` is not a valid number: {}", name, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EnvErr {
+    fn description(&self) -> &str {
+        match *self {
+            EnvErr::NotPresent(_) => "environment variable not set",
+            EnvErr::Parse(..) => "environment variable is not a valid number",
+        }
+    }
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EnvErr::NotPresent(_) => None,
+            EnvErr::Parse(_, ref error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseIntErr;
+    use core::error::Error;
+
+    #[test]
+    #[allow(deprecated)]
+    fn description() {
+        assert_eq!(ParseIntErr::with_byte_at(b'x', 0).description(), "invalid digit found in slice");
+        assert_eq!(ParseIntErr::with_byte_at(0xff, 2).description(), "invalid digit found in slice");
     }
 }
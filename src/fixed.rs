@@ -0,0 +1,234 @@
+//! Parsers for fixed-width fields, such as the date and time components in log lines,
+//! or the Unix timestamps in time series.
+//!
+//! Every parser validates the whole field in a single pass against its layout,
+//! and then converts the components with fully unrolled 2, 4 and 8 digit conversions.
+//! The components are not range checked, so a month of `13` is returned as is.
+use core::convert::TryFrom;
+
+use crate::{constants::ASCII_TO_INT_FACTOR, error::ParseIntErr};
+
+/// Returns whether `byte` fits `expected` from a layout, where a `0` stands for any digit.
+#[inline(always)]
+fn fits(byte: u8, expected: u8) -> bool {
+    match expected {
+        b'0' => byte.is_ascii_digit(),
+        expected => byte == expected,
+    }
+}
+
+/// Checks that `bytes` matches `layout`, where a `0` in the layout stands for any digit,
+/// and any other byte must be present as is.
+#[inline(always)]
+fn check_layout<'a, const N: usize>(bytes: &'a [u8], layout: &[u8; N]) -> Result<&'a [u8; N], ParseIntErr> {
+    if let Ok(field) = <&[u8; N]>::try_from(bytes) {
+        let mut valid = true;
+
+        for idx in 0..N {
+            valid &= fits(field[idx], layout[idx]);
+        }
+
+        if valid {
+            return Ok(field);
+        }
+    }
+
+    Err(layout_error(bytes, layout))
+}
+
+/// Finds the first byte that doesn't fit `layout`. Only if all of them do, the length is reported.
+#[cold]
+fn layout_error(bytes: &[u8], layout: &[u8]) -> ParseIntErr {
+    ParseIntErr::first_misfit(bytes, layout.len(), |idx, byte| idx >= layout.len() || fits(byte, layout[idx]))
+}
+
+/// Converts the digit at `idx`, which is known to be a digit.
+#[inline(always)]
+fn digit(field: &[u8], idx: usize) -> u8 {
+    field[idx] - ASCII_TO_INT_FACTOR
+}
+
+/// Converts the 2 digits at `idx`, which are known to be digits.
+#[inline(always)]
+fn digits2(field: &[u8], idx: usize) -> u8 {
+    digit(field, idx) * 10 + digit(field, idx + 1)
+}
+
+/// Converts the 4 digits at `idx`, which are known to be digits.
+#[inline(always)]
+fn digits4(field: &[u8], idx: usize) -> u16 {
+    u16::from(digits2(field, idx)) * 100 + u16::from(digits2(field, idx + 2))
+}
+
+/// Converts the 8 digits at `idx`, which are known to be digits.
+#[inline(always)]
+fn digits8(field: &[u8], idx: usize) -> u32 {
+    u32::from(digits4(field, idx)) * 10_000 + u32::from(digits4(field, idx + 4))
+}
+
+/// Parses a date as `YYYYMMDD`, into its year, month and day.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     fixed,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_yyyymmdd(b"20240517"), Ok((2024, 5, 17)));
+///     assert_eq!(fixed::parse_yyyymmdd(b"2024O517"), Err(ParseIntErr::with_byte_at(b'O', 4)));
+/// }
+/// ```
+#[inline]
+pub fn parse_yyyymmdd(bytes: &[u8]) -> Result<(u16, u8, u8), ParseIntErr> {
+    let field = check_layout(bytes, b"00000000")?;
+
+    Ok((digits4(field, 0), digits2(field, 4), digits2(field, 6)))
+}
+
+/// Parses a date as `YYYY-MM-DD`, into its year, month and day.
+///
+/// # Examples
+/// ```
+/// use byte_num::fixed;
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_yyyy_mm_dd(b"2024-05-17"), Ok((2024, 5, 17)));
+/// }
+/// ```
+#[inline]
+pub fn parse_yyyy_mm_dd(bytes: &[u8]) -> Result<(u16, u8, u8), ParseIntErr> {
+    let field = check_layout(bytes, b"0000-00-00")?;
+
+    Ok((digits4(field, 0), digits2(field, 5), digits2(field, 8)))
+}
+
+/// Parses a time of day as `HHMMSS`, into its hours, minutes and seconds.
+///
+/// # Examples
+/// ```
+/// use byte_num::fixed;
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_hhmmss(b"235959"), Ok((23, 59, 59)));
+/// }
+/// ```
+#[inline]
+pub fn parse_hhmmss(bytes: &[u8]) -> Result<(u8, u8, u8), ParseIntErr> {
+    let field = check_layout(bytes, b"000000")?;
+
+    Ok((digits2(field, 0), digits2(field, 2), digits2(field, 4)))
+}
+
+/// Parses a time of day as `HH:MM:SS`, into its hours, minutes and seconds.
+///
+/// # Examples
+/// ```
+/// use byte_num::fixed;
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_hh_mm_ss(b"08:30:00"), Ok((8, 30, 0)));
+/// }
+/// ```
+#[inline]
+pub fn parse_hh_mm_ss(bytes: &[u8]) -> Result<(u8, u8, u8), ParseIntErr> {
+    let field = check_layout(bytes, b"00:00:00")?;
+
+    Ok((digits2(field, 0), digits2(field, 3), digits2(field, 6)))
+}
+
+/// Parses a Unix timestamp in seconds, given as exactly 10 digits.
+///
+/// This covers every timestamp from September 2001 up to November 2286.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     fixed,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_unix_secs(b"1715904000"), Ok(1_715_904_000));
+///     assert_eq!(fixed::parse_unix_secs(b"171590400"), Err(ParseIntErr::with_byte_at(b'0', 8)));
+/// }
+/// ```
+#[inline]
+pub fn parse_unix_secs(bytes: &[u8]) -> Result<u64, ParseIntErr> {
+    let field = check_layout(bytes, b"0000000000")?;
+
+    Ok(u64::from(digits8(field, 0)) * 100 + u64::from(digits2(field, 8)))
+}
+
+/// Parses a Unix timestamp in milliseconds, given as exactly 13 digits.
+///
+/// # Examples
+/// ```
+/// use byte_num::fixed;
+///
+/// fn main() {
+///     assert_eq!(fixed::parse_unix_millis(b"1715904000123"), Ok(1_715_904_000_123));
+/// }
+/// ```
+#[inline]
+pub fn parse_unix_millis(bytes: &[u8]) -> Result<u64, ParseIntErr> {
+    let field = check_layout(bytes, b"0000000000000")?;
+
+    Ok(u64::from(digits8(field, 0)) * 100_000
+        + u64::from(digits4(field, 8)) * 10
+        + u64::from(digit(field, 12)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_hh_mm_ss, parse_hhmmss, parse_unix_millis, parse_unix_secs, parse_yyyy_mm_dd, parse_yyyymmdd,
+        ParseIntErr,
+    };
+
+    #[test]
+    fn dates() {
+        assert_eq!(parse_yyyymmdd(b"00000101"), Ok((0, 1, 1)));
+        assert_eq!(parse_yyyymmdd(b"99991231"), Ok((9999, 12, 31)));
+        assert_eq!(parse_yyyy_mm_dd(b"1970-01-01"), Ok((1970, 1, 1)));
+
+        // Not range checked.
+        assert_eq!(parse_yyyymmdd(b"20241399"), Ok((2024, 13, 99)));
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(parse_hhmmss(b"000000"), Ok((0, 0, 0)));
+        assert_eq!(parse_hhmmss(b"123456"), Ok((12, 34, 56)));
+        assert_eq!(parse_hh_mm_ss(b"23:59:60"), Ok((23, 59, 60)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse_yyyymmdd(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_yyyymmdd(b"2024051"), Err(ParseIntErr::with_byte_at(b'1', 6)));
+        assert_eq!(parse_yyyymmdd(b"202405170"), Err(ParseIntErr::with_byte_at(b'0', 8)));
+        assert_eq!(parse_yyyymmdd(b"2024-05-17"), Err(ParseIntErr::with_byte_at(b'-', 4)));
+        assert_eq!(parse_yyyymmdd(b"-2024051"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(parse_yyyy_mm_dd(b"2024/05/17"), Err(ParseIntErr::with_byte_at(b'/', 4)));
+        assert_eq!(parse_yyyy_mm_dd(b"2024-0x-17"), Err(ParseIntErr::with_byte_at(b'x', 6)));
+        assert_eq!(parse_hh_mm_ss(b"12:34-56"), Err(ParseIntErr::with_byte_at(b'-', 5)));
+        assert_eq!(parse_hhmmss(b"12:345"), Err(ParseIntErr::with_byte_at(b':', 2)));
+    }
+
+    #[test]
+    fn unix_timestamps() {
+        assert_eq!(parse_unix_secs(b"0000000000"), Ok(0));
+        assert_eq!(parse_unix_secs(b"9999999999"), Ok(9_999_999_999));
+        assert_eq!(parse_unix_secs(b"1234567890"), Ok(1_234_567_890));
+        assert_eq!(parse_unix_millis(b"9999999999999"), Ok(9_999_999_999_999));
+        assert_eq!(parse_unix_millis(b"1234567890123"), Ok(1_234_567_890_123));
+
+        assert_eq!(parse_unix_secs(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_unix_secs(b"-123456789"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(parse_unix_secs(b"1234567890123"), Err(ParseIntErr::with_byte_at(b'1', 10)));
+        assert_eq!(parse_unix_millis(b"1234567890"), Err(ParseIntErr::with_byte_at(b'0', 9)));
+        assert_eq!(parse_unix_millis(b"1234567890.12"), Err(ParseIntErr::with_byte_at(b'.', 10)));
+    }
+}
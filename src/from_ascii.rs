@@ -1,6 +1,11 @@
-use std::ops::Mul;
+use core::{convert::TryFrom, ffi::CStr, ops::Mul};
 
-use crate::{constants::*, error::ParseIntErr};
+use crate::{
+    constants::*,
+    error::{ParseFieldErr, ParseIntErr},
+    from_ascii_radix::FromAsciiRadix,
+    simd,
+};
 
 /// This trait converts bytes to integers,
 /// and is implemented on all integer types, except u128 and i128.
@@ -9,9 +14,26 @@ use crate::{constants::*, error::ParseIntErr};
 /// As argument, it takes anything that implements `AsRef<[u8]>`.
 /// The return type is a [`Result`], indicating whether the convertion succeeded or failed
 pub trait FromAscii: Sized {
+    /// The largest number of digits of a value of this type, not counting leading zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     assert_eq!(u8::MAX_DIGITS, 3);
+    ///     assert_eq!(i64::MAX_DIGITS, 19);
+    ///     assert_eq!(i64::MAX_FORMATTED_LEN, 20);
+    /// }
+    /// ```
+    const MAX_DIGITS: usize;
+
+    /// The largest number of bytes of a value of this type, with its sign, not counting leading zeros.
+    const MAX_FORMATTED_LEN: usize;
+
     /// The function performing the conversion from a byteslice to a number.
     /// It takes anything that can be transformed into a byte-slice.
-    /// An empty slice returns the number 0.
+    /// An empty slice returns [`ParseIntErr::Empty`].
     ///
     /// # Examples
     /// ```
@@ -22,12 +44,12 @@ pub trait FromAscii: Sized {
     ///
     /// fn main() {
     ///     assert_eq!(u32::atoi("1928"), Ok(1928));
-    ///     assert_eq!(u32::atoi("12e3"), Err(ParseIntErr::with_byte(b'e')));
+    ///     assert_eq!(u32::atoi("12e3"), Err(ParseIntErr::with_byte_at(b'e', 2)));
     /// }
     /// ```
     /// # Safety
-    /// It should be noted that trying to convert a slice that does not fit in the chosen integer type,
-    /// wraps around.
+    /// It should be noted that trying to convert a slice that does not fit in the chosen unsigned integer type,
+    /// wraps around. Signed integer types report this as [`ParseIntErr::PosOverflow`] instead.
     /// For example:
     /// ```
     /// use byte_num::from_ascii::FromAscii;
@@ -43,6 +65,326 @@ pub trait FromAscii: Sized {
     }
 
     fn bytes_to_int(s: &[u8]) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice in scientific notation, `mantissa[eE]exponent`, to a number.
+    /// The exponent may start with a `+`, but can not be negative.
+    /// Input without an exponent is parsed as a plain number.
+    ///
+    /// Unlike [`FromAscii::atoi`], this never wraps around:
+    /// if the expanded number does not fit, [`ParseIntErr::PosOverflow`] or [`ParseIntErr::NegOverflow`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_exp("12e6"), Ok(12_000_000));
+    ///     assert_eq!(i64::atoi_exp("-5E+3"), Ok(-5_000));
+    ///     assert_eq!(u8::atoi_exp("3e2"), Err(ParseIntErr::PosOverflow));
+    ///     assert_eq!(u32::atoi_exp("1e-3"), Err(ParseIntErr::with_byte_at(b'-', 2)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_exp(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_exp(s.as_ref())
+    }
+
+    fn bytes_to_int_exp(s: &[u8]) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice to a number, skipping `separator` bytes between digits, like the `_` in `1_000_000`.
+    /// A separator anywhere else, such as at the start, at the end, next to the sign or next to another separator,
+    /// is an invalid digit.
+    ///
+    /// Unlike [`FromAscii::atoi`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_with_separators("1_000_000", b'_'), Ok(1_000_000));
+    ///     assert_eq!(i32::atoi_with_separators("-12_34", b'_'), Ok(-1234));
+    ///     assert_eq!(u32::atoi_with_separators("1__0", b'_'), Err(ParseIntErr::with_byte_at(b'_', 1)));
+    ///     assert_eq!(u32::atoi_with_separators("10_", b'_'), Err(ParseIntErr::with_byte_at(b'_', 2)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_with_separators(s: impl AsRef<[u8]>, separator: u8) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_with_separators(s.as_ref(), separator)
+    }
+
+    fn bytes_to_int_with_separators(s: &[u8], separator: u8) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice to a number, skipping thousands separators, like the `,` in `1,234,567`,
+    /// or the `.` in `1.234.567`.
+    /// The first group has 1 to 3 digits, and every group after it exactly 3.
+    /// Input without any separator is accepted as well.
+    ///
+    /// Unlike [`FromAscii::atoi`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_grouped("1,234,567", b','), Ok(1_234_567));
+    ///     assert_eq!(i32::atoi_grouped("-1.234", b'.'), Ok(-1234));
+    ///     assert_eq!(u32::atoi_grouped("1234", b','), Ok(1234));
+    ///     assert_eq!(u32::atoi_grouped("12,34", b','), Err(ParseIntErr::with_byte_at(b'4', 4)));
+    ///     assert_eq!(u32::atoi_grouped("1234,567", b','), Err(ParseIntErr::with_byte_at(b',', 4)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_grouped(s: impl AsRef<[u8]>, separator: u8) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_grouped(s.as_ref(), separator)
+    }
+
+    fn bytes_to_int_grouped(s: &[u8], separator: u8) -> Result<Self, ParseIntErr>;
+
+    /// Converts the number at the start of a byteslice, with the semantics of C's `strtol`:
+    /// leading ASCII whitespace is skipped, an optional `+` or `-` is accepted,
+    /// and as many digits as possible are consumed, stopping at the first byte that is not a digit.
+    ///
+    /// Returns the number, and how many bytes were consumed, including the whitespace and the sign.
+    /// If there are no digits, this is `(0, 0)`.
+    /// Numbers that don't fit saturate at the bounds of the type, so a negative number is 0 for unsigned types.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     assert_eq!(i32::atoi_lenient("  -42 apples"), (-42, 5));
+    ///     assert_eq!(u32::atoi_lenient("+7,8"), (7, 2));
+    ///     assert_eq!(u32::atoi_lenient("apples"), (0, 0));
+    ///     assert_eq!(u8::atoi_lenient("1000"), (255, 4));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_lenient(s: impl AsRef<[u8]>) -> (Self, usize) {
+        Self::bytes_to_int_lenient(s.as_ref())
+    }
+
+    fn bytes_to_int_lenient(s: &[u8]) -> (Self, usize);
+
+    /// Converts the number at the start of a byteslice, stopping at the first byte that is not a digit.
+    /// Returns the number, and how many bytes it took up, so parsing can continue right after it.
+    ///
+    /// The number may start with a `-`, and must have at least one digit.
+    /// Unlike [`FromAscii::atoi`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_partial("1234,5678"), Ok((1234, 4)));
+    ///     assert_eq!(i32::atoi_partial("-12"), Ok((-12, 3)));
+    ///     assert_eq!(u32::atoi_partial(",5678"), Err(ParseIntErr::with_byte_at(b',', 0)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_partial(s: impl AsRef<[u8]>) -> Result<(Self, usize), ParseIntErr> {
+        Self::bytes_to_int_partial(s.as_ref())
+    }
+
+    fn bytes_to_int_partial(s: &[u8]) -> Result<(Self, usize), ParseIntErr>;
+
+    /// Like [`FromAscii::atoi_partial`], but returns the bytes after the number instead of a count,
+    /// which makes it easy to take several numbers out of one buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     let (width, rest) = u32::atoi_split("1920x1080").unwrap();
+    ///     let (height, rest) = u32::atoi_split(&rest[1..]).unwrap();
+    ///
+    ///     assert_eq!((width, height), (1920, 1080));
+    ///     assert!(rest.is_empty());
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_split<S>(s: &S) -> Result<(Self, &[u8]), ParseIntErr>
+    where
+        S: AsRef<[u8]> + ?Sized,
+    {
+        let bytes = s.as_ref();
+
+        Self::bytes_to_int_partial(bytes).map(|(value, consumed)| (value, &bytes[consumed..]))
+    }
+
+    /// Converts exactly `N` digits at the start of a byteslice, and returns the number together with the bytes after it.
+    /// This is meant for fixed-width fields, which are often padded with leading zeros. No sign is accepted.
+    ///
+    /// If the input is shorter than `N` bytes, the error is [`ParseIntErr::Empty`] for empty input,
+    /// and an [`ParseIntErr::InvalidDigit`] for its last byte otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     let (year, rest) = u16::atoi_n::<4, _>("20240517").unwrap();
+    ///     let (month, rest) = u8::atoi_n::<2, _>(rest).unwrap();
+    ///
+    ///     assert_eq!((year, month, rest), (2024, 5, &b"17"[..]));
+    ///     assert_eq!(u8::atoi_n::<3, _>("999"), Err(ParseIntErr::PosOverflow));
+    ///     assert_eq!(u32::atoi_n::<4, _>("12a4"), Err(ParseIntErr::with_byte_at(b'a', 2)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_n<const N: usize, S>(s: &S) -> Result<(Self, &[u8]), ParseIntErr>
+    where
+        S: AsRef<[u8]> + ?Sized,
+    {
+        Self::bytes_to_int_n::<N>(s.as_ref())
+    }
+
+    fn bytes_to_int_n<const N: usize>(s: &[u8]) -> Result<(Self, &[u8]), ParseIntErr>;
+
+    /// Converts the bytes of an iterator to a number, for input that isn't a contiguous slice,
+    /// like the output of a decompressor. The digits are accumulated one at a time.
+    /// Unlike [`FromAscii::atoi`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     let rope = [&b"-12"[..], b"", b"34"];
+    ///
+    ///     assert_eq!(i32::atoi_iter(rope.iter().flat_map(|part| part.iter().copied())), Ok(-1234));
+    ///     assert_eq!(u8::atoi_iter(b"1x".iter().copied()), Err(ParseIntErr::with_byte_at(b'x', 1)));
+    ///     assert_eq!(u8::atoi_iter(b"256".iter().copied()), Err(ParseIntErr::PosOverflow));
+    /// }
+    /// ```
+    fn atoi_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, ParseIntErr>;
+
+    /// Converts a number split over two slices, like the halves of a ring buffer returned by `VecDeque::as_slices`,
+    /// without copying it. Positions in errors count from the start of the first slice.
+    /// Like [`FromAscii::atoi_iter`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     let mut ring = VecDeque::with_capacity(4);
+    ///     ring.extend(b"xx");
+    ///     ring.drain(..);
+    ///     ring.extend(b"1234");
+    ///
+    ///     assert_eq!(u32::atoi_slices(ring.as_slices()), Ok(1234));
+    /// }
+    /// ```
+    fn atoi_slices(slices: (&[u8], &[u8])) -> Result<Self, ParseIntErr>;
+
+    /// Converts the number at the start of a buffer filled in by C, which must end in a NUL right after the digits.
+    /// Returns the number together with its length, not counting the NUL.
+    /// Like [`FromAscii::atoi_partial`], this never wraps around.
+    ///
+    /// Anything else after the digits is reported as an invalid digit, and a missing NUL as the last byte.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     let buffer = *b"-8080\0\0\0";
+    ///
+    ///     assert_eq!(i32::atoi_until_nul(&buffer), Ok((-8080, 5)));
+    ///     assert_eq!(i32::atoi_until_nul(b"80 \0"), Err(ParseIntErr::with_byte_at(b' ', 2)));
+    ///     assert_eq!(i32::atoi_until_nul(b"80"), Err(ParseIntErr::with_byte_at(b'0', 1)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_until_nul(s: &[u8]) -> Result<(Self, usize), ParseIntErr> {
+        let (value, len) = Self::bytes_to_int_partial(s)?;
+
+        match s.get(len) {
+            Some(0) => Ok((value, len)),
+            Some(&c) => Err(ParseIntErr::with_byte_at(c, len)),
+            None => Err(ParseIntErr::with_byte_at(s[len - 1], len - 1)),
+        }
+    }
+
+    /// Like [`FromAscii::atoi_until_nul`], but for a `CStr`, which always ends in a NUL.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::ffi::CStr;
+    /// use byte_num::from_ascii::FromAscii;
+    ///
+    /// fn main() {
+    ///     let port = CStr::from_bytes_with_nul(b"8080\0").unwrap();
+    ///     assert_eq!(u16::atoi_cstr(port), Ok((8080, 4)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_cstr(s: &CStr) -> Result<(Self, usize), ParseIntErr> {
+        Self::atoi_until_nul(s.to_bytes_with_nul())
+    }
+
+    /// Converts a pair of numbers split on the first `separator`, like the coordinates in `"12,-7"`.
+    /// Both sides are converted with [`FromAscii::atoi`].
+    ///
+    /// The error tells which side failed, in [`ParseFieldErr::field`]: 0 for the left, and 1 for the right.
+    /// Positions are relative to the start of that side. Without a separator, the right side is [`ParseIntErr::Empty`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii::FromAscii,
+    ///     error::{ParseFieldErr, ParseIntErr},
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(i32::atoi_pair("12,-7", b','), Ok((12, -7)));
+    ///     assert_eq!(
+    ///         i32::atoi_pair("12,7x", b','),
+    ///         Err(ParseFieldErr { field: 1, error: ParseIntErr::with_byte_at(b'x', 1) })
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_pair(s: impl AsRef<[u8]>, separator: u8) -> Result<(Self, Self), ParseFieldErr> {
+        let bytes = s.as_ref();
+        let field = |field| move |error| ParseFieldErr { field, error };
+
+        let (left, right) = match bytes.iter().position(|b| *b == separator) {
+            Some(idx) => (&bytes[..idx], Some(&bytes[idx + 1..])),
+            None => (bytes, None),
+        };
+
+        let left = Self::bytes_to_int(left).map_err(field(0))?;
+        let right = right.map_or(Err(ParseIntErr::Empty), Self::bytes_to_int).map_err(field(1))?;
+
+        Ok((left, right))
+    }
 }
 
 #[inline(always)]
@@ -59,159 +401,1327 @@ where
     Ok(N::from(d) * pow10)
 }
 
-macro_rules! unsigned_from_ascii {
-    ($int:ty, $const_table:ident) => {
-
-        impl FromAscii for $int {
-            // 1) Start at correct position in pow10 table (const_table.len() - bytes.len() ).
-            // 2) For each byte:
-            //     - substract 48, wrapping
-            //     - validate it's less than 9
-            //     - multiply with some power of 10
-            #[inline]
-            fn bytes_to_int(mut bytes: &[u8]) -> Result<Self, ParseIntErr> {
+/// Sets the high bit of every byte of `v` that isn't an ASCII digit.
+// The range is checked on the low 7 bits, so neither step carries into the next byte.
+// Bytes that had their high bit set are never digits.
+#[inline(always)]
+pub(crate) fn swar_non_digits(v: u64) -> u64 {
+    let ascii = v & !HIGH;
 
-                if bytes.len() > $const_table.len() {
-                    return Err(ParseIntErr::Overflow);
-                }
-        
-                let mut result: Self = 0;
-        
-                let mut len = bytes.len();
-                let mut idx = $const_table.len().wrapping_sub(len);
-        
-                // @NOTE: This is safe, we never overshoot the buffers.
-                // First we checked of the length of `bytes` is NOT longer than the length of the corresponding table of powers of 10,
-                // so there is no bounds check needed to access the table of powers of 10.
-                // Second, we loop while the length of the bytes is larger than or equal to 4, but only accessing the first 4 elements.
-                // No boundschecks is needed for that as well.
-                unsafe {
-                    while len >= 4 {
-                        match (
-                            bytes.get_unchecked(..4),
-                            $const_table.get_unchecked(idx..idx + 4),
-                        ) {
-                            ([a, b, c, d], [p1, p2, p3, p4]) => {
-                                let r1 = parse_byte(*a, *p1)?;
-                                let r2 = parse_byte(*b, *p2)?;
-                                let r3 = parse_byte(*c, *p3)?;
-                                let r4 = parse_byte(*d, *p4)?;
-        
-                                result = result.wrapping_add(r1 + r2 + r3 + r4);
-                            }
-                            // Never reachable. Never ever ever.
-                            _ => std::hint::unreachable_unchecked(),
-                        }
-        
-                        len -= 4;
-                        idx += 4;
-                        bytes = bytes.get_unchecked(4..);
-                    }
-        
-                    // Fixuploop
-                    for offset in 0..len {
-                        let a = bytes.get_unchecked(offset);
-                        let p = $const_table.get_unchecked(idx + offset);
-                        let r = parse_byte(*a, *p)?;
-                        result = result.wrapping_add(r);
-                    }
-                }
-        
-                Ok(result)
-            }
-        }
-    };
+    let above = ascii + ONES * u64::from(0x7F - b'9');
+    let at_least = (ascii | HIGH) - ONES * u64::from(b'0');
 
-    // @NOTE: Specialize implementation for u8, since that's finished within 3 Iterations at max.
-    (@u8, $const_table:ident) => {
-        impl FromAscii for u8 {
-            #[inline]
-            fn bytes_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
-                if bytes.len() > $const_table.len() {
-                    return Err(ParseIntErr::Overflow);
-                }
-        
-                let mut result: Self = 0;
-                let len = bytes.len();
-                let idx = $const_table.len().wrapping_sub(len);
-        
-                unsafe {
-                    for offset in 0..len {
-                        let a = bytes.get_unchecked(offset);
-                        let p = $const_table.get_unchecked(idx + offset);
-                        let r = parse_byte(*a, *p)?;
-                        result = result.wrapping_add(r);
-                    }
-                }
-        
-                Ok(result)
-            }
-        }
-    };
+    (above | !at_least | v) & HIGH
 }
 
-macro_rules! signed_from_ascii {
-    ($int:ty, $unsigned_version:ty) => {
-        impl FromAscii for $int {
-            #[inline]
-            fn bytes_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
-                if bytes.starts_with(b"-") {
-                    // .wrapping_neg() wraps around.
-                    Ok((<$unsigned_version>::bytes_to_int(&bytes[1..])? as Self).wrapping_neg())
-                } else {
-                    Ok(<$unsigned_version>::bytes_to_int(bytes)? as Self)
-                }
-            }
-        }
-    };
-}
+/// Validates and converts 8 decimal digits at once, with the first digit in the first byte.
+// 1) Check every byte is within '0'..='9', using the high bit of each byte as a flag.
+// 2) Combine neighbouring digits into pairs, then pairs into groups of 4 and those into the result,
+//    multiplying each lane by its power of 10 and adding it to its neighbour in a single multiplication.
+#[inline(always)]
+pub(crate) fn swar_parse8(bytes: [u8; 8]) -> Result<u32, ParseIntErr> {
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 100 + (1_000_000 << 32);
+    const MUL2: u64 = 1 + (10_000 << 32);
 
-// Generate the tables of powers of 10 :)
-use tablepower::table_of;
+    let v = u64::from_le_bytes(bytes);
+    let invalid = swar_non_digits(v);
 
-table_of!(u8, POW10_U8, order = descending);
-table_of!(u16, POW10_U16, order = descending);
-table_of!(u32, POW10_U32, order = descending);
-table_of!(u64, POW10_U64, order = descending);
-table_of!(usize, POW10_USIZE, order = descending);
+    if invalid != 0 {
+        let idx = invalid.trailing_zeros() as usize / 8;
+        return Err(ParseIntErr::with_byte(bytes[idx]));
+    }
 
-unsigned_from_ascii!(@u8, POW10_U8);
-unsigned_from_ascii!(u16, POW10_U16);
-unsigned_from_ascii!(u32, POW10_U32);
-unsigned_from_ascii!(u64, POW10_U64);
-unsigned_from_ascii!(usize, POW10_USIZE);
+    let mut n = v - ONES * u64::from(b'0');
+    n = (n * 10) + (n >> 8);
+    n = ((n & MASK).wrapping_mul(MUL1) + ((n >> 16) & MASK).wrapping_mul(MUL2)) >> 32;
 
-signed_from_ascii!(i8, u8);
-signed_from_ascii!(i16, u16);
-signed_from_ascii!(i32, u32);
-signed_from_ascii!(i64, u64);
-signed_from_ascii!(isize, usize);
+    Ok(n as u32)
+}
 
-#[cfg(test)]
-mod tests {
-    use super::{FromAscii, ParseIntErr};
+/// Parses up to 7 digits one at a time. Below 8 digits, this beats padding them into a block for [`swar_parse8`].
+#[inline(always)]
+fn scalar_digits_to_u64(bytes: &[u8]) -> Result<u64, ParseIntErr> {
+    let mut result = 0;
 
-    #[test]
-    fn to_u8() {
-        assert_eq!(u8::atoi("123"), Ok(123));
-        assert_eq!(u8::atoi("256"), Ok(0));
+    for &byte in bytes {
+        result = result * 10 + parse_byte::<u64>(byte, 1)?;
+    }
 
-        // Wraps around
-        assert_eq!(u8::atoi("257"), Ok(1));
+    Ok(result)
+}
 
-        // Error: InvalidDigit
-        assert_eq!(u8::atoi("!23"), Err(ParseIntErr::with_byte(b'!')));
+/// Parses a slice of digits with whatever is fastest for its length, wrapping around on overflow:
+/// digits that don't fill a block of 8 one at a time, then blocks of 8 with SWAR,
+/// and once the rest is a multiple of 16, blocks of 16 with the kernels of [`simd`].
+#[inline(always)]
+fn digits_to_u64(bytes: &[u8]) -> Result<u64, ParseIntErr> {
+    let (head, rest) = bytes.split_at(bytes.len() % 8);
+    let (middle, blocks) = rest.split_at(rest.len() % 16);
+    let mut result = scalar_digits_to_u64(head)?;
 
-        // Error: Overflow
-        assert_eq!(u8::atoi("1000"), Err(ParseIntErr::Overflow));
+    for chunk in middle.chunks_exact(8) {
+        let mut block = [0; 8];
+        block.copy_from_slice(chunk);
+
+        result = result.wrapping_mul(100_000_000).wrapping_add(u64::from(swar_parse8(block)?));
     }
 
-    #[test]
-    fn overflow_isize() {
-        // overflows minimum value of the isize by 1, but it wraps arroo
-        assert_eq!(isize::atoi("-9223372036854775809"), Ok(9223372036854775807));
+    for chunk in blocks.chunks_exact(16) {
+        let mut block = [0; 16];
+        block.copy_from_slice(chunk);
+
+        result = result.wrapping_mul(10_000_000_000_000_000).wrapping_add(simd::parse16(block)?);
+    }
 
-        // overflows maximum value of the isize by 1, but it wraps aroo
-        assert_eq!(isize::atoi("9223372036854775809"), Ok(-9223372036854775807));
+    Ok(result)
+}
+
+/// Fills in the index of an [`ParseIntErr::InvalidDigit`] within `bytes`.
+/// The digit parsers don't track their offset, so on error the input is scanned again for the first invalid byte,
+/// keeping the bookkeeping out of the hot loop.
+#[cold]
+pub(crate) fn locate_invalid_digit(err: ParseIntErr, bytes: &[u8], radix: u32) -> ParseIntErr {
+    match err {
+        ParseIntErr::InvalidDigit(..) => {
+            // A leading '-' is only invalid if it's all there is.
+            let start = match bytes {
+                [b'-', _, ..] => 1,
+                _ => 0,
+            };
+
+            let position = bytes[start..]
+                .iter()
+                .position(|b| u32::from(DIGIT_VALUES[*b as usize]) >= radix)
+                .map_or(0, |idx| idx + start);
+
+            ParseIntErr::with_byte_at(bytes[position], position)
+        }
+        err => err,
+    }
+}
+
+/// Strips leading `b'0'` bytes, but never the last byte of `bytes`,
+/// so an all-zero input still has a digit left to parse.
+#[inline(always)]
+pub(crate) fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let mut idx = 0;
+
+    while idx + 1 < bytes.len() && bytes[idx] == b'0' {
+        idx += 1;
+    }
+
+    &bytes[idx..]
+}
+
+/// Multiplies a mantissa by a power of 10, for [`FromAscii::atoi_exp`].
+trait ScaleExp: Sized {
+    fn scale_exp(self, exponent: u32) -> Option<Self>;
+}
+
+macro_rules! scale_exp {
+    ($($int:ty),*) => {
+        $(
+            impl ScaleExp for $int {
+                #[inline]
+                fn scale_exp(self, exponent: u32) -> Option<Self> {
+                    // Zero stays zero, even when the power of 10 itself would overflow.
+                    if self == 0 {
+                        return Some(0);
+                    }
+
+                    <$int>::checked_pow(10, exponent).and_then(|pow| self.checked_mul(pow))
+                }
+            }
+        )*
+    };
+}
+
+scale_exp!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Parses `mantissa[eE]exponent`. The mantissa goes through the exact, non-wrapping radix parser.
+#[inline]
+fn parse_exp<N>(bytes: &[u8]) -> Result<N, ParseIntErr>
+where
+    N: FromAsciiRadix + ScaleExp,
+{
+    let split = match bytes.iter().position(|b| *b == b'e' || *b == b'E') {
+        Some(split) => split,
+        None => return N::bytes_to_int_radix(bytes, 10),
+    };
+
+    let mantissa = match N::bytes_to_int_radix(&bytes[..split], 10) {
+        Err(ParseIntErr::Empty) => return Err(ParseIntErr::with_byte_at(bytes[split], split)),
+        mantissa => mantissa?,
+    };
+
+    let (digits, offset) = match &bytes[split + 1..] {
+        [b'+', digits @ ..] => (digits, split + 2),
+        digits => (digits, split + 1),
+    };
+
+    if digits.is_empty() {
+        let last = bytes.len() - 1;
+        return Err(ParseIntErr::with_byte_at(bytes[last], last));
+    }
+
+    if digits[0] == b'-' {
+        return Err(ParseIntErr::with_byte_at(b'-', offset));
+    }
+
+    let exponent = match u32::bytes_to_int_radix(digits, 10) {
+        Ok(exponent) => exponent,
+        // Too large for any non-zero mantissa anyway.
+        Err(ParseIntErr::PosOverflow) => u32::MAX,
+        Err(ParseIntErr::InvalidDigit([c], position)) => {
+            return Err(ParseIntErr::with_byte_at(c, position + offset))
+        }
+        Err(e) => return Err(e),
+    };
+
+    mantissa.scale_exp(exponent).ok_or(match bytes[0] {
+        b'-' => ParseIntErr::NegOverflow,
+        _ => ParseIntErr::PosOverflow,
+    })
+}
+
+/// Digit by digit accumulation with overflow checks,
+/// for the parsers that can't work on a contiguous slice of digits.
+pub(crate) trait Accumulate: Copy {
+    const ZERO: Self;
+
+    /// Appends the digit `d`. Negative numbers are accumulated downwards, so MIN is reachable.
+    /// For unsigned types that fails on the first non-zero digit, which is exactly when it is out of range.
+    fn push_digit(self, d: u8, is_negative: bool) -> Option<Self>;
+}
+
+/// The bounds of an integer type, for the parsers that saturate instead of reporting an overflow.
+trait Saturate: Sized {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+}
+
+macro_rules! accumulate {
+    ($($int:ty),*) => {
+        $(
+            impl Accumulate for $int {
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn push_digit(self, d: u8, is_negative: bool) -> Option<Self> {
+                    let acc = self.checked_mul(10)?;
+
+                    if is_negative {
+                        acc.checked_sub(d as Self)
+                    } else {
+                        acc.checked_add(d as Self)
+                    }
+                }
+            }
+
+            impl Saturate for $int {
+                const MIN: Self = <$int>::MIN;
+                const MAX: Self = <$int>::MAX;
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+accumulate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Parses the bytes of an iterator, for [`FromAscii::atoi_iter`].
+#[inline]
+fn parse_iter<N: Accumulate>(iter: impl IntoIterator<Item = u8>) -> Result<N, ParseIntErr> {
+    let mut iter = iter.into_iter().peekable();
+    let is_negative = iter.next_if_eq(&b'-').is_some();
+    let start = is_negative as usize;
+
+    let mut value = N::ZERO;
+    let mut len = start;
+
+    for byte in iter {
+        let d = byte.wrapping_sub(ASCII_TO_INT_FACTOR);
+
+        if d > 9 {
+            return Err(ParseIntErr::with_byte_at(byte, len));
+        }
+
+        value = value.push_digit(d, is_negative).ok_or(if is_negative {
+            ParseIntErr::NegOverflow
+        } else {
+            ParseIntErr::PosOverflow
+        })?;
+        len += 1;
+    }
+
+    match len {
+        0 => Err(ParseIntErr::Empty),
+        _ if len == start => Err(ParseIntErr::with_byte_at(b'-', 0)),
+        _ => Ok(value),
+    }
+}
+
+/// Parses a number split over two slices, for [`FromAscii::atoi_slices`].
+// Only when the number straddles the split, it's accumulated digit by digit.
+#[inline]
+fn parse_slices<N: FromAsciiRadix + Accumulate>(head: &[u8], tail: &[u8]) -> Result<N, ParseIntErr> {
+    match (head, tail) {
+        (bytes, []) | ([], bytes) => N::bytes_to_int_radix(bytes, 10),
+        _ => parse_iter(head.iter().chain(tail).copied()),
+    }
+}
+
+/// Returns the index of the first byte from `start` on that is not a digit.
+#[inline(always)]
+fn digits_end(bytes: &[u8], start: usize) -> usize {
+    start + bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// Parses the number at the start of `bytes`, for [`FromAscii::atoi_partial`].
+#[inline]
+fn parse_partial<N: FromAsciiRadix>(bytes: &[u8]) -> Result<(N, usize), ParseIntErr> {
+    let digits_start = (bytes.first() == Some(&b'-')) as usize;
+    let end = digits_end(bytes, digits_start);
+
+    // Without digits, report whatever is there instead.
+    if end == digits_start && end < bytes.len() {
+        return Err(ParseIntErr::with_byte_at(bytes[end], end));
+    }
+
+    N::bytes_to_int_radix(&bytes[..end], 10).map(|value| (value, end))
+}
+
+/// Parses exactly `N` digits at the start of `bytes`, for [`FromAscii::atoi_n`].
+// Up to 19 digits always fit in a u64, so that case needs no overflow checks in the loop.
+// With `N` known at compile time, the loop is unrolled, and the digits are validated all at once afterwards.
+#[inline]
+fn parse_n<T, const N: usize>(bytes: &[u8]) -> Result<(T, &[u8]), ParseIntErr>
+where
+    T: TryFrom<u64> + Accumulate,
+{
+    if bytes.len() < N {
+        return Err(match bytes.len() {
+            0 => ParseIntErr::Empty,
+            len => ParseIntErr::with_byte_at(bytes[len - 1], len - 1),
+        });
+    }
+
+    let (digits, rest) = bytes.split_at(N);
+
+    if N <= 19 {
+        let mut value = 0u64;
+        let mut invalid = false;
+
+        for byte in digits {
+            let d = byte.wrapping_sub(ASCII_TO_INT_FACTOR);
+            invalid |= d > 9;
+            value = value.wrapping_mul(10).wrapping_add(u64::from(d));
+        }
+
+        if invalid {
+            let idx = digits.iter().position(|b| !b.is_ascii_digit()).unwrap_or(0);
+            return Err(ParseIntErr::with_byte_at(digits[idx], idx));
+        }
+
+        T::try_from(value)
+            .map(|value| (value, rest))
+            .map_err(|_| ParseIntErr::PosOverflow)
+    } else {
+        let mut value = T::ZERO;
+
+        for (idx, byte) in digits.iter().enumerate() {
+            let d = byte.wrapping_sub(ASCII_TO_INT_FACTOR);
+
+            if d > 9 {
+                return Err(ParseIntErr::with_byte_at(*byte, idx));
+            }
+
+            value = value.push_digit(d, false).ok_or(ParseIntErr::PosOverflow)?;
+        }
+
+        Ok((value, rest))
+    }
+}
+
+/// Parses the number at the start of `bytes` like `strtol`, for [`FromAscii::atoi_lenient`].
+#[inline]
+fn parse_lenient<N>(bytes: &[u8]) -> (N, usize)
+where
+    N: FromAsciiRadix + Saturate,
+{
+    let start = bytes.iter().take_while(|b| b.is_ascii_whitespace()).count();
+
+    // The slice to parse includes a '-', but not a '+'.
+    let (number_start, digits_start) = match bytes.get(start) {
+        Some(b'-') => (start, start + 1),
+        Some(b'+') => (start + 1, start + 1),
+        _ => (start, start),
+    };
+
+    let end = digits_end(bytes, digits_start);
+
+    if end == digits_start {
+        return (N::ZERO, 0);
+    }
+
+    let value = match N::bytes_to_int_radix(&bytes[number_start..end], 10) {
+        Ok(value) => value,
+        Err(ParseIntErr::NegOverflow) => N::MIN,
+        Err(_) => N::MAX,
+    };
+
+    (value, end)
+}
+
+#[inline(always)]
+fn is_digit_at(bytes: &[u8], idx: usize) -> bool {
+    bytes.get(idx).is_some_and(u8::is_ascii_digit)
+}
+
+/// Parses digits with `separator` bytes between them, for [`FromAscii::atoi_with_separators`].
+/// If `grouped` is set, the separators must split the digits in groups of 3, as for [`FromAscii::atoi_grouped`].
+#[inline]
+fn parse_separated<N: Accumulate>(bytes: &[u8], separator: u8, grouped: bool) -> Result<N, ParseIntErr> {
+    let is_negative = match bytes {
+        [] => return Err(ParseIntErr::Empty),
+        [b'-', ..] => true,
+        _ => false,
+    };
+
+    let overflow = if is_negative {
+        ParseIntErr::NegOverflow
+    } else {
+        ParseIntErr::PosOverflow
+    };
+
+    let start = is_negative as usize;
+    let mut acc = N::ZERO;
+
+    let mut group_len = 0;
+    let mut seen_separator = false;
+
+    for (idx, &byte) in bytes.iter().enumerate().skip(start) {
+        let d = byte.wrapping_sub(ASCII_TO_INT_FACTOR);
+
+        if d <= 9 {
+            if grouped && seen_separator && group_len == 3 {
+                return Err(ParseIntErr::with_byte_at(byte, idx));
+            }
+
+            acc = acc.push_digit(d, is_negative).ok_or(overflow)?;
+            group_len += 1;
+        } else if byte != separator
+            || idx == start
+            || !is_digit_at(bytes, idx - 1)
+            || !is_digit_at(bytes, idx + 1)
+            || (grouped && (group_len > 3 || (seen_separator && group_len != 3)))
+        {
+            return Err(ParseIntErr::with_byte_at(byte, idx));
+        } else {
+            seen_separator = true;
+            group_len = 0;
+        }
+    }
+
+    // Only a sign.
+    if bytes.len() == start {
+        return Err(ParseIntErr::with_byte_at(b'-', 0));
+    }
+
+    // The last group is too short.
+    if grouped && seen_separator && group_len != 3 {
+        let last = bytes.len() - 1;
+        return Err(ParseIntErr::with_byte_at(bytes[last], last));
+    }
+
+    Ok(acc)
+}
+
+/// Parses a slice of nothing but digits, without handling any sign.
+/// This is the building block shared by the unsigned and signed implementations of [`FromAscii`].
+trait FromDigits: Sized {
+    fn digits_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr>;
+}
+
+macro_rules! unsigned_from_ascii {
+    ($int:ty, $const_table:ident) => {
+        unsigned_from_ascii!(@sign $int);
+
+        impl FromDigits for $int {
+            // 1) Route numbers of up to 3 digits to the short parsers.
+            // 2) Check the length against the table of powers of 10.
+            // 3) Pick the conversion by length, see `digits_to_u64`. The arithmetic wraps, just like `Self` would,
+            //    so truncating the result gives the same number as parsing in `Self` directly.
+            #[inline]
+            fn digits_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                // Zero-padded input may be longer than the table, but still fit.
+                let bytes = trim_leading_zeros(bytes);
+
+                // The short numbers that are most common skip the loop entirely.
+                match *bytes {
+                    [a] => return parse1([a]).map(Self::from),
+                    [a, b] => return parse2([a, b]).map(Self::from),
+                    [a, b, c] => return parse3([a, b, c]).map(Self::from),
+                    _ => {}
+                }
+
+                if bytes.len() > $const_table.len() {
+                    return Err(ParseIntErr::PosOverflow);
+                }
+
+                digits_to_u64(bytes).map(|n| n as Self)
+            }
+        }
+    };
+
+    // @NOTE: Specialize implementation for u8, since that's finished within 3 Iterations at max.
+    (@u8, $const_table:ident) => {
+        unsigned_from_ascii!(@sign u8);
+
+        impl FromDigits for u8 {
+            #[inline]
+            fn digits_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                match *trim_leading_zeros(bytes) {
+                    [] => Ok(0),
+                    [a] => parse1([a]),
+                    [a, b] => parse2([a, b]),
+                    // Like the other unsigned types, a value past MAX wraps around.
+                    [a, b, c] => parse3([a, b, c]).map(|n| n as u8),
+                    _ => Err(ParseIntErr::PosOverflow),
+                }
+            }
+        }
+    };
+
+    // A leading '-' is reported explicitly, rather than as an invalid digit.
+    (@sign $int:ty) => {
+        impl FromAscii for $int {
+            const MAX_DIGITS: usize = <$int>::MAX.ilog10() as usize + 1;
+            const MAX_FORMATTED_LEN: usize = Self::MAX_DIGITS;
+
+            #[inline]
+            fn bytes_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                let result = match bytes {
+                    [] => Err(ParseIntErr::Empty),
+                    [b'-'] => Err(ParseIntErr::with_byte(b'-')),
+                    // "-0" is still zero, but only when every digit is, since the magnitude wraps.
+                    [b'-', digits @ ..] if digits.iter().all(|&b| b == b'0') => Ok(0),
+                    [b'-', digits @ ..] => match Self::digits_to_int(digits) {
+                        Ok(_) | Err(ParseIntErr::PosOverflow) => Err(ParseIntErr::NegOverflow),
+                        Err(e) => Err(e),
+                    },
+                    digits => Self::digits_to_int(digits),
+                };
+
+                result.map_err(|e| locate_invalid_digit(e, bytes, 10))
+            }
+
+            #[inline]
+            fn bytes_to_int_exp(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_exp(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_with_separators(bytes: &[u8], separator: u8) -> Result<Self, ParseIntErr> {
+                parse_separated(bytes, separator, false)
+            }
+
+            #[inline]
+            fn bytes_to_int_grouped(bytes: &[u8], separator: u8) -> Result<Self, ParseIntErr> {
+                parse_separated(bytes, separator, true)
+            }
+
+            #[inline]
+            fn bytes_to_int_lenient(bytes: &[u8]) -> (Self, usize) {
+                parse_lenient(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_partial(bytes: &[u8]) -> Result<(Self, usize), ParseIntErr> {
+                parse_partial(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_n<const N: usize>(bytes: &[u8]) -> Result<(Self, &[u8]), ParseIntErr> {
+                parse_n::<Self, N>(bytes)
+            }
+
+            #[inline]
+            fn atoi_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, ParseIntErr> {
+                parse_iter(iter)
+            }
+
+            #[inline]
+            fn atoi_slices((head, tail): (&[u8], &[u8])) -> Result<Self, ParseIntErr> {
+                parse_slices(head, tail)
+            }
+        }
+    };
+}
+
+macro_rules! signed_from_ascii {
+    ($int:ty, $unsigned_version:ty, $const_table:ident) => {
+        impl FromAscii for $int {
+            const MAX_DIGITS: usize = <$int>::MIN.unsigned_abs().ilog10() as usize + 1;
+            const MAX_FORMATTED_LEN: usize = Self::MAX_DIGITS + 1;
+
+            // 1) Strip the sign and any leading zeros.
+            // 2) Parse all but the last digit as the unsigned type. That never wraps,
+            //    because it's at least one digit shorter than the table of powers of 10.
+            // 3) Add the last digit with checked arithmetic, and check the magnitude against the signed range.
+            #[inline]
+            fn bytes_to_int(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                #[inline(always)]
+                fn parse(bytes: &[u8]) -> Result<$int, ParseIntErr> {
+                    let (is_negative, digits) = match bytes {
+                        [] => return Err(ParseIntErr::Empty),
+                        [b'-', digits @ ..] => {
+                            // A sign without any digits following it is not a number.
+                            if digits.is_empty() {
+                                return Err(ParseIntErr::with_byte(b'-'));
+                            }
+
+                            (true, digits)
+                        }
+                        digits => (false, digits),
+                    };
+
+                    let digits = trim_leading_zeros(digits);
+
+                    let overflow = if is_negative {
+                        ParseIntErr::NegOverflow
+                    } else {
+                        ParseIntErr::PosOverflow
+                    };
+
+                    if digits.len() > $const_table.len() {
+                        return Err(overflow);
+                    }
+
+                    let magnitude = match digits.split_last() {
+                        Some((last, head)) => {
+                            let head = <$unsigned_version>::digits_to_int(head)?;
+                            let last = parse_byte::<$unsigned_version>(*last, 1)?;
+
+                            head.checked_mul(10)
+                                .and_then(|n| n.checked_add(last))
+                                .ok_or(overflow)?
+                        }
+                        None => 0,
+                    };
+
+                    if is_negative {
+                        // The magnitude of MIN is one larger than MAX.
+                        if magnitude > <$int>::MIN as $unsigned_version {
+                            return Err(overflow);
+                        }
+
+                        Ok((magnitude as $int).wrapping_neg())
+                    } else {
+                        if magnitude > <$int>::MAX as $unsigned_version {
+                            return Err(overflow);
+                        }
+
+                        Ok(magnitude as $int)
+                    }
+                }
+
+                parse(bytes).map_err(|e| locate_invalid_digit(e, bytes, 10))
+            }
+
+            #[inline]
+            fn bytes_to_int_exp(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_exp(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_with_separators(bytes: &[u8], separator: u8) -> Result<Self, ParseIntErr> {
+                parse_separated(bytes, separator, false)
+            }
+
+            #[inline]
+            fn bytes_to_int_grouped(bytes: &[u8], separator: u8) -> Result<Self, ParseIntErr> {
+                parse_separated(bytes, separator, true)
+            }
+
+            #[inline]
+            fn bytes_to_int_lenient(bytes: &[u8]) -> (Self, usize) {
+                parse_lenient(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_partial(bytes: &[u8]) -> Result<(Self, usize), ParseIntErr> {
+                parse_partial(bytes)
+            }
+
+            #[inline]
+            fn bytes_to_int_n<const N: usize>(bytes: &[u8]) -> Result<(Self, &[u8]), ParseIntErr> {
+                parse_n::<Self, N>(bytes)
+            }
+
+            #[inline]
+            fn atoi_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, ParseIntErr> {
+                parse_iter(iter)
+            }
+
+            #[inline]
+            fn atoi_slices((head, tail): (&[u8], &[u8])) -> Result<Self, ParseIntErr> {
+                parse_slices(head, tail)
+            }
+        }
+    };
+}
+
+// Generate the tables of powers of 10 :)
+use tablepower::table_of;
+
+table_of!(u8, POW10_U8, order = descending);
+table_of!(u16, POW10_U16, order = descending);
+table_of!(u32, POW10_U32, order = descending);
+table_of!(u64, POW10_U64, order = descending);
+table_of!(usize, POW10_USIZE, order = descending);
+
+unsigned_from_ascii!(@u8, POW10_U8);
+unsigned_from_ascii!(u16, POW10_U16);
+unsigned_from_ascii!(u32, POW10_U32);
+unsigned_from_ascii!(u64, POW10_U64);
+unsigned_from_ascii!(usize, POW10_USIZE);
+
+signed_from_ascii!(i8, u8, POW10_U8);
+signed_from_ascii!(i16, u16, POW10_U16);
+signed_from_ascii!(i32, u32, POW10_U32);
+signed_from_ascii!(i64, u64, POW10_U64);
+signed_from_ascii!(isize, usize, POW10_USIZE);
+
+/// Returns the index of the first byte in `bytes` that isn't an ASCII digit, or `None` if all of them are.
+///
+/// The bytes are checked up to 32 at a time, which makes this a cheap way to find the end of a run of digits,
+/// or to validate a token before committing to a parse.
+///
+/// # Examples
+/// ```
+/// use byte_num::from_ascii::first_non_digit;
+///
+/// fn main() {
+///     assert_eq!(first_non_digit(b"12345678901,2"), Some(11));
+///     assert_eq!(first_non_digit(b"2024"), None);
+///     assert_eq!(first_non_digit(b""), None);
+/// }
+/// ```
+#[inline]
+pub fn first_non_digit(bytes: &[u8]) -> Option<usize> {
+    let mut blocks = bytes.chunks_exact(32);
+
+    for (idx, chunk) in blocks.by_ref().enumerate() {
+        let mut block = [0; 32];
+        block.copy_from_slice(chunk);
+
+        let invalid = simd::non_digits32(block);
+
+        if invalid != 0 {
+            return Some(idx * 32 + invalid.trailing_zeros() as usize);
+        }
+    }
+
+    let mut offset = bytes.len() - blocks.remainder().len();
+
+    if let Some(chunk) = bytes.get(offset..offset + 16) {
+        let mut block = [0; 16];
+        block.copy_from_slice(chunk);
+
+        let invalid = simd::non_digits16(block);
+
+        if invalid != 0 {
+            return Some(offset + invalid.trailing_zeros() as usize);
+        }
+
+        offset += 16;
+    }
+
+    let mut chunks = bytes[offset..].chunks_exact(8);
+
+    for (idx, chunk) in chunks.by_ref().enumerate() {
+        let mut block = [0; 8];
+        block.copy_from_slice(chunk);
+
+        let invalid = swar_non_digits(u64::from_le_bytes(block));
+
+        if invalid != 0 {
+            return Some(offset + idx * 8 + invalid.trailing_zeros() as usize / 8);
+        }
+    }
+
+    let tail = chunks.remainder();
+
+    tail.iter()
+        .position(|b| !b.is_ascii_digit())
+        .map(|idx| bytes.len() - tail.len() + idx)
+}
+
+/// Returns whether every byte of `bytes` is an ASCII digit. This is true for an empty slice.
+///
+/// # Examples
+/// ```
+/// use byte_num::from_ascii::is_ascii_digits;
+///
+/// fn main() {
+///     assert!(is_ascii_digits(b"0123456789"));
+///     assert!(!is_ascii_digits(b"-12"));
+///     assert!(is_ascii_digits(b""));
+/// }
+/// ```
+#[inline]
+pub fn is_ascii_digits(bytes: &[u8]) -> bool {
+    first_non_digit(bytes).is_none()
+}
+
+/// Reports the first byte of `bytes` that isn't a digit, for the short parsers below.
+#[cold]
+fn short_invalid_digit(bytes: &[u8]) -> ParseIntErr {
+    let position = bytes.iter().position(|b| !b.is_ascii_digit()).unwrap_or(0);
+
+    ParseIntErr::with_byte_at(bytes[position], position)
+}
+
+/// Parses a single digit.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii::parse1,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(parse1(*b"7"), Ok(7));
+///     assert_eq!(parse1(*b"x"), Err(ParseIntErr::with_byte_at(b'x', 0)));
+/// }
+/// ```
+#[inline(always)]
+pub fn parse1(bytes: [u8; 1]) -> Result<u8, ParseIntErr> {
+    let d = bytes[0].wrapping_sub(ASCII_TO_INT_FACTOR);
+
+    if d > 9 {
+        return Err(short_invalid_digit(&bytes));
+    }
+
+    Ok(d)
+}
+
+/// Parses exactly two digits, like a percentage or the minutes of a time.
+///
+/// All digits are validated with a single compare.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii::parse2,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(parse2(*b"42"), Ok(42));
+///     assert_eq!(parse2(*b"07"), Ok(7));
+///     assert_eq!(parse2(*b"4x"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+/// }
+/// ```
+#[inline(always)]
+pub fn parse2(bytes: [u8; 2]) -> Result<u8, ParseIntErr> {
+    let a = bytes[0].wrapping_sub(ASCII_TO_INT_FACTOR);
+    let b = bytes[1].wrapping_sub(ASCII_TO_INT_FACTOR);
+
+    if a.max(b) > 9 {
+        return Err(short_invalid_digit(&bytes));
+    }
+
+    Ok(a * 10 + b)
+}
+
+/// Parses exactly three digits, like an HTTP status code or an octet of an IPv4 address.
+///
+/// All digits are validated with a single compare.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii::parse3,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(parse3(*b"404"), Ok(404));
+///     assert_eq!(parse3(*b"999"), Ok(999));
+///     assert_eq!(parse3(*b"2-0"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+/// }
+/// ```
+#[inline(always)]
+pub fn parse3(bytes: [u8; 3]) -> Result<u16, ParseIntErr> {
+    let a = bytes[0].wrapping_sub(ASCII_TO_INT_FACTOR);
+    let b = bytes[1].wrapping_sub(ASCII_TO_INT_FACTOR);
+    let c = bytes[2].wrapping_sub(ASCII_TO_INT_FACTOR);
+
+    if a.max(b).max(c) > 9 {
+        return Err(short_invalid_digit(&bytes));
+    }
+
+    Ok(u16::from(a) * 100 + u16::from(b) * 10 + u16::from(c))
+}
+
+/// Converts every slice of `inputs` with [`FromAscii::atoi`], writing the results into `out`.
+///
+/// The slices are converted four at a time, so the independent conversions can overlap in the CPU.
+///
+/// # Panics
+/// Panics if `inputs` and `out` differ in length.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii::atoi_many_into,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let mut out = [Ok(0); 3];
+///     atoi_many_into::<i16>(&[b"12", b"-3", b""], &mut out);
+///
+///     assert_eq!(out, [Ok(12), Ok(-3), Err(ParseIntErr::Empty)]);
+/// }
+/// ```
+#[inline]
+pub fn atoi_many_into<T: FromAscii>(inputs: &[&[u8]], out: &mut [Result<T, ParseIntErr>]) {
+    assert_eq!(inputs.len(), out.len(), "inputs and out differ in length");
+
+    let mut inputs = inputs.chunks_exact(4);
+    let mut out = out.chunks_exact_mut(4);
+
+    for (inputs, out) in inputs.by_ref().zip(out.by_ref()) {
+        let results = [
+            T::bytes_to_int(inputs[0]),
+            T::bytes_to_int(inputs[1]),
+            T::bytes_to_int(inputs[2]),
+            T::bytes_to_int(inputs[3]),
+        ];
+
+        for (slot, result) in out.iter_mut().zip(results) {
+            *slot = result;
+        }
+    }
+
+    for (input, slot) in inputs.remainder().iter().zip(out.into_remainder()) {
+        *slot = T::bytes_to_int(input);
+    }
+}
+
+/// Converts every slice of `inputs` with [`FromAscii::atoi`], see [`atoi_many_into`].
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii::atoi_many,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let fields: Vec<&[u8]> = b"1 22 333 x".split(|b| *b == b' ').collect();
+///
+///     assert_eq!(
+///         atoi_many::<u32>(&fields),
+///         [Ok(1), Ok(22), Ok(333), Err(ParseIntErr::with_byte_at(b'x', 0))]
+///     );
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn atoi_many<T: FromAscii>(inputs: &[&[u8]]) -> Vec<Result<T, ParseIntErr>> {
+    let mut out = Vec::with_capacity(inputs.len());
+    out.resize_with(inputs.len(), || Err(ParseIntErr::Empty));
+
+    atoi_many_into(inputs, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromAscii, ParseFieldErr, ParseIntErr};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn atoi_many() {
+        let inputs = (0..11i64).map(|n| (n * -97).to_string()).collect::<Vec<_>>();
+        let mut inputs = inputs.iter().map(String::as_bytes).collect::<Vec<_>>();
+        inputs[5] = b"5a";
+
+        let results = super::atoi_many::<i64>(&inputs);
+
+        assert_eq!(results.len(), 11);
+        assert_eq!(results[4], Ok(-388));
+        assert_eq!(results[5], Err(ParseIntErr::with_byte_at(b'a', 1)));
+        assert_eq!(results[10], Ok(-970));
+        assert!(super::atoi_many::<i64>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn atoi_many_into_lengths() {
+        let mut out = [Ok(0u8); 2];
+        super::atoi_many_into(&[b"1"], &mut out);
+    }
+
+    #[test]
+    fn parse_short() {
+        assert_eq!(super::parse1(*b"0"), Ok(0));
+        assert_eq!(super::parse2(*b"99"), Ok(99));
+        assert_eq!(super::parse3(*b"000"), Ok(0));
+        assert_eq!(super::parse3(*b"12:"), Err(ParseIntErr::with_byte_at(b':', 2)));
+        assert_eq!(super::parse2(*b"/0"), Err(ParseIntErr::with_byte_at(b'/', 0)));
+
+        // Short inputs take these paths, and still report positions within the whole input.
+        assert_eq!(u32::atoi("1-"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(u64::atoi("0012a"), Err(ParseIntErr::with_byte_at(b'a', 4)));
+        assert_eq!(i16::atoi("-999"), Ok(-999));
+    }
+
+    #[test]
+    fn swar_blocks() {
+        assert_eq!(super::swar_parse8(*b"12345678"), Ok(12_345_678));
+        assert_eq!(super::swar_parse8(*b"99999999"), Ok(99_999_999));
+        assert_eq!(super::swar_parse8(*b"0000000/"), Err(ParseIntErr::with_byte(b'/')));
+        assert_eq!(super::swar_parse8(*b"123:5678"), Err(ParseIntErr::with_byte(b':')));
+        assert_eq!(super::swar_parse8(*b"1234567\xb9"), Err(ParseIntErr::with_byte(0xb9)));
+
+        assert_eq!(u64::atoi("1234"), Ok(1234));
+        assert_eq!(u64::atoi("123456789"), Ok(123_456_789));
+        assert_eq!(u64::atoi("1000000000000000"), Ok(1_000_000_000_000_000));
+        assert_eq!(u64::atoi("18446744073709551615"), Ok(u64::MAX));
+
+        assert_eq!(u32::atoi("4294967295"), Ok(u32::MAX));
+        assert_eq!(u64::atoi("123456789012a"), Err(ParseIntErr::with_byte_at(b'a', 12)));
+        assert_eq!(u64::atoi("1234 5678"), Err(ParseIntErr::with_byte_at(b' ', 4)));
+    }
+
+    #[test]
+    fn every_length() {
+        let digits = b"98765432109876543210";
+
+        for len in 1..=digits.len() {
+            let input = &digits[..len];
+            let expected = input.iter().fold(0u64, |n, d| n.wrapping_mul(10).wrapping_add(u64::from(d - b'0')));
+
+            assert_eq!(u64::atoi(input), Ok(expected));
+
+            for position in 0..len {
+                let mut input = *digits;
+                input[position] = b'.';
+
+                assert_eq!(u64::atoi(&input[..len]), Err(ParseIntErr::with_byte_at(b'.', position)));
+            }
+        }
+    }
+
+    #[test]
+    fn digit_runs() {
+        use super::{first_non_digit, is_ascii_digits};
+
+        let digits = b"01234567890123456789012345678901234567890123456789012345678901234567";
+
+        for end in 0..digits.len() {
+            assert!(is_ascii_digits(&digits[..end]));
+
+            for &byte in &[b'/', b':', b' ', 0, 0x80, 0xB0, 0xFF] {
+                let mut input = *digits;
+                input[end] = byte;
+
+                assert_eq!(first_non_digit(&input), Some(end));
+                assert!(!is_ascii_digits(&input));
+            }
+        }
+    }
+
+    #[test]
+    fn to_u8() {
+        assert_eq!(u8::atoi("123"), Ok(123));
+        assert_eq!(u8::atoi("256"), Ok(0));
+
+        // Wraps around
+        assert_eq!(u8::atoi("257"), Ok(1));
+
+        // Error: InvalidDigit
+        assert_eq!(u8::atoi("!23"), Err(ParseIntErr::with_byte_at(b'!', 0)));
+
+        // Error: Overflow
+        assert_eq!(u8::atoi("1000"), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn leading_zeros() {
+        assert_eq!(u32::atoi("00000000001"), Ok(1));
+        assert_eq!(u8::atoi("0000255"), Ok(255));
+        assert_eq!(u64::atoi("00000000000000000000000"), Ok(0));
+        assert_eq!(i32::atoi("-0000000000042"), Ok(-42));
+
+        // Still an error after trimming the zeros.
+        assert_eq!(u8::atoi("0001000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u16::atoi("000000x1"), Err(ParseIntErr::with_byte_at(b'x', 6)));
+    }
+
+    #[test]
+    fn sign_only() {
+        assert_eq!(i32::atoi("-"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i8::atoi("-"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i32::atoi("-0"), Ok(0));
+    }
+
+    #[test]
+    fn negative_unsigned() {
+        assert_eq!(u32::atoi("-5"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi("-1000"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u64::atoi("-0"), Ok(0));
+        assert_eq!(u8::atoi("-000"), Ok(0));
+        assert_eq!(u8::atoi("-256"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi("-512"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u16::atoi("-65536"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u32::atoi("-4294967296"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u64::atoi("-18446744073709551616"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u16::atoi("-"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(u16::atoi("-1x"), Err(ParseIntErr::with_byte_at(b'x', 2)));
+        assert_eq!(u16::atoi("--1"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(i16::atoi("--11"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+    }
+
+    #[test]
+    fn invalid_digit_position() {
+        assert_eq!(u64::atoi("1234567890123x5").unwrap_err().position(), Some(13));
+        assert_eq!(u32::atoi("12345678x").unwrap_err().position(), Some(8));
+        assert_eq!(i64::atoi("-12 ").unwrap_err().position(), Some(3));
+        assert_eq!(u32::atoi("99999999999").unwrap_err().position(), None);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(u32::atoi(""), Err(ParseIntErr::Empty));
+        assert_eq!(i32::atoi(""), Err(ParseIntErr::Empty));
+        assert_eq!(u8::atoi(""), Err(ParseIntErr::Empty));
+    }
+
+    #[test]
+    fn overflow_isize() {
+        // overflows minimum value of the isize by 1
+        assert_eq!(isize::atoi("-9223372036854775809"), Err(ParseIntErr::NegOverflow));
+
+        // overflows maximum value of the isize by 1
+        assert_eq!(isize::atoi("9223372036854775808"), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!(u32::atoi_exp("12e6"), Ok(12_000_000));
+        assert_eq!(u32::atoi_exp("12E+6"), Ok(12_000_000));
+        assert_eq!(u32::atoi_exp("42"), Ok(42));
+        assert_eq!(u64::atoi_exp("0e99999999999"), Ok(0));
+        assert_eq!(i8::atoi_exp("-128e0"), Ok(i8::MIN));
+        assert_eq!(i8::atoi_exp("-12e1"), Ok(-120));
+
+        assert_eq!(i8::atoi_exp("-13e1"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u16::atoi_exp("7e4"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u64::atoi_exp("1e99999999999"), Err(ParseIntErr::PosOverflow));
+
+        // Unlike atoi, the mantissa doesn't wrap around either.
+        assert_eq!(u8::atoi_exp("256e0"), Err(ParseIntErr::PosOverflow));
+
+        assert_eq!(u32::atoi_exp("e5"), Err(ParseIntErr::with_byte_at(b'e', 0)));
+        assert_eq!(u32::atoi_exp("12e"), Err(ParseIntErr::with_byte_at(b'e', 2)));
+        assert_eq!(u32::atoi_exp("12e+"), Err(ParseIntErr::with_byte_at(b'+', 3)));
+        assert_eq!(u32::atoi_exp("1x2e3"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(u32::atoi_exp("12e3x"), Err(ParseIntErr::with_byte_at(b'x', 4)));
+        assert_eq!(u32::atoi_exp("12e3e4"), Err(ParseIntErr::with_byte_at(b'e', 4)));
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(u64::atoi_with_separators("1_000_000", b'_'), Ok(1_000_000));
+        assert_eq!(u64::atoi_with_separators("1000", b'_'), Ok(1000));
+        assert_eq!(u8::atoi_with_separators("2_5_5", b'_'), Ok(255));
+        assert_eq!(i8::atoi_with_separators("-1_28", b'_'), Ok(i8::MIN));
+        assert_eq!(u32::atoi_with_separators("-0", b'_'), Ok(0));
+        assert_eq!(u32::atoi_with_separators("1'000", b'\''), Ok(1000));
+
+        assert_eq!(u8::atoi_with_separators("2_56", b'_'), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i8::atoi_with_separators("-1_29", b'_'), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi_with_separators("-1", b'_'), Err(ParseIntErr::NegOverflow));
+
+        assert_eq!(u32::atoi_with_separators("", b'_'), Err(ParseIntErr::Empty));
+        assert_eq!(i32::atoi_with_separators("-", b'_'), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(u32::atoi_with_separators("_1", b'_'), Err(ParseIntErr::with_byte_at(b'_', 0)));
+        assert_eq!(i32::atoi_with_separators("-_1", b'_'), Err(ParseIntErr::with_byte_at(b'_', 1)));
+        assert_eq!(u32::atoi_with_separators("1_x", b'_'), Err(ParseIntErr::with_byte_at(b'_', 1)));
+        assert_eq!(u32::atoi_with_separators("1,000", b'_'), Err(ParseIntErr::with_byte_at(b',', 1)));
+    }
+
+    #[test]
+    fn grouped() {
+        assert_eq!(u64::atoi_grouped("1,234,567", b','), Ok(1_234_567));
+        assert_eq!(u64::atoi_grouped("12.345.678", b'.'), Ok(12_345_678));
+        assert_eq!(u64::atoi_grouped("123,456", b','), Ok(123_456));
+        assert_eq!(u64::atoi_grouped("1234567", b','), Ok(1_234_567));
+        assert_eq!(i16::atoi_grouped("-32,768", b','), Ok(i16::MIN));
+        assert_eq!(u16::atoi_grouped("65,536", b','), Err(ParseIntErr::PosOverflow));
+
+        assert_eq!(u64::atoi_grouped("1,23", b','), Err(ParseIntErr::with_byte_at(b'3', 3)));
+        assert_eq!(u64::atoi_grouped("1,2345", b','), Err(ParseIntErr::with_byte_at(b'5', 5)));
+        assert_eq!(u64::atoi_grouped("1,23,456", b','), Err(ParseIntErr::with_byte_at(b',', 4)));
+        assert_eq!(u64::atoi_grouped("1234,567", b','), Err(ParseIntErr::with_byte_at(b',', 4)));
+        assert_eq!(u64::atoi_grouped(",123", b','), Err(ParseIntErr::with_byte_at(b',', 0)));
+        assert_eq!(u64::atoi_grouped("1,234,", b','), Err(ParseIntErr::with_byte_at(b',', 5)));
+        assert_eq!(u64::atoi_grouped("1.234", b','), Err(ParseIntErr::with_byte_at(b'.', 1)));
+    }
+
+    #[test]
+    fn lenient() {
+        assert_eq!(i64::atoi_lenient("123"), (123, 3));
+        assert_eq!(i64::atoi_lenient(" \t\n-0042xyz"), (-42, 8));
+        assert_eq!(u64::atoi_lenient("+18446744073709551615 "), (u64::MAX, 21));
+        assert_eq!(u64::atoi_lenient("18446744073709551616"), (u64::MAX, 20));
+        assert_eq!(i8::atoi_lenient("-129"), (i8::MIN, 4));
+        assert_eq!(u8::atoi_lenient("-1"), (0, 2));
+        assert_eq!(u8::atoi_lenient("-0"), (0, 2));
+
+        // Without digits, nothing is consumed, not even the whitespace.
+        assert_eq!(i32::atoi_lenient(""), (0, 0));
+        assert_eq!(i32::atoi_lenient("   "), (0, 0));
+        assert_eq!(i32::atoi_lenient(" -"), (0, 0));
+        assert_eq!(i32::atoi_lenient("+-1"), (0, 0));
+    }
+
+    #[test]
+    fn partial() {
+        assert_eq!(u64::atoi_partial("42"), Ok((42, 2)));
+        assert_eq!(u64::atoi_partial("0042 "), Ok((42, 4)));
+        assert_eq!(i8::atoi_partial("-128-1"), Ok((i8::MIN, 4)));
+        assert_eq!(u8::atoi_partial("-0x"), Ok((0, 2)));
+
+        assert_eq!(u64::atoi_partial(""), Err(ParseIntErr::Empty));
+        assert_eq!(i64::atoi_partial("-"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i64::atoi_partial("-x"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(i64::atoi_partial(" 1"), Err(ParseIntErr::with_byte_at(b' ', 0)));
+        assert_eq!(u8::atoi_partial("256,"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_partial("-1,"), Err(ParseIntErr::NegOverflow));
+    }
+
+    #[test]
+    fn split() {
+        let (a, rest) = i32::atoi_split("-1 2 3").unwrap();
+        let (b, rest) = i32::atoi_split(&rest[1..]).unwrap();
+        let (c, rest) = i32::atoi_split(&rest[1..]).unwrap();
+
+        assert_eq!((a, b, c), (-1, 2, 3));
+        assert_eq!(rest, b"");
+
+        assert_eq!(u8::atoi_split(&b"7;"[..]), Ok((7, &b";"[..])));
+        assert_eq!(u8::atoi_split(";"), Err(ParseIntErr::with_byte_at(b';', 0)));
+    }
+
+    #[test]
+    fn fixed_width() {
+        assert_eq!(u32::atoi_n::<6, _>("000042rest"), Ok((42, &b"rest"[..])));
+        assert_eq!(u8::atoi_n::<3, _>("255"), Ok((255, &b""[..])));
+        assert_eq!(u8::atoi_n::<0, _>("1"), Ok((0, &b"1"[..])));
+        assert_eq!(i16::atoi_n::<5, _>("32767"), Ok((i16::MAX, &b""[..])));
+        assert_eq!(i16::atoi_n::<5, _>("32768"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u64::atoi_n::<20, _>("18446744073709551615"), Ok((u64::MAX, &b""[..])));
+        assert_eq!(u64::atoi_n::<20, _>("18446744073709551616"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u64::atoi_n::<21, _>("000000000000000000001"), Ok((1, &b""[..])));
+
+        assert_eq!(u32::atoi_n::<4, _>(""), Err(ParseIntErr::Empty));
+        assert_eq!(u32::atoi_n::<4, _>("123"), Err(ParseIntErr::with_byte_at(b'3', 2)));
+        assert_eq!(i32::atoi_n::<4, _>("-123"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i32::atoi_n::<4, _>("-12a"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(u32::atoi_n::<4, _>("1 23"), Err(ParseIntErr::with_byte_at(b' ', 1)));
+        assert_eq!(u64::atoi_n::<20, _>("1844674407370955161x"), Err(ParseIntErr::with_byte_at(b'x', 19)));
+    }
+
+    #[test]
+    fn signed_full_range() {
+        assert_eq!(i8::atoi("-128"), Ok(i8::MIN));
+        assert_eq!(i8::atoi("127"), Ok(i8::MAX));
+        assert_eq!(i8::atoi("128"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i8::atoi("-129"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(i8::atoi("-256"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(i8::atoi("999"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i8::atoi("-1000"), Err(ParseIntErr::NegOverflow));
+
+        assert_eq!(i64::atoi("-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(i64::atoi("9223372036854775807"), Ok(i64::MAX));
+        assert_eq!(i64::atoi("18446744073709551616"), Err(ParseIntErr::PosOverflow));
+
+        assert_eq!(i16::atoi("-32x68"), Err(ParseIntErr::with_byte_at(b'x', 3)));
+        assert_eq!(i16::atoi("-3276x"), Err(ParseIntErr::with_byte_at(b'x', 5)));
+    }
+
+    #[test]
+    fn pair() {
+        assert_eq!(u32::atoi_pair("1920x1080", b'x'), Ok((1920, 1080)));
+        assert_eq!(i8::atoi_pair("-1:-128", b':'), Ok((-1, -128)));
+
+        // Only the first separator splits.
+        assert_eq!(
+            u8::atoi_pair("1,2,3", b','),
+            Err(ParseFieldErr { field: 1, error: ParseIntErr::with_byte_at(b',', 1) })
+        );
+        assert_eq!(u8::atoi_pair(",2", b','), Err(ParseFieldErr { field: 0, error: ParseIntErr::Empty }));
+        assert_eq!(u8::atoi_pair("1,", b','), Err(ParseFieldErr { field: 1, error: ParseIntErr::Empty }));
+        assert_eq!(u8::atoi_pair("12", b','), Err(ParseFieldErr { field: 1, error: ParseIntErr::Empty }));
+        assert_eq!(
+            i8::atoi_pair("200,1", b','),
+            Err(ParseFieldErr { field: 0, error: ParseIntErr::PosOverflow })
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let digits = |s: &'static str| s.bytes();
+
+        assert_eq!(u64::atoi_iter(digits("18446744073709551615")), Ok(u64::MAX));
+        assert_eq!(i64::atoi_iter(digits("-9223372036854775808")), Ok(i64::MIN));
+        assert_eq!(u8::atoi_iter(digits("-0")), Ok(0));
+        assert_eq!(u8::atoi_iter(digits("007")), Ok(7));
+
+        assert_eq!(u8::atoi_iter(digits("")), Err(ParseIntErr::Empty));
+        assert_eq!(i8::atoi_iter(digits("-")), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i8::atoi_iter(digits("--1")), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(i8::atoi_iter(digits("1-")), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(i8::atoi_iter(digits("-129")), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi_iter(digits("-1")), Err(ParseIntErr::NegOverflow));
+    }
+
+    #[test]
+    fn slices() {
+        let number = b"-9223372036854775808";
+
+        for split in 0..=number.len() {
+            let (head, tail) = number.split_at(split);
+
+            assert_eq!(i64::atoi_slices((head, tail)), Ok(i64::MIN));
+            assert_eq!(u64::atoi_slices((head, tail)), Err(ParseIntErr::NegOverflow));
+        }
+
+        assert_eq!(u8::atoi_slices((b"", b"")), Err(ParseIntErr::Empty));
+        assert_eq!(i8::atoi_slices((b"-", b"")), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(u16::atoi_slices((b"12", b"3x")), Err(ParseIntErr::with_byte_at(b'x', 3)));
+        assert_eq!(u16::atoi_slices((b"", b"3x")), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(u8::atoi_slices((b"25", b"6")), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn until_nul() {
+        assert_eq!(u8::atoi_until_nul(b"255\0"), Ok((255, 3)));
+        assert_eq!(u8::atoi_until_nul(b"256\0"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_until_nul(b""), Err(ParseIntErr::Empty));
+        assert_eq!(u8::atoi_until_nul(b"\0"), Err(ParseIntErr::with_byte_at(0, 0)));
+        assert_eq!(i8::atoi_until_nul(b"-\0"), Err(ParseIntErr::with_byte_at(0, 1)));
+        assert_eq!(u8::atoi_cstr(core::ffi::CStr::from_bytes_with_nul(b"\0").unwrap()), Err(ParseIntErr::with_byte_at(0, 0)));
+        assert_eq!(i64::atoi_cstr(core::ffi::CStr::from_bytes_with_nul(b"-1\0").unwrap()), Ok((-1, 2)));
     }
 }
@@ -0,0 +1,215 @@
+//! Parsing of delimited numbers, straight into arrays and tuples, or into any collection.
+use core::iter::FromIterator;
+
+use crate::{
+    error::{ParseFieldErr, ParseIntErr},
+    from_ascii::FromAscii,
+    record::Record,
+};
+
+/// This trait converts delimited numbers into arrays of integers, and tuples of up to 4 integers.
+///
+/// Every field is converted with [`FromAscii::atoi`]. There must be exactly as many fields as elements:
+/// a missing field is [`ParseIntErr::Empty`], and an extra field is reported as the delimiter
+/// that ends the last expected field. An empty array only takes empty input, and reports anything else at its first byte.
+/// Errors carry the index of the field, with positions relative to its start.
+pub trait FromAsciiDelim: Sized {
+    /// Converts the fields of `s`, split on `delimiter`.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_delim::FromAsciiDelim,
+    ///     error::{ParseFieldErr, ParseIntErr},
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(<[u32; 3]>::atoi_delim("1 2 3", b' '), Ok([1, 2, 3]));
+    ///     assert_eq!(<(u8, i64)>::atoi_delim("7;-1", b';'), Ok((7, -1)));
+    ///     assert_eq!(
+    ///         <[u32; 3]>::atoi_delim("1 2 3 4", b' '),
+    ///         Err(ParseFieldErr { field: 2, error: ParseIntErr::with_byte_at(b' ', 1) })
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_delim(s: impl AsRef<[u8]>, delimiter: u8) -> Result<Self, ParseFieldErr> {
+        Self::bytes_to_delim(s.as_ref(), delimiter)
+    }
+
+    fn bytes_to_delim(s: &[u8], delimiter: u8) -> Result<Self, ParseFieldErr>;
+}
+
+impl<T, const N: usize> FromAsciiDelim for [T; N]
+where
+    T: FromAscii + Copy + Default,
+{
+    #[inline]
+    fn bytes_to_delim(s: &[u8], delimiter: u8) -> Result<Self, ParseFieldErr> {
+        let mut record = Record::new(s, delimiter);
+        let mut array = [T::default(); N];
+
+        // There's no last field to take the rest.
+        if let (0, Some(&byte)) = (N, s.first()) {
+            return Err(ParseFieldErr { field: 0, error: ParseIntErr::with_byte_at(byte, 0) });
+        }
+
+        for (idx, element) in array.iter_mut().enumerate() {
+            // The last field takes the rest, so an extra field shows up as an invalid digit.
+            let take = if idx + 1 == N { Record::rest } else { Record::field };
+
+            *element = record.parse_with(take, T::bytes_to_int)?;
+        }
+
+        Ok(array)
+    }
+}
+
+macro_rules! tuple_from_ascii_delim {
+    ($($init:ident)*; $last:ident) => {
+        impl<$($init: FromAscii,)* $last: FromAscii> FromAsciiDelim for ($($init,)* $last,) {
+            #[inline]
+            fn bytes_to_delim(s: &[u8], delimiter: u8) -> Result<Self, ParseFieldErr> {
+                let mut record = Record::new(s, delimiter);
+
+                Ok((
+                    $(record.parse_with(Record::field, $init::bytes_to_int)?,)*
+                    record.parse_with(Record::rest, $last::bytes_to_int)?,
+                ))
+            }
+        }
+    };
+}
+
+tuple_from_ascii_delim!(; A);
+tuple_from_ascii_delim!(A; B);
+tuple_from_ascii_delim!(A B; C);
+tuple_from_ascii_delim!(A B C; D);
+
+/// What separates the numbers in [`parse_all`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Delimiter {
+    /// A single byte between every two numbers. Every field must hold a number.
+    Byte(u8),
+
+    /// Any run of ASCII whitespace. Whitespace at the start and end is ignored.
+    Whitespace,
+}
+
+impl From<u8> for Delimiter {
+    #[inline]
+    fn from(byte: u8) -> Self {
+        Delimiter::Byte(byte)
+    }
+}
+
+/// Converts every number in `bytes` with [`FromAscii::atoi`], and collects them into any collection.
+///
+/// The first error stops the parsing, and carries the index of the number it was found in,
+/// with positions relative to the start of that number. Empty input is an empty collection.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii_delim::{parse_all, Delimiter},
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let numbers: Vec<u32> = parse_all(b"1,2,3", Delimiter::Byte(b',')).unwrap();
+///     assert_eq!(numbers, [1, 2, 3]);
+///
+///     let numbers: Vec<i8> = parse_all(b" 4\t-5\n 6\n", Delimiter::Whitespace).unwrap();
+///     assert_eq!(numbers, [4, -5, 6]);
+///
+///     assert_eq!(
+///         parse_all::<u32, Vec<_>>(b"1,,3", b','.into()),
+///         Err(ParseFieldErr { field: 1, error: ParseIntErr::Empty })
+///     );
+/// }
+/// ```
+#[inline]
+pub fn parse_all<T, C>(bytes: &[u8], delimiter: Delimiter) -> Result<C, ParseFieldErr>
+where
+    T: FromAscii,
+    C: FromIterator<T>,
+{
+    fn collect<'a, T: FromAscii, C: FromIterator<T>>(
+        tokens: impl Iterator<Item = &'a [u8]>,
+    ) -> Result<C, ParseFieldErr> {
+        tokens
+            .enumerate()
+            .map(|(field, token)| T::bytes_to_int(token).map_err(|error| ParseFieldErr { field, error }))
+            .collect()
+    }
+
+    match delimiter {
+        _ if bytes.is_empty() => Ok(C::from_iter(None)),
+        Delimiter::Byte(delimiter) => collect(bytes.split(|b| *b == delimiter)),
+        Delimiter::Whitespace => collect(
+            bytes
+                .split(|b| b.is_ascii_whitespace())
+                .filter(|token| !token.is_empty()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_all, Delimiter, FromAsciiDelim, ParseFieldErr};
+    use crate::error::ParseIntErr;
+
+    fn err(field: usize, error: ParseIntErr) -> ParseFieldErr {
+        ParseFieldErr { field, error }
+    }
+
+    #[test]
+    fn arrays() {
+        assert_eq!(<[u8; 1]>::atoi_delim("255", b','), Ok([255]));
+        assert_eq!(<[i16; 4]>::atoi_delim("-1,0,1,32767", b','), Ok([-1, 0, 1, 32767]));
+        assert_eq!(<[u64; 2]>::atoi_delim("1,", b','), Err(err(1, ParseIntErr::Empty)));
+        assert_eq!(<[u64; 3]>::atoi_delim("1,2", b','), Err(err(2, ParseIntErr::Empty)));
+        assert_eq!(<[u64; 2]>::atoi_delim("1,x", b','), Err(err(1, ParseIntErr::with_byte_at(b'x', 0))));
+        assert_eq!(<[u64; 2]>::atoi_delim("1  2", b' '), Err(err(1, ParseIntErr::with_byte_at(b' ', 0))));
+        assert_eq!(<[u32; 0]>::atoi_delim("", b','), Ok([]));
+        assert_eq!(<[u32; 0]>::atoi_delim("1,2,3", b','), Err(err(0, ParseIntErr::with_byte_at(b'1', 0))));
+        assert_eq!(<[u32; 0]>::atoi_delim(",", b','), Err(err(0, ParseIntErr::with_byte_at(b',', 0))));
+    }
+
+    #[test]
+    fn tuples() {
+        assert_eq!(<(u8,)>::atoi_delim("1", b','), Ok((1,)));
+        assert_eq!(<(u8, i8, u16, i32)>::atoi_delim("1|-2|3|-4", b'|'), Ok((1, -2, 3, -4)));
+        assert_eq!(<(u32, u32, u32)>::atoi_delim("1|2|3|4", b'|'), Err(err(2, ParseIntErr::with_byte_at(b'|', 1))));
+        assert_eq!(<(i8, i8)>::atoi_delim("-1|-200", b'|'), Err(err(1, ParseIntErr::NegOverflow)));
+    }
+
+    #[test]
+    fn all() {
+        let numbers: [u8; 0] = [];
+        assert_eq!(parse_all::<u8, std::vec::Vec<_>>(b"", Delimiter::Byte(b',')), Ok(numbers.to_vec()));
+        assert_eq!(parse_all::<u8, std::vec::Vec<_>>(b" \r\n", Delimiter::Whitespace), Ok(numbers.to_vec()));
+        assert_eq!(
+            parse_all::<u16, std::vec::Vec<_>>(b"1;65535", Delimiter::Byte(b';')),
+            Ok(std::vec![1, 65535])
+        );
+
+        // Any collection works, and the first error is the one reported.
+        assert_eq!(
+            parse_all::<i32, std::collections::BTreeSet<_>>(b"3 1  2 1\n", Delimiter::Whitespace),
+            Ok([1, 2, 3].iter().copied().collect())
+        );
+        assert_eq!(
+            parse_all::<i32, std::vec::Vec<_>>(b"1 2 3x 4y", Delimiter::Whitespace),
+            Err(err(2, ParseIntErr::with_byte_at(b'x', 1)))
+        );
+        assert_eq!(
+            parse_all::<i32, std::vec::Vec<_>>(b"1,2,", Delimiter::Byte(b',')),
+            Err(err(2, ParseIntErr::Empty))
+        );
+        assert_eq!(
+            parse_all::<i32, std::vec::Vec<_>>(b"1, 2", Delimiter::Byte(b',')),
+            Err(err(1, ParseIntErr::with_byte_at(b' ', 0)))
+        );
+    }
+}
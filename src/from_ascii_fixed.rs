@@ -0,0 +1,434 @@
+//! Parsing of decimal numbers into scaled integers.
+//!
+//! A number like `12.34` at a scale of 2 becomes `1234`: the integer number of hundredths.
+//! This represents prices, measurements and rates exactly, without going through floats.
+use crate::{constants::ASCII_TO_INT_FACTOR, error::ParseIntErr};
+
+/// What to do with fraction digits beyond the scale.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Rounding {
+    /// Reject them with an [`ParseIntErr::InvalidDigit`] at the first one that isn't `0`.
+    /// Trailing zeros don't change the value, so those are always accepted.
+    Reject,
+
+    /// Drop them, rounding towards zero.
+    Truncate,
+
+    /// Round to the nearest value, and away from zero if exactly halfway.
+    HalfAwayFromZero,
+
+    /// Round to the nearest value, and to an even last digit if exactly halfway.
+    HalfEven,
+}
+
+/// This trait converts decimal numbers to scaled integers,
+/// and is implemented on all integer types, except u128 and i128.
+///
+/// The accepted syntax is an optional `-`, digits, and optionally a `.` followed by more digits.
+/// There must be at least one digit, before or after the `.`.
+pub trait FromAsciiFixed: Sized {
+    /// Converts a decimal number to an integer in units of `10^-scale`,
+    /// rejecting fraction digits that don't fit in the scale.
+    /// Unlike [`crate::from_ascii::FromAscii::atoi`], this never wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_fixed::FromAsciiFixed,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(i64::atoi_fixed("12.34", 2), Ok(1234));
+    ///     assert_eq!(i64::atoi_fixed("-0.5", 2), Ok(-50));
+    ///     assert_eq!(u32::atoi_fixed("7", 3), Ok(7000));
+    ///     assert_eq!(u32::atoi_fixed("1.234", 2), Err(ParseIntErr::with_byte_at(b'4', 4)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_fixed(s: impl AsRef<[u8]>, scale: u32) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_fixed(s.as_ref(), scale, Rounding::Reject)
+    }
+
+    /// Like [`FromAsciiFixed::atoi_fixed`], but handles fraction digits beyond the scale as `rounding` says.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii_fixed::{FromAsciiFixed, Rounding};
+    ///
+    /// fn main() {
+    ///     assert_eq!(i32::atoi_fixed_with("1.235", 2, Rounding::Truncate), Ok(123));
+    ///     assert_eq!(i32::atoi_fixed_with("-1.235", 2, Rounding::HalfAwayFromZero), Ok(-124));
+    ///     assert_eq!(i32::atoi_fixed_with("1.225", 2, Rounding::HalfEven), Ok(122));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_fixed_with(s: impl AsRef<[u8]>, scale: u32, rounding: Rounding) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_fixed(s.as_ref(), scale, rounding)
+    }
+
+    fn bytes_to_fixed(s: &[u8], scale: u32, rounding: Rounding) -> Result<Self, ParseIntErr>;
+
+    /// Converts a percentage, a decimal number followed by `%`, to an integer in units of `10^-scale` percent.
+    /// Like [`FromAsciiFixed::atoi_fixed`], fraction digits that don't fit in the scale are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_fixed::FromAsciiFixed,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_percent("12.5%", 1), Ok(125));
+    ///     assert_eq!(u32::atoi_percent("100%", 0), Ok(100));
+    ///     assert_eq!(u32::atoi_percent("12.5", 1), Err(ParseIntErr::with_byte_at(b'5', 3)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_percent(s: impl AsRef<[u8]>, scale: u32) -> Result<Self, ParseIntErr> {
+        let bytes = s.as_ref();
+
+        match bytes.strip_suffix(b"%") {
+            Some([]) => Err(ParseIntErr::with_byte_at(b'%', 0)),
+            Some(number) => Self::bytes_to_fixed(number, scale, Rounding::Reject),
+            // Report any invalid byte before complaining about the missing '%'.
+            None => Self::bytes_to_fixed(bytes, scale, Rounding::Reject).and_then(|_| {
+                let last = bytes.len() - 1;
+                Err(ParseIntErr::with_byte_at(bytes[last], last))
+            }),
+        }
+    }
+
+    /// Converts a percentage to basis points, hundredths of a percent.
+    /// This is the same as calling [`FromAsciiFixed::atoi_percent`] with a scale of 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii_fixed::FromAsciiFixed;
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_bps("12.5%"), Ok(1250));
+    ///     assert_eq!(i32::atoi_bps("-0.01%"), Ok(-1));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_bps(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::atoi_percent(s, 2)
+    }
+}
+
+#[inline(always)]
+fn digit_at(bytes: &[u8], idx: usize) -> Option<u8> {
+    bytes
+        .get(idx)
+        .map(|b| b.wrapping_sub(ASCII_TO_INT_FACTOR))
+        .filter(|d| *d <= 9)
+}
+
+macro_rules! from_ascii_fixed {
+    ($($int:ty),*) => {
+        $(
+            impl FromAsciiFixed for $int {
+                // Negative numbers are accumulated downwards, so MIN is reachable.
+                // For unsigned types that fails on the first non-zero digit, which is exactly when it is out of range.
+                #[inline]
+                fn bytes_to_fixed(bytes: &[u8], scale: u32, rounding: Rounding) -> Result<Self, ParseIntErr> {
+                    let is_negative = match bytes {
+                        [] => return Err(ParseIntErr::Empty),
+                        [b'-', ..] => true,
+                        _ => false,
+                    };
+
+                    let overflow = if is_negative {
+                        ParseIntErr::NegOverflow
+                    } else {
+                        ParseIntErr::PosOverflow
+                    };
+
+                    let push = |acc: Self, d: u8| -> Result<Self, ParseIntErr> {
+                        let acc = acc.checked_mul(10);
+
+                        if is_negative {
+                            acc.and_then(|acc| acc.checked_sub(d as Self))
+                        } else {
+                            acc.and_then(|acc| acc.checked_add(d as Self))
+                        }
+                        .ok_or(overflow)
+                    };
+
+                    let mut idx = is_negative as usize;
+                    let mut acc: Self = 0;
+                    let mut any_digits = false;
+
+                    while let Some(d) = digit_at(bytes, idx) {
+                        acc = push(acc, d)?;
+                        any_digits = true;
+                        idx += 1;
+                    }
+
+                    let mut fraction_digits = 0;
+                    // The first digit beyond the scale, and whether any non-zero digit follows it.
+                    let mut first_excess = None;
+                    let mut sticky = false;
+
+                    if let Some(b'.') = bytes.get(idx) {
+                        idx += 1;
+
+                        while let Some(d) = digit_at(bytes, idx) {
+                            if fraction_digits < scale {
+                                acc = push(acc, d)?;
+                                fraction_digits += 1;
+                            } else if d != 0 && rounding == Rounding::Reject {
+                                return Err(ParseIntErr::with_byte_at(bytes[idx], idx));
+                            } else if first_excess.is_none() {
+                                first_excess = Some(d);
+                            } else {
+                                sticky |= d != 0;
+                            }
+
+                            any_digits = true;
+                            idx += 1;
+                        }
+                    }
+
+                    if !any_digits {
+                        return Err(ParseIntErr::at_or_last(bytes, idx));
+                    }
+
+                    if let Some(b) = bytes.get(idx) {
+                        return Err(ParseIntErr::with_byte_at(*b, idx));
+                    }
+
+                    for _ in fraction_digits..scale {
+                        acc = push(acc, 0)?;
+                    }
+
+                    let round_up = match (rounding, first_excess) {
+                        (Rounding::HalfAwayFromZero, Some(d)) => d >= 5,
+                        (Rounding::HalfEven, Some(d)) => d > 5 || (d == 5 && (sticky || acc % 2 != 0)),
+                        _ => false,
+                    };
+
+                    if round_up {
+                        acc = if is_negative { acc.checked_sub(1) } else { acc.checked_add(1) }.ok_or(overflow)?;
+                    }
+
+                    Ok(acc)
+                }
+            }
+        )*
+    };
+}
+
+from_ascii_fixed!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// The currency symbols [`atoi_cents`] accepts, as UTF-8.
+const CURRENCY_SYMBOLS: [&[u8]; 4] = [b"$", "\u{20ac}".as_bytes(), "\u{a3}".as_bytes(), "\u{a5}".as_bytes()];
+
+/// Converts a money amount to cents.
+/// It takes anything that can be transformed into a byte-slice.
+///
+/// The amount may start with a `-` and a currency symbol (`$`, `€`, `£` or `¥`), in either order.
+/// The integer part may be grouped by `,` in groups of 3 digits,
+/// and it must be followed by a `.` and exactly 2 fraction digits.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii_fixed::atoi_cents,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(atoi_cents("€1,234.56"), Ok(123_456));
+///     assert_eq!(atoi_cents("-$0.99"), Ok(-99));
+///     assert_eq!(atoi_cents("1234567.00"), Ok(123_456_700));
+///     assert_eq!(atoi_cents("12,34.56"), Err(ParseIntErr::with_byte_at(b'.', 5)));
+/// }
+/// ```
+#[inline]
+pub fn atoi_cents(s: impl AsRef<[u8]>) -> Result<i64, ParseIntErr> {
+    bytes_to_cents(s.as_ref())
+}
+
+/// Converts a byteslice with a money amount to cents. See [`atoi_cents`] for the accepted syntax.
+pub fn bytes_to_cents(bytes: &[u8]) -> Result<i64, ParseIntErr> {
+    if bytes.is_empty() {
+        return Err(ParseIntErr::Empty);
+    }
+
+    let mut idx = 0;
+    let mut is_negative = bytes[0] == b'-';
+    idx += is_negative as usize;
+
+    if let Some(symbol) = CURRENCY_SYMBOLS.iter().find(|symbol| bytes[idx..].starts_with(symbol)) {
+        idx += symbol.len();
+    }
+
+    if !is_negative && bytes.get(idx) == Some(&b'-') {
+        is_negative = true;
+        idx += 1;
+    }
+
+    let overflow = if is_negative {
+        ParseIntErr::NegOverflow
+    } else {
+        ParseIntErr::PosOverflow
+    };
+
+    let push = |acc: i64, d: u8| -> Result<i64, ParseIntErr> {
+        let acc = acc.checked_mul(10);
+
+        if is_negative {
+            acc.and_then(|acc| acc.checked_sub(i64::from(d)))
+        } else {
+            acc.and_then(|acc| acc.checked_add(i64::from(d)))
+        }
+        .ok_or(overflow)
+    };
+
+    let mut acc = 0;
+    let mut group_len = 0;
+    let mut grouped = false;
+
+    loop {
+        match bytes.get(idx) {
+            Some(b',') => {
+                // The first group has 1 to 3 digits, all others exactly 3.
+                if group_len == 0 || group_len > 3 || (grouped && group_len != 3) {
+                    return Err(ParseIntErr::at_or_last(bytes, idx));
+                }
+
+                grouped = true;
+                group_len = 0;
+            }
+            Some(_) => match digit_at(bytes, idx) {
+                Some(_) if grouped && group_len == 3 => return Err(ParseIntErr::at_or_last(bytes, idx)),
+                Some(d) => {
+                    acc = push(acc, d)?;
+                    group_len += 1;
+                }
+                None => break,
+            },
+            None => break,
+        }
+
+        idx += 1;
+    }
+
+    if group_len == 0 || (grouped && group_len != 3) || bytes.get(idx) != Some(&b'.') {
+        return Err(ParseIntErr::at_or_last(bytes, idx));
+    }
+
+    for _ in 0..2 {
+        idx += 1;
+
+        match digit_at(bytes, idx) {
+            Some(d) => acc = push(acc, d)?,
+            None => return Err(ParseIntErr::at_or_last(bytes, idx)),
+        }
+    }
+
+    match bytes.get(idx + 1) {
+        Some(b) => Err(ParseIntErr::with_byte_at(*b, idx + 1)),
+        None => Ok(acc),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{atoi_cents, FromAsciiFixed, ParseIntErr, Rounding};
+
+    #[test]
+    fn fixed() {
+        assert_eq!(i64::atoi_fixed("12.34", 2), Ok(1234));
+        assert_eq!(i64::atoi_fixed("12.3", 2), Ok(1230));
+        assert_eq!(i64::atoi_fixed("12.", 2), Ok(1200));
+        assert_eq!(i64::atoi_fixed(".05", 2), Ok(5));
+        assert_eq!(i64::atoi_fixed("-.05", 2), Ok(-5));
+        assert_eq!(i64::atoi_fixed("12.3400", 2), Ok(1234));
+        assert_eq!(u32::atoi_fixed("-0.00", 2), Ok(0));
+        assert_eq!(u8::atoi_fixed("2.55", 2), Ok(255));
+        assert_eq!(i8::atoi_fixed("-1.28", 2), Ok(i8::MIN));
+        assert_eq!(i32::atoi_fixed("42", 0), Ok(42));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(i64::atoi_fixed("", 2), Err(ParseIntErr::Empty));
+        assert_eq!(i64::atoi_fixed("-", 2), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i64::atoi_fixed(".", 2), Err(ParseIntErr::with_byte_at(b'.', 0)));
+        assert_eq!(i64::atoi_fixed("1.2.3", 2), Err(ParseIntErr::with_byte_at(b'.', 3)));
+        assert_eq!(i64::atoi_fixed("1,5", 2), Err(ParseIntErr::with_byte_at(b',', 1)));
+        assert_eq!(i64::atoi_fixed("1.2301", 2), Err(ParseIntErr::with_byte_at(b'1', 5)));
+        assert_eq!(i64::atoi_fixed("1.23x", 2), Err(ParseIntErr::with_byte_at(b'x', 4)));
+
+        assert_eq!(u8::atoi_fixed("2.56", 2), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_fixed("3", 2), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_fixed("-0.01", 2), Err(ParseIntErr::NegOverflow));
+        assert_eq!(i8::atoi_fixed("-1.29", 2), Err(ParseIntErr::NegOverflow));
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(i32::atoi_fixed_with("1.2349", 2, Rounding::Truncate), Ok(123));
+        assert_eq!(i32::atoi_fixed_with("-1.2399", 2, Rounding::Truncate), Ok(-123));
+
+        assert_eq!(i32::atoi_fixed_with("1.235", 2, Rounding::HalfAwayFromZero), Ok(124));
+        assert_eq!(i32::atoi_fixed_with("1.2349", 2, Rounding::HalfAwayFromZero), Ok(123));
+        assert_eq!(i32::atoi_fixed_with("-1.235", 2, Rounding::HalfAwayFromZero), Ok(-124));
+
+        assert_eq!(i32::atoi_fixed_with("1.225", 2, Rounding::HalfEven), Ok(122));
+        assert_eq!(i32::atoi_fixed_with("1.235", 2, Rounding::HalfEven), Ok(124));
+        assert_eq!(i32::atoi_fixed_with("1.2251", 2, Rounding::HalfEven), Ok(123));
+        assert_eq!(i32::atoi_fixed_with("-1.225", 2, Rounding::HalfEven), Ok(-122));
+
+        // Rounding up can overflow as well.
+        assert_eq!(u8::atoi_fixed_with("2.555", 2, Rounding::HalfAwayFromZero), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i8::atoi_fixed_with("-1.286", 2, Rounding::HalfEven), Err(ParseIntErr::NegOverflow));
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(u16::atoi_bps("0%"), Ok(0));
+        assert_eq!(u16::atoi_bps("0.25%"), Ok(25));
+        assert_eq!(u16::atoi_bps("100%"), Ok(10_000));
+        assert_eq!(i64::atoi_percent("-7.125%", 3), Ok(-7125));
+
+        assert_eq!(u16::atoi_bps(""), Err(ParseIntErr::Empty));
+        assert_eq!(u16::atoi_bps("%"), Err(ParseIntErr::with_byte_at(b'%', 0)));
+        assert_eq!(u16::atoi_bps("12"), Err(ParseIntErr::with_byte_at(b'2', 1)));
+        assert_eq!(u16::atoi_bps("1x%"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(u16::atoi_bps("1%%"), Err(ParseIntErr::with_byte_at(b'%', 1)));
+        assert_eq!(u16::atoi_bps("0.125%"), Err(ParseIntErr::with_byte_at(b'5', 4)));
+        assert_eq!(u16::atoi_bps("1000%"), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn cents() {
+        assert_eq!(atoi_cents("0.00"), Ok(0));
+        assert_eq!(atoi_cents("$1.50"), Ok(150));
+        assert_eq!(atoi_cents("\u{20ac}1,234.56"), Ok(123_456));
+        assert_eq!(atoi_cents("\u{a3}-12.00"), Ok(-1200));
+        assert_eq!(atoi_cents("-\u{a5}1,000,000.01"), Ok(-100_000_001));
+        assert_eq!(atoi_cents("-92,233,720,368,547,758.08"), Ok(i64::MIN));
+        assert_eq!(atoi_cents("92233720368547758.08"), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn cents_errors() {
+        assert_eq!(atoi_cents(""), Err(ParseIntErr::Empty));
+        assert_eq!(atoi_cents("$"), Err(ParseIntErr::with_byte_at(b'$', 0)));
+        assert_eq!(atoi_cents("12"), Err(ParseIntErr::with_byte_at(b'2', 1)));
+        assert_eq!(atoi_cents("12.5"), Err(ParseIntErr::with_byte_at(b'5', 3)));
+        assert_eq!(atoi_cents("12.505"), Err(ParseIntErr::with_byte_at(b'5', 5)));
+        assert_eq!(atoi_cents(",123.00"), Err(ParseIntErr::with_byte_at(b',', 0)));
+        assert_eq!(atoi_cents("1234,567.00"), Err(ParseIntErr::with_byte_at(b',', 4)));
+        assert_eq!(atoi_cents("1,2345.00"), Err(ParseIntErr::with_byte_at(b'5', 5)));
+        assert_eq!(atoi_cents("1,,234.00"), Err(ParseIntErr::with_byte_at(b',', 2)));
+        assert_eq!(atoi_cents("1,234,.00"), Err(ParseIntErr::with_byte_at(b'.', 6)));
+        assert_eq!(atoi_cents("--1.00"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(atoi_cents("$$1.00"), Err(ParseIntErr::with_byte_at(b'$', 1)));
+    }
+}
@@ -0,0 +1,744 @@
+//! Correctly rounded parsing of floats.
+//!
+//! Most inputs are converted with one of two fast paths:
+//! Clinger's fast path for short mantissas with small exponents, where a single float multiplication or division is exact,
+//! and the Eisel–Lemire algorithm, which uses a 128-bit approximation of the power of 5 for everything else.
+//! The few inputs neither can round correctly, such as those with more than 19 significant digits, fall back to the parser in `core`.
+use core::ops::{Div, Mul};
+
+use crate::error::ParseFloatErr;
+
+/// This trait converts bytes to floats.
+///
+/// The accepted syntax is the same as that of [`str::parse`] for floats:
+/// an optional sign, followed by digits with an optional decimal point, and an optional exponent.
+/// `inf`, `infinity` and `nan` are accepted in any case.
+pub trait FromAsciiFloat: Sized {
+    /// The function performing the conversion from a byteslice to a float.
+    /// It takes anything that can be transformed into a byte-slice.
+    /// The result is always the float closest to the decimal number, rounding ties to even.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_float::FromAsciiFloat,
+    ///     error::ParseFloatErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(f64::atof("3.25"), Ok(3.25));
+    ///     assert_eq!(f64::atof("-1.5e3"), Ok(-1500.0));
+    ///     assert_eq!(f64::atof(".5"), Ok(0.5));
+    ///     assert_eq!(f32::atof("0.1"), Ok(0.1f32));
+    ///     assert_eq!(f64::atof("1.5x"), Err(ParseFloatErr::with_byte_at(b'x', 3)));
+    /// }
+    /// ```
+    #[inline]
+    fn atof(s: impl AsRef<[u8]>) -> Result<Self, ParseFloatErr> {
+        Self::bytes_to_float(s.as_ref())
+    }
+
+    fn bytes_to_float(s: &[u8]) -> Result<Self, ParseFloatErr>;
+}
+
+/// The properties of a float type, needed for parsing.
+trait RawFloat: Copy + Mul<Output = Self> + Div<Output = Self> + core::str::FromStr {
+    const INFINITY: Self;
+    const NAN: Self;
+
+    const MANTISSA_EXPLICIT_BITS: i32;
+    const MINIMUM_EXPONENT: i32;
+    const INFINITE_POWER: i32;
+
+    const SMALLEST_POWER_OF_TEN: i64;
+    const LARGEST_POWER_OF_TEN: i64;
+
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64;
+
+    const MIN_EXPONENT_FAST_PATH: i64;
+    const MAX_EXPONENT_FAST_PATH: i64;
+    const MAX_MANTISSA_FAST_PATH: u64;
+
+    /// Converts `v`, which is at most [`RawFloat::MAX_MANTISSA_FAST_PATH`], exactly.
+    fn from_u64(v: u64) -> Self;
+
+    /// Returns 10 to the power of `exponent`, which is at most [`RawFloat::MAX_EXPONENT_FAST_PATH`], exactly.
+    fn pow10_fast_path(exponent: usize) -> Self;
+
+    /// Assembles a float from its mantissa without the hidden bit, and its biased exponent.
+    fn from_parts(mantissa: u64, power2: i32) -> Self;
+
+    fn neg(self) -> Self;
+}
+
+impl RawFloat for f64 {
+    const INFINITY: Self = f64::INFINITY;
+    const NAN: Self = f64::NAN;
+
+    const MANTISSA_EXPLICIT_BITS: i32 = 52;
+    const MINIMUM_EXPONENT: i32 = -1023;
+    const INFINITE_POWER: i32 = 0x7FF;
+
+    const SMALLEST_POWER_OF_TEN: i64 = -342;
+    const LARGEST_POWER_OF_TEN: i64 = 308;
+
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -4;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 23;
+
+    const MIN_EXPONENT_FAST_PATH: i64 = -22;
+    const MAX_EXPONENT_FAST_PATH: i64 = 22;
+    const MAX_MANTISSA_FAST_PATH: u64 = 2 << 52;
+
+    #[inline(always)]
+    fn from_u64(v: u64) -> Self {
+        v as f64
+    }
+
+    #[inline(always)]
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16, 1e17, 1e18,
+            1e19, 1e20, 1e21, 1e22,
+        ];
+
+        TABLE[exponent]
+    }
+
+    #[inline(always)]
+    fn from_parts(mantissa: u64, power2: i32) -> Self {
+        f64::from_bits(mantissa | (power2 as u64) << Self::MANTISSA_EXPLICIT_BITS)
+    }
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+impl RawFloat for f32 {
+    const INFINITY: Self = f32::INFINITY;
+    const NAN: Self = f32::NAN;
+
+    const MANTISSA_EXPLICIT_BITS: i32 = 23;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const INFINITE_POWER: i32 = 0xFF;
+
+    const SMALLEST_POWER_OF_TEN: i64 = -65;
+    const LARGEST_POWER_OF_TEN: i64 = 38;
+
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -17;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 10;
+
+    const MIN_EXPONENT_FAST_PATH: i64 = -10;
+    const MAX_EXPONENT_FAST_PATH: i64 = 10;
+    const MAX_MANTISSA_FAST_PATH: u64 = 2 << 23;
+
+    #[inline(always)]
+    fn from_u64(v: u64) -> Self {
+        v as f32
+    }
+
+    #[inline(always)]
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+        TABLE[exponent]
+    }
+
+    #[inline(always)]
+    fn from_parts(mantissa: u64, power2: i32) -> Self {
+        f32::from_bits((mantissa | (power2 as u64) << Self::MANTISSA_EXPLICIT_BITS) as u32)
+    }
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+const SMALLEST_POWER_OF_FIVE: i64 = -342;
+const LARGEST_POWER_OF_FIVE: i64 = 308;
+
+/// The most significant 128 bits of `limbs`, a little-endian big integer, as `(hi, lo)`.
+const fn top128(limbs: &[u64]) -> (u64, u64) {
+    let mut h = limbs.len() - 1;
+
+    while limbs[h] == 0 {
+        h -= 1;
+    }
+
+    let a = limbs[h];
+    let b = if h >= 1 { limbs[h - 1] } else { 0 };
+    let c = if h >= 2 { limbs[h - 2] } else { 0 };
+    let lz = a.leading_zeros();
+
+    if lz == 0 {
+        (a, b)
+    } else {
+        ((a << lz) | (b >> (64 - lz)), (b << lz) | (c >> (64 - lz)))
+    }
+}
+
+/// 128-bit approximations of the powers of 5 from 5^-342 up to 5^308, as `(hi, lo)`,
+/// normalized so the most significant bit is set.
+///
+/// Positive powers are the exact powers, truncated. Negative powers are `2^b / 5^-q` for a large enough `b`,
+/// rounded up, in the same way as the tables of the reference implementation.
+const POWERS_OF_FIVE_128: [(u64, u64); (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize] = {
+    const ZERO: usize = (-SMALLEST_POWER_OF_FIVE) as usize;
+
+    let mut table = [(0, 0); (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize];
+
+    // 5^308 takes 716 bits.
+    let mut pow5 = [0u64; 12];
+    pow5[0] = 1;
+
+    let mut q = 0;
+    while q <= LARGEST_POWER_OF_FIVE as usize {
+        table[ZERO + q] = top128(&pow5);
+
+        let mut carry = 0;
+        let mut idx = 0;
+        while idx < pow5.len() {
+            let v = pow5[idx] as u128 * 5 + carry;
+            pow5[idx] = v as u64;
+            carry = v >> 64;
+            idx += 1;
+        }
+
+        q += 1;
+    }
+
+    // floor(2^1088 / 5^q), divided by 5 once per step. 5^342 takes 795 bits, so plenty of bits remain.
+    let mut quotient = [0u64; 18];
+    quotient[17] = 1;
+
+    let mut q = 1;
+    while q <= ZERO {
+        let mut rem = 0;
+        let mut idx = quotient.len();
+        while idx > 0 {
+            idx -= 1;
+            let v = (rem << 64) | quotient[idx] as u128;
+            quotient[idx] = (v / 5) as u64;
+            rem = v % 5;
+        }
+
+        table[ZERO - q] = if q <= 27 {
+            // floor(2^b / 5^q) + 1, with b chosen so the result takes exactly 128 bits.
+            let (hi, lo) = top128(&quotient);
+            let (lo, carry) = lo.overflowing_add(1);
+            (hi + carry as u64, lo)
+        } else {
+            // floor(2^b / 5^q) + 1, truncated to 128 bits.
+            let mut plus_one = quotient;
+            let mut idx = 0;
+            while idx < plus_one.len() {
+                let (v, carry) = plus_one[idx].overflowing_add(1);
+                plus_one[idx] = v;
+                if !carry {
+                    break;
+                }
+                idx += 1;
+            }
+
+            top128(&plus_one)
+        };
+
+        q += 1;
+    }
+
+    table
+};
+
+/// A float as its mantissa and biased exponent. An exponent below 0 means the value could not be computed.
+struct BiasedFp {
+    f: u64,
+    e: i32,
+}
+
+#[inline(always)]
+fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    let r = u128::from(a) * u128::from(b);
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Computes `w * 5^q` to at least `precision` bits, as `(lo, hi)`.
+#[inline(always)]
+fn compute_product_approx(q: i64, w: u64, precision: i32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+
+    let (hi5, lo5) = POWERS_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+    let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+
+    if first_hi & mask == mask {
+        // The truncated bits might matter, so multiply with the lower half of the power as well.
+        // If that carries, the upper product needs rounding up.
+        let (_, second_hi) = full_multiplication(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+
+    (first_lo, first_hi)
+}
+
+/// Approximates `log2(10^q)`, which is exact enough for the range of exponents handled.
+#[inline(always)]
+fn power(q: i32) -> i32 {
+    ((q * (152_170 + 65536)) >> 16) + 63
+}
+
+/// The Eisel–Lemire algorithm, converting `w * 10^q` to a float.
+// 1) Multiply the normalized mantissa with a 128-bit approximation of 5^q.
+// 2) If the approximation can't decide the rounding, give up, so the slow path takes over.
+// 3) Take the mantissa bits from the product, and derive the binary exponent from q and the normalization shift.
+// 4) Round to nearest, ties to even, handling subnormals and overflow into infinity.
+#[inline]
+fn compute_float<F: RawFloat>(q: i64, mut w: u64) -> BiasedFp {
+    let zero = BiasedFp { f: 0, e: 0 };
+    let infinite = BiasedFp { f: 0, e: F::INFINITE_POWER };
+    let error = BiasedFp { f: 0, e: -1 };
+
+    if w == 0 || q < F::SMALLEST_POWER_OF_TEN {
+        return zero;
+    } else if q > F::LARGEST_POWER_OF_TEN {
+        return infinite;
+    }
+
+    let lz = w.leading_zeros();
+    w <<= lz;
+
+    let (lo, hi) = compute_product_approx(q, w, F::MANTISSA_EXPLICIT_BITS + 3);
+
+    // Adding the truncated part could round over the halfway point.
+    // Only for q in -27..=55 the product is exact enough to still be sure.
+    if lo == u64::MAX && !(-27..=55).contains(&q) {
+        return error;
+    }
+
+    let upperbit = (hi >> 63) as i32;
+    let mut mantissa = hi >> (upperbit + 64 - F::MANTISSA_EXPLICIT_BITS - 3);
+    let mut power2 = power(q as i32) + upperbit - lz as i32 - F::MINIMUM_EXPONENT;
+
+    if power2 <= 0 {
+        // More than 64 bits below the smallest exponent, so it rounds to zero.
+        if -power2 + 1 >= 64 {
+            return zero;
+        }
+
+        // A subnormal, which might round up into the smallest normal float.
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        power2 = (mantissa >= (1 << F::MANTISSA_EXPLICIT_BITS)) as i32;
+
+        return BiasedFp { f: mantissa, e: power2 };
+    }
+
+    // Exactly halfway between two floats, with an even lower one: round down.
+    if lo <= 1
+        && (F::MIN_EXPONENT_ROUND_TO_EVEN..=F::MAX_EXPONENT_ROUND_TO_EVEN).contains(&q)
+        && mantissa & 3 == 1
+        && (mantissa << (upperbit + 64 - F::MANTISSA_EXPLICIT_BITS - 3)) == hi
+    {
+        mantissa &= !1;
+    }
+
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+
+    // Rounding up carried into the next power of two.
+    if mantissa >= (2 << F::MANTISSA_EXPLICIT_BITS) {
+        mantissa = 1 << F::MANTISSA_EXPLICIT_BITS;
+        power2 += 1;
+    }
+
+    mantissa &= !(1 << F::MANTISSA_EXPLICIT_BITS);
+
+    if power2 >= F::INFINITE_POWER {
+        return infinite;
+    }
+
+    BiasedFp { f: mantissa, e: power2 }
+}
+
+/// A decimal number, as `mantissa * 10^exponent`.
+struct Number {
+    exponent: i64,
+    mantissa: u64,
+    digits: usize,
+    negative: bool,
+    /// Whether non-zero digits were dropped, because they didn't fit in the mantissa.
+    many_digits: bool,
+}
+
+impl Number {
+    /// The most significant digits that always fit in a u64.
+    const MAX_DIGITS: usize = 19;
+
+    #[inline(always)]
+    fn push_digit(&mut self, d: u8, is_fraction: bool) {
+        if self.mantissa == 0 && d == 0 {
+            // Leading zeros only move the decimal point.
+            self.exponent -= is_fraction as i64;
+        } else if self.digits < Self::MAX_DIGITS {
+            self.mantissa = self.mantissa * 10 + u64::from(d);
+            self.digits += 1;
+            self.exponent -= is_fraction as i64;
+        } else {
+            self.many_digits |= d != 0;
+            self.exponent += !is_fraction as i64;
+        }
+    }
+}
+
+/// Validates the syntax of a float, and collects its significant digits and exponent.
+#[inline]
+fn parse_number(bytes: &[u8]) -> Result<Number, ParseFloatErr> {
+    let mut idx = 0;
+
+    let negative = match bytes.first() {
+        Some(b'-') => true,
+        Some(b'+') => false,
+        _ => false,
+    };
+
+    if let Some(b'-') | Some(b'+') = bytes.first() {
+        idx += 1;
+    }
+
+    let mut number = Number {
+        exponent: 0,
+        mantissa: 0,
+        digits: 0,
+        negative,
+        many_digits: false,
+    };
+
+    let mut any_digits = false;
+
+    while let Some(d) = bytes.get(idx).map(|b| b.wrapping_sub(b'0')).filter(|d| *d <= 9) {
+        number.push_digit(d, false);
+        any_digits = true;
+        idx += 1;
+    }
+
+    if let Some(b'.') = bytes.get(idx) {
+        idx += 1;
+
+        while let Some(d) = bytes.get(idx).map(|b| b.wrapping_sub(b'0')).filter(|d| *d <= 9) {
+            number.push_digit(d, true);
+            any_digits = true;
+            idx += 1;
+        }
+    }
+
+    if !any_digits {
+        return Err(ParseFloatErr::at_or_last(bytes, idx));
+    }
+
+    if let Some(b'e') | Some(b'E') = bytes.get(idx) {
+        idx += 1;
+
+        let exp_negative = match bytes.get(idx) {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => false,
+        };
+
+        if let Some(b'-') | Some(b'+') = bytes.get(idx) {
+            idx += 1;
+        }
+
+        let start = idx;
+        let mut exp_number: i64 = 0;
+
+        while let Some(d) = bytes.get(idx).map(|b| b.wrapping_sub(b'0')).filter(|d| *d <= 9) {
+            // Anything this large is infinite or zero anyway, so stop before overflowing.
+            if exp_number < 0x10000 {
+                exp_number = 10 * exp_number + i64::from(d);
+            }
+
+            idx += 1;
+        }
+
+        if idx == start {
+            return Err(ParseFloatErr::at_or_last(bytes, idx));
+        }
+
+        number.exponent += if exp_negative { -exp_number } else { exp_number };
+    }
+
+    match bytes.get(idx) {
+        Some(b) => Err(ParseFloatErr::with_byte_at(*b, idx)),
+        None => Ok(number),
+    }
+}
+
+/// Parses `inf`, `infinity` and `nan` in any case, after the sign.
+#[inline]
+fn parse_special<F: RawFloat>(bytes: &[u8]) -> Option<F> {
+    let (negative, rest) = match bytes {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        rest => (false, rest),
+    };
+
+    let value = if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity") {
+        F::INFINITY
+    } else if rest.eq_ignore_ascii_case(b"nan") {
+        F::NAN
+    } else {
+        return None;
+    };
+
+    Some(if negative { value.neg() } else { value })
+}
+
+/// The slow path, for the rare inputs the fast paths can't round correctly.
+/// `bytes` already passed validation, so it's ASCII, and a float `core` accepts as well.
+#[cold]
+fn parse_fallback<F: RawFloat>(bytes: &[u8]) -> F {
+    match core::str::from_utf8(bytes).ok().and_then(|s| s.parse().ok()) {
+        Some(f) => f,
+        None => unreachable!("validated float failed to parse"),
+    }
+}
+
+#[inline]
+fn parse_float<F: RawFloat>(bytes: &[u8]) -> Result<F, ParseFloatErr> {
+    if bytes.is_empty() {
+        return Err(ParseFloatErr::Empty);
+    }
+
+    let number = match parse_number(bytes) {
+        Ok(number) => number,
+        Err(e) => return parse_special(bytes).ok_or(e),
+    };
+
+    // The fallback parses the sign as well.
+    if number.many_digits {
+        return Ok(parse_fallback(bytes));
+    }
+
+    let value = if (F::MIN_EXPONENT_FAST_PATH..=F::MAX_EXPONENT_FAST_PATH).contains(&number.exponent)
+        && number.mantissa <= F::MAX_MANTISSA_FAST_PATH
+    {
+        // Both operands are exact, so the single operation rounds correctly.
+        let mantissa = F::from_u64(number.mantissa);
+
+        if number.exponent < 0 {
+            mantissa / F::pow10_fast_path(-number.exponent as usize)
+        } else {
+            mantissa * F::pow10_fast_path(number.exponent as usize)
+        }
+    } else {
+        match compute_float::<F>(number.exponent, number.mantissa) {
+            BiasedFp { e, .. } if e < 0 => return Ok(parse_fallback(bytes)),
+            fp => F::from_parts(fp.f, fp.e),
+        }
+    };
+
+    Ok(if number.negative { value.neg() } else { value })
+}
+
+impl FromAsciiFloat for f64 {
+    #[inline]
+    fn bytes_to_float(bytes: &[u8]) -> Result<Self, ParseFloatErr> {
+        parse_float(bytes)
+    }
+}
+
+impl FromAsciiFloat for f32 {
+    #[inline]
+    fn bytes_to_float(bytes: &[u8]) -> Result<Self, ParseFloatErr> {
+        parse_float(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromAsciiFloat, ParseFloatErr, POWERS_OF_FIVE_128};
+
+    /// A small xorshift generator, so the tests are deterministic.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn assert_matches_std(s: &str) {
+        let expected: f64 = s.parse().unwrap();
+        let parsed = f64::atof(s).unwrap();
+
+        assert_eq!(parsed.to_bits(), expected.to_bits(), "{}", s);
+    }
+
+    fn assert_matches_std_f32(s: &str) {
+        let expected: f32 = s.parse().unwrap();
+        let parsed = f32::atof(s).unwrap();
+
+        assert_eq!(parsed.to_bits(), expected.to_bits(), "{}", s);
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(POWERS_OF_FIVE_128[0], (0xeef453d6923bd65a, 0x113faa2906a13b3f));
+        assert_eq!(POWERS_OF_FIVE_128[342], (0x8000000000000000, 0x0000000000000000));
+        assert_eq!(POWERS_OF_FIVE_128[342 + 308], (0x8e679c2f5e44ff8f, 0x570f09eaa7ea7648));
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(f64::atof("0"), Ok(0.0));
+        assert_eq!(f64::atof("-0").map(f64::to_bits), Ok((-0.0f64).to_bits()));
+        assert_eq!(f64::atof("1"), Ok(1.0));
+        assert_eq!(f64::atof("+1.5"), Ok(1.5));
+        assert_eq!(f64::atof("1."), Ok(1.0));
+        assert_eq!(f64::atof("1e3"), Ok(1000.0));
+        assert_eq!(f64::atof("1E-3"), Ok(0.001));
+        assert_eq!(f64::atof("0.1"), Ok(0.1));
+        assert_eq!(f64::atof("inf"), Ok(f64::INFINITY));
+        assert_eq!(f64::atof("-Infinity"), Ok(f64::NEG_INFINITY));
+        assert!(f64::atof("NaN").unwrap().is_nan());
+    }
+
+    #[test]
+    fn hard_cases() {
+        for s in &[
+            "9007199254740993",
+            "9007199254740992.5",
+            "1e23",
+            "8.98846567431158e307",
+            "1.7976931348623157e308",
+            "1.7976931348623158e308",
+            "1.7976931348623159e308",
+            "2.2250738585072011e-308",
+            "2.2250738585072014e-308",
+            "4.9406564584124654e-324",
+            "2.4703282292062327e-324",
+            "2.4703282292062328e-324",
+            "1e-400",
+            "1e400",
+            "123456789012345678901234567890",
+            "0.000000000000000000000000000000000000000000001",
+            "7.2057594037927933e16",
+            "3.0000000000000004440892098500626",
+            "1e000000000000000000000000000001",
+            "0.30000000000000004",
+            "1448997445238699",
+            "5e-324",
+            "-12345678901234567890123",
+            "-0.12345678901234567890123",
+            "-9007199254740993.00000000000000000001",
+            "-2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+        ] {
+            assert_matches_std(s);
+        }
+    }
+
+    #[test]
+    fn random_bits_roundtrip() {
+        let mut state = 0x1234_5678_9abc_def0;
+
+        for _ in 0..20_000 {
+            let f = f64::from_bits(xorshift(&mut state));
+
+            if f.is_finite() {
+                assert_matches_std(&std::format!("{}", f));
+                assert_matches_std(&std::format!("{:e}", f));
+                assert_matches_std(&std::format!("{:.3e}", f));
+            }
+        }
+    }
+
+    #[test]
+    fn random_decimals() {
+        let mut state = 0x0fed_cba9_8765_4321;
+
+        for _ in 0..20_000 {
+            let mantissa = xorshift(&mut state) >> (xorshift(&mut state) % 64);
+            let exponent = (xorshift(&mut state) % 700) as i64 - 350;
+
+            assert_matches_std(&std::format!("{}e{}", mantissa, exponent));
+            assert_matches_std(&std::format!("-{}e{}", mantissa, exponent));
+
+            // Longer than 19 digits, so these take the fallback.
+            let long = std::format!("{}{:020}", mantissa, xorshift(&mut state));
+            assert_matches_std(&std::format!("{}e{}", long, exponent));
+            assert_matches_std(&std::format!("-{}e{}", long, exponent));
+            assert_matches_std(&std::format!("-0.{}", long));
+        }
+    }
+
+    #[test]
+    fn f32_hard_cases() {
+        for s in &[
+            "16777217",
+            "16777216.5",
+            "3.4028235e38",
+            "3.4028236e38",
+            "3.40282357e38",
+            "1.17549435e-38",
+            "1.1754942e-38",
+            "1e-45",
+            "1.4e-45",
+            "7e-46",
+            "7.1e-46",
+            "1e-50",
+            "1e39",
+            "0.1",
+            "1.00000017881393432617187499",
+            "0.000000000000000000000000000000000000011754943508222875079687365372222456778186655567720875215087517062784172594547271728515625",
+            "-12345678901234567890123",
+            "-0.12345678901234567890123",
+            "-16777217.0000000000000000000001",
+        ] {
+            assert_matches_std_f32(s);
+        }
+
+        assert_eq!(f32::atof("-inf"), Ok(f32::NEG_INFINITY));
+        assert_eq!(f32::atof("1.5f"), Err(ParseFloatErr::with_byte_at(b'f', 3)));
+    }
+
+    #[test]
+    fn f32_random() {
+        let mut state = 0xdead_beef_cafe_f00d;
+
+        for _ in 0..20_000 {
+            let f = f32::from_bits(xorshift(&mut state) as u32);
+
+            if f.is_finite() {
+                assert_matches_std_f32(&std::format!("{}", f));
+                assert_matches_std_f32(&std::format!("{:e}", f));
+                assert_matches_std_f32(&std::format!("{:.12e}", f));
+            }
+
+            let mantissa = xorshift(&mut state) >> (xorshift(&mut state) % 64);
+            let exponent = (xorshift(&mut state) % 110) as i64 - 60;
+            assert_matches_std_f32(&std::format!("{}e{}", mantissa, exponent));
+            assert_matches_std_f32(&std::format!("-{}e{}", mantissa, exponent));
+
+            let long = std::format!("{}{:020}", mantissa, xorshift(&mut state));
+            assert_matches_std_f32(&std::format!("-{}e{}", long, exponent));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(f64::atof(""), Err(ParseFloatErr::Empty));
+        assert_eq!(f64::atof("-"), Err(ParseFloatErr::with_byte_at(b'-', 0)));
+        assert_eq!(f64::atof("."), Err(ParseFloatErr::with_byte_at(b'.', 0)));
+        assert_eq!(f64::atof("1e"), Err(ParseFloatErr::with_byte_at(b'e', 1)));
+        assert_eq!(f64::atof("1e+"), Err(ParseFloatErr::with_byte_at(b'+', 2)));
+        assert_eq!(f64::atof("e5"), Err(ParseFloatErr::with_byte_at(b'e', 0)));
+        assert_eq!(f64::atof("1.2.3"), Err(ParseFloatErr::with_byte_at(b'.', 3)));
+        assert_eq!(f64::atof("12 "), Err(ParseFloatErr::with_byte_at(b' ', 2)));
+        assert_eq!(f64::atof("infinit"), Err(ParseFloatErr::with_byte_at(b'i', 0)));
+    }
+}
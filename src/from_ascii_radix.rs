@@ -0,0 +1,712 @@
+use core::ops::Mul;
+
+use crate::{
+    constants::*,
+    error::ParseIntErr,
+    from_ascii::{locate_invalid_digit, trim_leading_zeros},
+};
+
+/// This trait converts bytes to integers in any base from 2 up to and including 36,
+/// and is implemented on all integer types, except u128 and i128.
+///
+/// Digits are `0-9`, followed by `a-z` or `A-Z` for the bases above 10.
+/// Unlike decimal parsing with [`crate::from_ascii::FromAscii`], numbers that don't fit in the target type
+/// are always reported as an error, and never wrap around.
+pub trait FromAsciiRadix: Sized {
+    /// The function performing the conversion from a byteslice in base `radix` to a number.
+    /// It takes anything that can be transformed into a byte-slice.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_radix::FromAsciiRadix,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_radix("ff", 16), Ok(255));
+    ///     assert_eq!(i32::atoi_radix("-Zz", 36), Ok(-1295));
+    ///     assert_eq!(u8::atoi_radix("100000000", 2), Err(ParseIntErr::PosOverflow));
+    ///     assert_eq!(u32::atoi_radix("129", 8), Err(ParseIntErr::with_byte_at(b'9', 2)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_radix(s: impl AsRef<[u8]>, radix: u32) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_radix(s.as_ref(), radix)
+    }
+
+    fn bytes_to_int_radix(s: &[u8], radix: u32) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice of hexadecimal digits to a number.
+    /// This is the same as calling [`FromAsciiRadix::atoi_radix`] with a radix of 16,
+    /// but uses a faster path, that validates and converts 8 digits at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii_radix::FromAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(u64::atoi_hex("DeadBeef"), Ok(0xdead_beef));
+    ///     assert_eq!(u16::atoi_hex("1f4"), Ok(500));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_hex(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_hex(s.as_ref())
+    }
+
+    fn bytes_to_int_hex(s: &[u8]) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice of octal digits to a number.
+    /// Like [`FromAsciiRadix::atoi_hex`], this uses a faster path than [`FromAsciiRadix::atoi_radix`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_radix::FromAsciiRadix,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_oct("755"), Ok(0o755));
+    ///     assert_eq!(u8::atoi_oct("377"), Ok(255));
+    ///     assert_eq!(u8::atoi_oct("400"), Err(ParseIntErr::PosOverflow));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_oct(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_oct(s.as_ref())
+    }
+
+    fn bytes_to_int_oct(s: &[u8]) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice of binary digits to a number.
+    /// Like [`FromAsciiRadix::atoi_hex`], this uses a faster path than [`FromAsciiRadix::atoi_radix`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::from_ascii_radix::FromAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(u8::atoi_bin("10100101"), Ok(0b1010_0101));
+    ///     assert_eq!(i8::atoi_bin("-10000000"), Ok(i8::MIN));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_bin(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_bin(s.as_ref())
+    }
+
+    fn bytes_to_int_bin(s: &[u8]) -> Result<Self, ParseIntErr>;
+
+    /// Converts a byteslice to a number, picking the radix from its prefix like a Rust integer literal does:
+    /// `0x` for hexadecimal, `0o` for octal, `0b` for binary, and decimal otherwise.
+    /// The prefix follows the sign, if there is one.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     from_ascii_radix::FromAsciiRadix,
+    ///     error::ParseIntErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     assert_eq!(u32::atoi_any("0xff"), Ok(255));
+    ///     assert_eq!(i32::atoi_any("-0o17"), Ok(-15));
+    ///     assert_eq!(u8::atoi_any("0b101"), Ok(5));
+    ///     assert_eq!(u16::atoi_any("1234"), Ok(1234));
+    ///     assert_eq!(u16::atoi_any("0x"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+    /// }
+    /// ```
+    #[inline]
+    fn atoi_any(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+        Self::bytes_to_int_any(s.as_ref())
+    }
+
+    fn bytes_to_int_any(s: &[u8]) -> Result<Self, ParseIntErr>;
+}
+
+#[inline(always)]
+pub(crate) fn assert_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must lie in the range from 2 to 36, got {}",
+        radix
+    );
+}
+
+#[inline(always)]
+fn parse_byte_radix<N>(byte: u8, radix: u32, pow: N) -> Result<N, ParseIntErr>
+where
+    N: From<u8> + Mul<Output = N>,
+{
+    let d = DIGIT_VALUES[byte as usize];
+
+    if u32::from(d) >= radix {
+        return Err(ParseIntErr::with_byte(byte));
+    }
+
+    Ok(N::from(d) * pow)
+}
+
+/// Splits off the sign. A sign without any digits following it is not a number.
+#[inline(always)]
+fn split_sign(bytes: &[u8]) -> Result<(bool, &[u8]), ParseIntErr> {
+    match bytes {
+        [] => Err(ParseIntErr::Empty),
+        [b'-'] => Err(ParseIntErr::with_byte(b'-')),
+        [b'-', digits @ ..] => Ok((true, digits)),
+        digits => Ok((false, digits)),
+    }
+}
+
+/// Moves the position of an [`ParseIntErr::InvalidDigit`] by `offset`.
+#[inline(always)]
+fn offset_position(err: ParseIntErr, offset: usize) -> ParseIntErr {
+    match err {
+        ParseIntErr::InvalidDigit([c], position) => ParseIntErr::with_byte_at(c, position + offset),
+        err => err,
+    }
+}
+
+/// Sets the high bit of every byte in `v` that lies within `lo..=hi`.
+/// None of the bytes of `v` may have their high bit set.
+#[inline(always)]
+fn swar_in_range(v: u64, lo: u8, hi: u8) -> u64 {
+    let above = v + ONES * u64::from(0x7F - hi);
+    let at_least = (v | HIGH) - ONES * u64::from(lo);
+
+    !above & at_least & HIGH
+}
+
+/// Validates and converts 8 digits at once, in the radix `1 << shift`.
+/// Only binary, octal and hexadecimal are supported, so `shift` is 1, 3 or 4.
+// 1) Check every byte is a digit, and for hexadecimal, a letter within 'a'..='f' after setting the lowercase bit.
+// 2) Every digit's value is its low nibble, plus 9 for letters.
+// 3) Pack the digits together, halving the number of lanes each step.
+#[inline(always)]
+fn swar_digits8(bytes: [u8; 8], shift: u32) -> Result<u32, ParseIntErr> {
+    let v = u64::from_be_bytes(bytes);
+    let ascii = v & !HIGH;
+
+    let (digits, letters) = if shift == 4 {
+        (
+            swar_in_range(ascii, b'0', b'9'),
+            swar_in_range(ascii | (ONES * 0x20), b'a', b'f'),
+        )
+    } else {
+        (swar_in_range(ascii, b'0', b'0' + (1 << shift) - 1), 0)
+    };
+
+    let invalid = (!(digits | letters) | v) & HIGH;
+
+    if invalid != 0 {
+        let idx = invalid.leading_zeros() as usize / 8;
+        return Err(ParseIntErr::with_byte(bytes[idx]));
+    }
+
+    let mut n = (ascii & (ONES * 0x0F)) + (letters >> 7) * 9;
+    n = (n | (n >> (8 - shift))) & (0x0001_0001_0001_0001 * ((1 << (2 * shift)) - 1));
+    n = (n | (n >> (16 - 2 * shift))) & (0x0000_0001_0000_0001 * ((1 << (4 * shift)) - 1));
+    n = (n | (n >> (32 - 4 * shift))) & ((1 << (8 * shift)) - 1);
+
+    Ok(n as u32)
+}
+
+/// Parses digits in the radix `1 << shift` into a number of at most `bits` bits.
+/// Every digit is exactly `shift` bits, so after stripping leading zeros,
+/// the length and the first digit decide whether the number fits.
+#[inline(always)]
+fn pow2_digits_to_u64(bytes: &[u8], shift: u32, bits: u32) -> Result<u64, ParseIntErr> {
+    let bytes = trim_leading_zeros(bytes);
+    let total = bytes.len() * shift as usize;
+
+    if total > bits as usize {
+        // Only the first digit may stick out, and only by its unused high bits.
+        let excess = (total - bits as usize) as u32;
+        let first = u32::from(DIGIT_VALUES[bytes[0] as usize]);
+
+        if excess >= shift || (first < 1 << shift && first >> (shift - excess) != 0) {
+            return Err(ParseIntErr::PosOverflow);
+        }
+    }
+
+    // Pad the head with zeros, so everything is converted in chunks of 8.
+    let (head, chunks) = bytes.split_at(bytes.len() % 8);
+    let mut padded = [b'0'; 8];
+    padded[8 - head.len()..].copy_from_slice(head);
+
+    let mut result = u64::from(swar_digits8(padded, shift)?);
+
+    for chunk in chunks.chunks_exact(8) {
+        let mut block = [0; 8];
+        block.copy_from_slice(chunk);
+
+        result = (result << (8 * shift)) | u64::from(swar_digits8(block, shift)?);
+    }
+
+    Ok(result)
+}
+
+/// Generates a table of powers for every radix up to 36, for the given integer type.
+/// The powers of a radix are stored in descending order, and end at the last element of its row.
+/// Powers that don't fit in the type are left as 0, which marks the maximum number of digits for that radix.
+macro_rules! radix_table_of {
+    ($int:ty, $name:ident) => {
+        const $name: [[$int; <$int>::BITS as usize]; 37] = {
+            const LEN: usize = <$int>::BITS as usize;
+
+            let mut tables = [[0; LEN]; 37];
+            let mut radix = 2;
+
+            while radix <= 36 {
+                let mut pow: $int = 1;
+                let mut idx = LEN;
+
+                loop {
+                    idx -= 1;
+                    tables[radix][idx] = pow;
+
+                    match pow.checked_mul(radix as $int) {
+                        Some(next) => pow = next,
+                        None => break,
+                    }
+                }
+
+                radix += 1;
+            }
+
+            tables
+        };
+    };
+}
+
+/// Parses a slice of nothing but digits, without handling any sign.
+/// Implemented on the unsigned types, which hold the magnitude of every number.
+trait FromRadixDigits: Sized {
+    fn radix_digits_to_int(bytes: &[u8], radix: u32) -> Result<Self, ParseIntErr>;
+
+    /// Parses digits in the radix `1 << shift`, see [`pow2_digits_to_u64`].
+    fn pow2_digits_to_int(bytes: &[u8], shift: u32) -> Result<Self, ParseIntErr>;
+}
+
+/// Applies the sign to a parsed magnitude, checking it fits in the type.
+trait FromMagnitude: Sized {
+    type Unsigned: FromRadixDigits;
+
+    fn from_magnitude(is_negative: bool, magnitude: Result<Self::Unsigned, ParseIntErr>) -> Result<Self, ParseIntErr>;
+}
+
+/// Parses a possibly signed number, using `parse_digits` for the magnitude.
+#[inline(always)]
+fn parse_signed<N: FromMagnitude>(
+    bytes: &[u8],
+    radix: u32,
+    parse_digits: impl FnOnce(&[u8]) -> Result<N::Unsigned, ParseIntErr>,
+) -> Result<N, ParseIntErr> {
+    split_sign(bytes)
+        .and_then(|(is_negative, digits)| N::from_magnitude(is_negative, parse_digits(digits)))
+        .map_err(|e| locate_invalid_digit(e, bytes, radix))
+}
+
+/// Parses a number with an optional `0x`, `0o` or `0b` prefix after the sign, like a Rust integer literal.
+#[inline(always)]
+fn parse_any<N: FromMagnitude + FromAsciiRadix>(bytes: &[u8]) -> Result<N, ParseIntErr> {
+    let (is_negative, rest) = split_sign(bytes).map_err(|e| locate_invalid_digit(e, bytes, 10))?;
+
+    let (shift, digits) = match rest {
+        [b'0', b'x', digits @ ..] => (4, digits),
+        [b'0', b'o', digits @ ..] => (3, digits),
+        [b'0', b'b', digits @ ..] => (1, digits),
+        _ => return N::bytes_to_int_radix(bytes, 10),
+    };
+
+    // The sign and digits are no longer contiguous, so positions are found within the digits.
+    let offset = bytes.len() - digits.len();
+
+    if digits.is_empty() {
+        return Err(ParseIntErr::with_byte_at(rest[1], offset - 1));
+    }
+
+    N::from_magnitude(is_negative, N::Unsigned::pow2_digits_to_int(digits, shift))
+        .map_err(|e| offset_position(locate_invalid_digit(e, digits, 1 << shift), offset))
+}
+
+macro_rules! unsigned_from_ascii_radix {
+    ($int:ty, $const_table:ident) => {
+        impl FromRadixDigits for $int {
+            // 1) Start at correct position in the table of the radix (table.len() - bytes.len()).
+            // 2) If that is the highest power that fits, the first digit can overflow, so it's added with checked arithmetic.
+            // 3) Parse the remaining bytes 4 at a time, which can't overflow anymore.
+            #[inline]
+            fn radix_digits_to_int(bytes: &[u8], radix: u32) -> Result<Self, ParseIntErr> {
+                let bytes = trim_leading_zeros(bytes);
+                let table = &$const_table[radix as usize];
+
+                if bytes.is_empty() {
+                    return Ok(0);
+                }
+
+                if bytes.len() > table.len() || table[table.len() - bytes.len()] == 0 {
+                    return Err(ParseIntErr::PosOverflow);
+                }
+
+                let mut idx = table.len() - bytes.len();
+
+                let (high, mut bytes) = match bytes.split_first() {
+                    Some((first, rest)) if idx == 0 || table[idx - 1] == 0 => {
+                        let high = parse_byte_radix::<Self>(*first, radix, 1)?
+                            .checked_mul(table[idx])
+                            .ok_or(ParseIntErr::PosOverflow)?;
+
+                        idx += 1;
+                        (high, rest)
+                    }
+                    _ => (0, bytes),
+                };
+
+                let mut low: Self = 0;
+
+                while let ([a, b, c, d, ..], [p1, p2, p3, p4, ..]) = (bytes, &table[idx..]) {
+                    let r1 = parse_byte_radix(*a, radix, *p1)?;
+                    let r2 = parse_byte_radix(*b, radix, *p2)?;
+                    let r3 = parse_byte_radix(*c, radix, *p3)?;
+                    let r4 = parse_byte_radix(*d, radix, *p4)?;
+
+                    low += r1 + r2 + r3 + r4;
+
+                    idx += 4;
+                    bytes = &bytes[4..];
+                }
+
+                for (a, p) in bytes.iter().zip(&table[idx..]) {
+                    low += parse_byte_radix(*a, radix, *p)?;
+                }
+
+                high.checked_add(low).ok_or(ParseIntErr::PosOverflow)
+            }
+
+            #[inline]
+            fn pow2_digits_to_int(bytes: &[u8], shift: u32) -> Result<Self, ParseIntErr> {
+                pow2_digits_to_u64(bytes, shift, Self::BITS).map(|n| n as Self)
+            }
+        }
+
+        // A leading '-' is reported explicitly, rather than as an invalid digit.
+        impl FromMagnitude for $int {
+            type Unsigned = Self;
+
+            #[inline(always)]
+            fn from_magnitude(is_negative: bool, magnitude: Result<Self, ParseIntErr>) -> Result<Self, ParseIntErr> {
+                match magnitude {
+                    // "-0" is still zero.
+                    Ok(0) => Ok(0),
+                    Ok(_) | Err(ParseIntErr::PosOverflow) if is_negative => Err(ParseIntErr::NegOverflow),
+                    magnitude => magnitude,
+                }
+            }
+        }
+
+        from_ascii_radix!($int);
+    };
+}
+
+macro_rules! signed_from_ascii_radix {
+    ($int:ty, $unsigned_version:ty) => {
+        // The magnitude never overflows the unsigned type for a valid signed number,
+        // so it's parsed as that, and then checked against the signed range.
+        impl FromMagnitude for $int {
+            type Unsigned = $unsigned_version;
+
+            #[inline(always)]
+            fn from_magnitude(is_negative: bool, magnitude: Result<$unsigned_version, ParseIntErr>) -> Result<Self, ParseIntErr> {
+                let overflow = if is_negative {
+                    ParseIntErr::NegOverflow
+                } else {
+                    ParseIntErr::PosOverflow
+                };
+
+                let magnitude = match magnitude {
+                    Err(ParseIntErr::PosOverflow) => return Err(overflow),
+                    magnitude => magnitude?,
+                };
+
+                if is_negative {
+                    // The magnitude of MIN is one larger than MAX.
+                    if magnitude > Self::MIN as $unsigned_version {
+                        return Err(overflow);
+                    }
+
+                    Ok((magnitude as Self).wrapping_neg())
+                } else {
+                    if magnitude > Self::MAX as $unsigned_version {
+                        return Err(overflow);
+                    }
+
+                    Ok(magnitude as Self)
+                }
+            }
+        }
+
+        from_ascii_radix!($int);
+    };
+}
+
+macro_rules! from_ascii_radix {
+    ($int:ty) => {
+        impl FromAsciiRadix for $int {
+            #[inline]
+            fn bytes_to_int_radix(bytes: &[u8], radix: u32) -> Result<Self, ParseIntErr> {
+                assert_radix(radix);
+                parse_signed::<Self>(bytes, radix, |digits| FromRadixDigits::radix_digits_to_int(digits, radix))
+            }
+
+            #[inline]
+            fn bytes_to_int_hex(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_signed::<Self>(bytes, 16, |digits| FromRadixDigits::pow2_digits_to_int(digits, 4))
+            }
+
+            #[inline]
+            fn bytes_to_int_oct(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_signed::<Self>(bytes, 8, |digits| FromRadixDigits::pow2_digits_to_int(digits, 3))
+            }
+
+            #[inline]
+            fn bytes_to_int_bin(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_signed::<Self>(bytes, 2, |digits| FromRadixDigits::pow2_digits_to_int(digits, 1))
+            }
+
+            #[inline]
+            fn bytes_to_int_any(bytes: &[u8]) -> Result<Self, ParseIntErr> {
+                parse_any::<Self>(bytes)
+            }
+        }
+    };
+}
+
+radix_table_of!(u8, POWERS_U8);
+radix_table_of!(u16, POWERS_U16);
+radix_table_of!(u32, POWERS_U32);
+radix_table_of!(u64, POWERS_U64);
+radix_table_of!(usize, POWERS_USIZE);
+
+unsigned_from_ascii_radix!(u8, POWERS_U8);
+unsigned_from_ascii_radix!(u16, POWERS_U16);
+unsigned_from_ascii_radix!(u32, POWERS_U32);
+unsigned_from_ascii_radix!(u64, POWERS_U64);
+unsigned_from_ascii_radix!(usize, POWERS_USIZE);
+
+signed_from_ascii_radix!(i8, u8);
+signed_from_ascii_radix!(i16, u16);
+signed_from_ascii_radix!(i32, u32);
+signed_from_ascii_radix!(i64, u64);
+signed_from_ascii_radix!(isize, usize);
+
+/// Parses the hexadecimal size at the start of an HTTP/1.1 chunk header,
+/// and returns it together with the number of bytes taken up by the size.
+///
+/// The size must be followed by a `;` that starts the chunk extensions, or by the `\r\n` that ends the header.
+/// Whitespace between the size and the `;` is allowed, and not counted in the bytes consumed.
+/// If the input ends before the terminator, the last byte is reported.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     from_ascii_radix::parse_chunk_size,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(parse_chunk_size(b"1A\r\nhello..."), Ok((26, 2)));
+///     assert_eq!(parse_chunk_size(b"400;name=value\r\n"), Ok((1024, 3)));
+///     assert_eq!(parse_chunk_size(b"400 x\r\n"), Err(ParseIntErr::with_byte_at(b'x', 4)));
+///     assert_eq!(parse_chunk_size(b"10000000000000000\r\n"), Err(ParseIntErr::PosOverflow));
+/// }
+/// ```
+#[inline]
+pub fn parse_chunk_size(bytes: &[u8]) -> Result<(u64, usize), ParseIntErr> {
+    let len = bytes.iter().take_while(|b| b.is_ascii_hexdigit()).count();
+
+    if len == 0 {
+        return Err(ParseIntErr::at_or_last(bytes, 0));
+    }
+
+    let ws = bytes[len..].iter().take_while(|b| **b == b' ' || **b == b'\t').count();
+
+    match &bytes[len + ws..] {
+        [b';', ..] => {}
+        [b'\r', b'\n', ..] if ws == 0 => {}
+        [b'\r'] if ws == 0 => return Err(ParseIntErr::at_or_last(bytes, len)),
+        [b'\r', ..] if ws == 0 => return Err(ParseIntErr::at_or_last(bytes, len + 1)),
+        _ => return Err(ParseIntErr::at_or_last(bytes, len + ws)),
+    }
+
+    let size = u64::bytes_to_int_hex(&bytes[..len])?;
+
+    Ok((size, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_chunk_size, FromAsciiRadix, ParseIntErr};
+
+    #[test]
+    fn matches_std() {
+        for radix in 2..=36 {
+            for n in (0..u16::MAX).step_by(7).chain(Some(u16::MAX)) {
+                let mut buff = [0; 16];
+                let mut idx = buff.len();
+                let mut m = n;
+
+                loop {
+                    idx -= 1;
+                    buff[idx] = core::char::from_digit(u32::from(m) % radix, radix).unwrap() as u8;
+                    m /= radix as u16;
+
+                    if m == 0 {
+                        break;
+                    }
+                }
+
+                assert_eq!(u16::atoi_radix(&buff[idx..], radix), Ok(n));
+                assert_eq!(u64::atoi_radix(&buff[idx..], radix), Ok(u64::from(n)));
+            }
+        }
+    }
+
+    #[test]
+    fn full_range() {
+        assert_eq!(u64::atoi_radix("ffffffffffffffff", 16), Ok(u64::MAX));
+        assert_eq!(u64::atoi_radix("10000000000000000", 16), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u64::atoi_radix("3w5e11264sgsf", 36), Ok(u64::MAX));
+        assert_eq!(u64::atoi_radix("3w5e11264sgsg", 36), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_radix("11111111", 2), Ok(255));
+        assert_eq!(u8::atoi_radix("0000000011111111", 2), Ok(255));
+        assert_eq!(u8::atoi_radix("100000000", 2), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_radix("73", 36), Ok(255));
+        assert_eq!(u8::atoi_radix("74", 36), Err(ParseIntErr::PosOverflow));
+
+        assert_eq!(i8::atoi_radix("-80", 16), Ok(i8::MIN));
+        assert_eq!(i8::atoi_radix("7f", 16), Ok(i8::MAX));
+        assert_eq!(i8::atoi_radix("-81", 16), Err(ParseIntErr::NegOverflow));
+        assert_eq!(i8::atoi_radix("80", 16), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i64::atoi_radix("-8000000000000000", 16), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(u32::atoi_radix("", 16), Err(ParseIntErr::Empty));
+        assert_eq!(i32::atoi_radix("-", 16), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(u32::atoi_radix("-1", 16), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u32::atoi_radix("12g", 16), Err(ParseIntErr::with_byte_at(b'g', 2)));
+        assert_eq!(i32::atoi_radix("-1012", 2), Err(ParseIntErr::with_byte_at(b'2', 4)));
+    }
+
+    #[test]
+    fn hex() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            let lower = std::format!("{:x}", n);
+            let upper = std::format!("{:X}", n);
+
+            assert_eq!(u64::atoi_hex(&lower), Ok(n));
+            assert_eq!(u64::atoi_hex(&upper), Ok(n));
+            assert_eq!(u64::atoi_hex(std::format!("000{}", lower)), Ok(n));
+        }
+
+        assert_eq!(u8::atoi_hex("fF"), Ok(255));
+        assert_eq!(u8::atoi_hex("100"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u32::atoi_hex("0"), Ok(0));
+        assert_eq!(u32::atoi_hex("-0"), Ok(0));
+        assert_eq!(u32::atoi_hex("-a"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u32::atoi_hex(""), Err(ParseIntErr::Empty));
+        assert_eq!(u64::atoi_hex("10000000000000000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i16::atoi_hex("-8000"), Ok(i16::MIN));
+
+        for (idx, &invalid) in [b'g', b'G', b'/', b':', b'@', b'`', b' ', 0xC1, 0xE6].iter().enumerate() {
+            let mut digits = *b"123456789abc";
+            digits[idx] = invalid;
+
+            assert_eq!(
+                u64::atoi_hex(digits),
+                Err(ParseIntErr::with_byte_at(invalid, idx))
+            );
+        }
+    }
+
+    #[test]
+    fn oct_and_bin() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            assert_eq!(u64::atoi_oct(std::format!("{:o}", n)), Ok(n));
+            assert_eq!(u64::atoi_bin(std::format!("{:b}", n)), Ok(n));
+            assert_eq!(u64::atoi_bin(std::format!("00{:b}", n)), Ok(n));
+        }
+
+        for n in 0..=u16::MAX {
+            assert_eq!(u16::atoi_oct(std::format!("{:o}", n)), Ok(n));
+            assert_eq!(u16::atoi_bin(std::format!("{:b}", n)), Ok(n));
+        }
+
+        assert_eq!(u64::atoi_oct("1777777777777777777777"), Ok(u64::MAX));
+        assert_eq!(u64::atoi_oct("2000000000000000000000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u16::atoi_oct("177777"), Ok(u16::MAX));
+        assert_eq!(u16::atoi_oct("200000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u16::atoi_oct("1000000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u16::atoi_oct("800000"), Err(ParseIntErr::with_byte_at(b'8', 0)));
+        assert_eq!(u16::atoi_oct("17778"), Err(ParseIntErr::with_byte_at(b'8', 4)));
+        assert_eq!(u8::atoi_bin("111111111"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u8::atoi_bin("0000000011111111"), Ok(255));
+        assert_eq!(u8::atoi_bin("1012"), Err(ParseIntErr::with_byte_at(b'2', 3)));
+        assert_eq!(u32::atoi_bin("-1"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(i32::atoi_oct("-17777777777"), Ok(-0o17777777777));
+    }
+
+    #[test]
+    fn any() {
+        assert_eq!(u64::atoi_any("0xffffffffffffffff"), Ok(u64::MAX));
+        assert_eq!(i64::atoi_any("-0x8000000000000000"), Ok(i64::MIN));
+        assert_eq!(i64::atoi_any("0x8000000000000000"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(i8::atoi_any("-0b10000001"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi_any("-0x0"), Ok(0));
+        assert_eq!(u8::atoi_any("-0x1"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi_any("-12"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(u8::atoi_any("0"), Ok(0));
+        assert_eq!(u8::atoi_any("07"), Ok(7));
+        assert_eq!(u8::atoi_any(""), Err(ParseIntErr::Empty));
+        assert_eq!(u8::atoi_any("-"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(i8::atoi_any("-0b"), Err(ParseIntErr::with_byte_at(b'b', 2)));
+        assert_eq!(u32::atoi_any("0xfg"), Err(ParseIntErr::with_byte_at(b'g', 3)));
+        assert_eq!(i32::atoi_any("-0o78"), Err(ParseIntErr::with_byte_at(b'8', 4)));
+        assert_eq!(u32::atoi_any("0X1"), Err(ParseIntErr::with_byte_at(b'X', 1)));
+        assert_eq!(u32::atoi_any("0x-1"), Err(ParseIntErr::with_byte_at(b'-', 2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_radix() {
+        let _ = u32::atoi_radix("1", 37);
+    }
+
+    #[test]
+    fn chunk_size() {
+        assert_eq!(parse_chunk_size(b"0\r\n\r\n"), Ok((0, 1)));
+        assert_eq!(parse_chunk_size(b"ffffffffffffffff\r\n"), Ok((u64::MAX, 16)));
+        assert_eq!(parse_chunk_size(b"0000000000000000001\r\n"), Ok((1, 19)));
+        assert_eq!(parse_chunk_size(b"aB;"), Ok((0xab, 2)));
+        assert_eq!(parse_chunk_size(b"aB \t;ext"), Ok((0xab, 2)));
+
+        assert_eq!(parse_chunk_size(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_chunk_size(b"\r\n"), Err(ParseIntErr::with_byte_at(b'\r', 0)));
+        assert_eq!(parse_chunk_size(b"-1\r\n"), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(parse_chunk_size(b"0x10\r\n"), Err(ParseIntErr::with_byte_at(b'x', 1)));
+        assert_eq!(parse_chunk_size(b"10"), Err(ParseIntErr::with_byte_at(b'0', 1)));
+        assert_eq!(parse_chunk_size(b"10\r"), Err(ParseIntErr::with_byte_at(b'\r', 2)));
+        assert_eq!(parse_chunk_size(b"10\rx"), Err(ParseIntErr::with_byte_at(b'x', 3)));
+        assert_eq!(parse_chunk_size(b"10\n"), Err(ParseIntErr::with_byte_at(b'\n', 2)));
+        assert_eq!(parse_chunk_size(b"10 \r\n"), Err(ParseIntErr::with_byte_at(b'\r', 3)));
+        assert_eq!(parse_chunk_size(b"10 "), Err(ParseIntErr::with_byte_at(b' ', 2)));
+        assert_eq!(parse_chunk_size(b"10000000000000000;"), Err(ParseIntErr::PosOverflow));
+    }
+}
@@ -0,0 +1,161 @@
+//! Formatting numbers into the fixed capacity [`String`] and [`Vec`] of the [`heapless`](::heapless) crate.
+//!
+//! The capacity `N` is part of the type, so these work on embedded targets without an allocator.
+//! Parsing needs nothing extra, since [`FromAscii::atoi`](crate::from_ascii::FromAscii::atoi)
+//! takes a heapless `String` or `Vec` like any other `AsRef<[u8]>`.
+//!
+//! This module is only available with the `heapless` feature.
+//!
+//! # Examples
+//! ```
+//! use byte_num::{
+//!     from_ascii::FromAscii,
+//!     heapless::itoa_heapless,
+//! };
+//!
+//! fn main() {
+//!     let s: heapless::String<4> = itoa_heapless(-123i16).unwrap();
+//!
+//!     assert_eq!(s, "-123");
+//!     assert_eq!(i16::atoi(&s), Ok(-123));
+//! }
+//! ```
+use ::heapless::{String, Vec};
+
+use crate::{error::FormatErr, into_ascii::IntoAscii};
+
+/// Formats `n` in decimal into a [`String`] with a capacity of `N` bytes.
+///
+/// # Errors
+/// Returns [`FormatErr::BufferTooSmall`] if `n` doesn't fit in `N` bytes.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     error::FormatErr,
+///     heapless::itoa_heapless,
+/// };
+///
+/// fn main() {
+///     assert_eq!(itoa_heapless::<8, _>(4096u32).unwrap(), "4096");
+///     assert_eq!(itoa_heapless::<3, _>(4096u32), Err(FormatErr::BufferTooSmall(4)));
+/// }
+/// ```
+#[inline]
+pub fn itoa_heapless<const N: usize, T: IntoAscii + Copy>(n: T) -> Result<String<N>, FormatErr> {
+    let digits = itoa_heapless_vec(n)?;
+
+    // Safety: the digits, and the sign, are all ASCII.
+    Ok(unsafe { String::from_utf8_unchecked(digits) })
+}
+
+/// Formats `n` in decimal into a [`Vec`] with a capacity of `N` bytes.
+///
+/// # Errors
+/// Returns [`FormatErr::BufferTooSmall`] if `n` doesn't fit in `N` bytes.
+///
+/// # Examples
+/// ```
+/// use byte_num::heapless::itoa_heapless_vec;
+///
+/// fn main() {
+///     let digits: heapless::Vec<u8, 20> = itoa_heapless_vec(u64::MAX).unwrap();
+///
+///     assert_eq!(&digits[..], b"18446744073709551615");
+/// }
+/// ```
+#[inline]
+pub fn itoa_heapless_vec<const N: usize, T: IntoAscii + Copy>(
+    n: T,
+) -> Result<Vec<u8, N>, FormatErr> {
+    let mut digits = Vec::new();
+    push_int(&mut digits, n)?;
+
+    Ok(digits)
+}
+
+/// Appends `n` in decimal to `buff`.
+///
+/// # Errors
+/// Returns [`FormatErr::BufferTooSmall`], with the number of bytes `buff` needs to be able to hold in total,
+/// if the digits don't fit in its remaining capacity. `buff` is left unchanged then.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     error::FormatErr,
+///     heapless::push_int,
+/// };
+///
+/// fn main() {
+///     let mut buff = heapless::Vec::<u8, 6>::new();
+///
+///     assert_eq!(push_int(&mut buff, -42i32), Ok(()));
+///     assert_eq!(push_int(&mut buff, 7u8), Ok(()));
+///     assert_eq!(&buff[..], b"-427");
+///
+///     assert_eq!(push_int(&mut buff, 100u8), Err(FormatErr::BufferTooSmall(7)));
+///     assert_eq!(&buff[..], b"-427");
+/// }
+/// ```
+#[inline]
+pub fn push_int<const N: usize, T: IntoAscii + Copy>(
+    buff: &mut Vec<u8, N>,
+    n: T,
+) -> Result<(), FormatErr> {
+    let digits = n.itoa_array();
+
+    buff.extend_from_slice(&digits)
+        .map_err(|_| FormatErr::BufferTooSmall(buff.len() + digits.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{itoa_heapless, itoa_heapless_vec, push_int};
+    use crate::{error::FormatErr, from_ascii::FromAscii};
+    use ::heapless::{String, Vec};
+
+    #[test]
+    fn to_heapless() {
+        assert_eq!(itoa_heapless::<1, _>(0u8).unwrap(), "0");
+        assert_eq!(itoa_heapless::<4, _>(i8::MIN).unwrap(), "-128");
+        assert_eq!(
+            itoa_heapless::<19, _>(i64::MIN),
+            Err(FormatErr::BufferTooSmall(20))
+        );
+        assert_eq!(
+            &itoa_heapless_vec::<20, _>(&u64::MAX).unwrap()[..],
+            b"18446744073709551615"
+        );
+        assert_eq!(
+            itoa_heapless_vec::<0, _>(1u8),
+            Err(FormatErr::BufferTooSmall(1))
+        );
+    }
+
+    #[test]
+    fn push_ints() {
+        let mut buff = Vec::<u8, 12>::new();
+
+        for n in [-1i32, 0, 1, 99].iter() {
+            push_int(&mut buff, *n).unwrap();
+            buff.push(b',').unwrap();
+        }
+
+        assert_eq!(&buff[..], b"-1,0,1,99,");
+        assert_eq!(
+            push_int(&mut buff, 123u16),
+            Err(FormatErr::BufferTooSmall(13))
+        );
+        assert_eq!(&buff[..], b"-1,0,1,99,");
+    }
+
+    #[test]
+    fn from_heapless() {
+        let digits = Vec::<u8, 8>::from_slice(b"-5000").unwrap();
+        assert_eq!(i16::atoi(&digits), Ok(-5000));
+
+        let s: String<3> = itoa_heapless(u8::MAX).unwrap();
+        assert_eq!(u8::atoi(&s), Ok(u8::MAX));
+    }
+}
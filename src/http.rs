@@ -0,0 +1,73 @@
+//! Parsing of the numbers in HTTP/1.1 header values.
+//!
+//! Header values are handed over as they appear after the `:`,
+//! so optional whitespace (OWS, spaces and tabs) around the value is skipped.
+//! The hexadecimal size of a chunk is parsed with [`crate::from_ascii_radix::parse_chunk_size`].
+use crate::{error::ParseIntErr, from_ascii_radix::FromAsciiRadix};
+
+/// Returns whether `byte` is optional whitespace, as defined in RFC 7230.
+#[inline(always)]
+fn is_ows(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+/// Parses a header value of nothing but decimal digits, like `Content-Length`, surrounded by optional whitespace.
+///
+/// Signs are rejected, and numbers that don't fit in a u64 are reported as [`ParseIntErr::PosOverflow`].
+/// The positions of errors are relative to the start of `value`.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     http,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(http::parse_content_length(b" 1024\t"), Ok(1024));
+///     assert_eq!(http::parse_content_length(b" +1024"), Err(ParseIntErr::with_byte_at(b'+', 1)));
+///     assert_eq!(http::parse_content_length(b"10 24"), Err(ParseIntErr::with_byte_at(b' ', 2)));
+///     assert_eq!(http::parse_content_length(b"  "), Err(ParseIntErr::Empty));
+/// }
+/// ```
+#[inline]
+pub fn parse_content_length(value: &[u8]) -> Result<u64, ParseIntErr> {
+    let start = match value.iter().position(|b| !is_ows(*b)) {
+        Some(start) => start,
+        None => return Err(ParseIntErr::Empty),
+    };
+
+    // There is at least one byte that isn't whitespace, at `start`.
+    let end = value.iter().rposition(|b| !is_ows(*b)).map_or(start, |end| end + 1);
+    let digits = &value[start..end];
+
+    if !digits[0].is_ascii_digit() {
+        return Err(ParseIntErr::with_byte_at(digits[0], start));
+    }
+
+    u64::bytes_to_int_radix(digits, 10).map_err(|err| match err {
+        ParseIntErr::InvalidDigit([c], position) => ParseIntErr::with_byte_at(c, position + start),
+        err => err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_content_length, ParseIntErr};
+
+    #[test]
+    fn content_length() {
+        assert_eq!(parse_content_length(b"0"), Ok(0));
+        assert_eq!(parse_content_length(b"007"), Ok(7));
+        assert_eq!(parse_content_length(b"\t \t42 \t "), Ok(42));
+        assert_eq!(parse_content_length(b"18446744073709551615"), Ok(u64::MAX));
+
+        assert_eq!(parse_content_length(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_content_length(b" \t"), Err(ParseIntErr::Empty));
+        assert_eq!(parse_content_length(b"18446744073709551616"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(parse_content_length(b" -0"), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(parse_content_length(b"  12a "), Err(ParseIntErr::with_byte_at(b'a', 4)));
+        assert_eq!(parse_content_length(b"12\r\n"), Err(ParseIntErr::with_byte_at(b'\r', 2)));
+        assert_eq!(parse_content_length(b"12, 12"), Err(ParseIntErr::with_byte_at(b',', 2)));
+    }
+}
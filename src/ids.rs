@@ -0,0 +1,147 @@
+//! Compact textual IDs, in base 36 and base 62.
+//!
+//! Base 36 uses `0-9` followed by lowercase letters, and decodes letters of either case.
+//! Base 62 uses `0-9`, then `A-Z`, then `a-z`, so the case of a letter matters.
+use crate::{
+    constants::{DIGITS_62, DIGITS_LOWER, DIGIT_VALUES, DIGIT_VALUES_62},
+    error::ParseIntErr,
+};
+
+/// This trait encodes integers as compact IDs, and decodes them again.
+/// It is implemented on `u64` and `u128`.
+pub trait Id: Sized {
+    /// Encodes `self` in base 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::ids::Id;
+    ///
+    /// fn main() {
+    ///     assert_eq!(1_295u64.encode_base36(), b"zz");
+    ///     assert_eq!(u64::decode_base36("ZZ"), Ok(1_295));
+    /// }
+    /// ```
+    fn encode_base36(self) -> Vec<u8>;
+
+    /// Encodes `self` in base 62.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::ids::Id;
+    ///
+    /// fn main() {
+    ///     assert_eq!(3_843u64.encode_base62(), b"zz");
+    ///     assert_eq!(u64::decode_base62("zz"), Ok(3_843));
+    ///     assert_eq!(u64::decode_base62("ZZ"), Ok(2_205));
+    /// }
+    /// ```
+    fn encode_base62(self) -> Vec<u8>;
+
+    /// Decodes an ID in base 36. Letters may be of either case.
+    fn decode_base36(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr>;
+
+    /// Decodes an ID in base 62.
+    fn decode_base62(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr>;
+}
+
+macro_rules! id {
+    ($int:ty) => {
+        impl Id for $int {
+            #[inline]
+            fn encode_base36(self) -> Vec<u8> {
+                id!(@encode $int, self, 36, DIGITS_LOWER)
+            }
+
+            #[inline]
+            fn encode_base62(self) -> Vec<u8> {
+                id!(@encode $int, self, 62, DIGITS_62)
+            }
+
+            #[inline]
+            fn decode_base36(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+                id!(@decode $int, s.as_ref(), 36, DIGIT_VALUES)
+            }
+
+            #[inline]
+            fn decode_base62(s: impl AsRef<[u8]>) -> Result<Self, ParseIntErr> {
+                id!(@decode $int, s.as_ref(), 62, DIGIT_VALUES_62)
+            }
+        }
+    };
+
+    (@encode $int:ty, $n:expr, $base:expr, $digits:ident) => {{
+        let mut n: $int = $n;
+        let mut buff = [0; <$int>::BITS as usize];
+        let mut idx = buff.len();
+
+        loop {
+            idx -= 1;
+            buff[idx] = $digits[(n % $base) as usize];
+            n /= $base;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        buff[idx..].to_vec()
+    }};
+
+    (@decode $int:ty, $bytes:expr, $base:expr, $values:ident) => {{
+        let bytes: &[u8] = $bytes;
+
+        if bytes.is_empty() {
+            return Err(ParseIntErr::Empty);
+        }
+
+        let mut result: $int = 0;
+
+        for (position, &byte) in bytes.iter().enumerate() {
+            let d = $values[byte as usize];
+
+            if d >= $base {
+                return Err(ParseIntErr::with_byte_at(byte, position));
+            }
+
+            result = result
+                .checked_mul($base)
+                .and_then(|n| n.checked_add(<$int>::from(d)))
+                .ok_or(ParseIntErr::PosOverflow)?;
+        }
+
+        Ok(result)
+    }};
+}
+
+id!(u64);
+id!(u128);
+
+#[cfg(test)]
+mod tests {
+    use super::{Id, ParseIntErr};
+
+    #[test]
+    fn roundtrip() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            assert_eq!(u64::decode_base36(n.encode_base36()), Ok(n));
+            assert_eq!(u64::decode_base62(n.encode_base62()), Ok(n));
+
+            let wide = u128::from(n) << 64 | u128::from(n);
+            assert_eq!(u128::decode_base36(wide.encode_base36()), Ok(wide));
+            assert_eq!(u128::decode_base62(wide.encode_base62()), Ok(wide));
+        }
+
+        assert_eq!(u128::MAX.encode_base62(), b"7n42DGM5Tflk9n8mt7Fhc7");
+        assert_eq!(u128::decode_base62(u128::MAX.encode_base62()), Ok(u128::MAX));
+        assert_eq!(0u64.encode_base62(), b"0");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(u64::decode_base62(""), Err(ParseIntErr::Empty));
+        assert_eq!(u64::decode_base62("ab-c"), Err(ParseIntErr::with_byte_at(b'-', 2)));
+        assert_eq!(u64::decode_base36("3w5e11264sgsf"), Ok(u64::MAX));
+        assert_eq!(u64::decode_base36("3w5e11264sgsg"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(u64::decode_base62("LygHa16AHYG"), Err(ParseIntErr::PosOverflow));
+    }
+}
@@ -1,4 +1,69 @@
-use crate::constants::ASCII_TO_INT_FACTOR;
+use core::{fmt, mem::MaybeUninit, ops::Deref, str};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use crate::{
+    constants::{ASCII_TO_INT_FACTOR, DIGIT_PAIRS, MAX_INT_LEN},
+    error::FormatErr,
+};
+
+/// How [`IntoAscii::int_to_bytes_fill`] fills the bytes of a buffer that the number doesn't cover.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fill {
+    /// Puts the number at the start of the buffer, and fills the rest with the byte.
+    Left(u8),
+
+    /// Puts the number at the end of the buffer, and fills the start with the byte.
+    Right(u8),
+
+    /// Puts the number at the end of the buffer, with zeroes between the sign and the digits, like `-00042`.
+    Zero,
+}
+
+/// The sizes of the digit groups for [`IntoAscii::itoa_grouped`], from the right.
+/// The last size repeats for all groups after it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Grouping<'a> {
+    sizes: &'a [u8],
+}
+
+impl<'a> Grouping<'a> {
+    /// Groups of three, like `1,234,567`.
+    pub const THOUSANDS: Grouping<'static> = Grouping::new(&[3]);
+
+    /// The Indian numbering system: a group of three, and groups of two after it, like `12,34,567`.
+    pub const INDIAN: Grouping<'static> = Grouping::new(&[3, 2]);
+
+    /// Creates a grouping out of the sizes of the groups, from the right.
+    /// The pattern `3;2` of locale data is `Grouping::new(&[3, 2])`.
+    ///
+    /// # Panics
+    /// Panics if `sizes` is empty, or has a size of 0.
+    #[inline]
+    pub const fn new(sizes: &'a [u8]) -> Self {
+        assert!(!sizes.is_empty(), "a grouping needs at least one size");
+
+        let mut idx = 0;
+        while idx < sizes.len() {
+            assert!(sizes[idx] > 0, "groups can't be empty");
+            idx += 1;
+        }
+
+        Self { sizes }
+    }
+
+    /// Returns the size of group `idx`, counting from the right.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn size(&self, idx: usize) -> usize {
+        self.sizes[idx.min(self.sizes.len() - 1)] as usize
+    }
+}
 
 /// This traits converts integers to bytes, and is implemented on all integer types.
 /// The most important method on this trait is [`IntoAscii::itoa`], which is called in a method-like style.
@@ -16,88 +81,555 @@ pub trait IntoAscii {
     ///     assert_eq!((-12345i32).itoa(), [b'-', b'1', b'2', b'3', b'4', b'5']);
     /// }
     /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
     fn itoa(&self) -> Vec<u8>
     where
         Self: Copy,
     {
-        let size = Self::digits10(*self);
+        let size = self.formatted_len();
         let mut buff = vec![0; size];
 
         self.int_to_bytes(&mut buff);
         buff
     }
 
+    /// Appends the digits of `self` to the end of `buff`, with a `-` for negative numbers.
+    ///
+    /// This reserves exactly the room the number needs, and doesn't allocate a `Vec` per number like [`IntoAscii::itoa`] does.
+    /// Prefer it for building records out of several numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let mut record = Vec::new();
+    ///
+    ///     17u32.itoa_into(&mut record);
+    ///     record.push(b',');
+    ///     (-250i32).itoa_into(&mut record);
+    ///     record.push(b'\n');
+    ///
+    ///     assert_eq!(record, b"17,-250\n");
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa_into(&self, buff: &mut Vec<u8>)
+    where
+        Self: Copy,
+    {
+        let start = buff.len();
+        buff.resize(start + self.formatted_len(), 0);
+
+        self.int_to_bytes(&mut buff[start..]);
+    }
+
+    /// Converts `self` to bytes, with `separator` between groups of digits of the sizes of `grouping`.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::{Grouping, IntoAscii};
+    ///
+    /// fn main() {
+    ///     assert_eq!((-1234567i32).itoa_grouped(b',', Grouping::THOUSANDS), b"-1,234,567");
+    ///     assert_eq!(123456789u32.itoa_grouped(b',', Grouping::INDIAN), b"12,34,56,789");
+    ///     assert_eq!(123456789u64.itoa_grouped(b' ', Grouping::new(&[4])), b"1 2345 6789");
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa_grouped(&self, separator: u8, grouping: Grouping<'_>) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        let mut digits = [0; MAX_INT_LEN];
+        let (sign, digits) = match format_digits(*self, &mut digits) {
+            [b'-', digits @ ..] => (Some(b'-'), digits),
+            digits => (None, digits),
+        };
+
+        // Built from the right, and reversed at the end.
+        let mut buff = Vec::with_capacity(digits.len() * 2 + 1);
+        let mut group = 0;
+        let mut group_len = 0;
+
+        for &digit in digits.iter().rev() {
+            if group_len == grouping.size(group) {
+                buff.push(separator);
+                group += 1;
+                group_len = 0;
+            }
+
+            buff.push(digit);
+            group_len += 1;
+        }
+
+        buff.extend(sign);
+        buff.reverse();
+        buff
+    }
+
+    /// The largest number of digits [`IntoAscii::digits10`] returns for this type.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let mut buff = [0; i32::MAX_FORMATTED_LEN];
+    ///
+    ///     assert_eq!(i32::MIN.int_to_bytes(&mut buff), 11);
+    ///     assert_eq!(&buff, b"-2147483648");
+    ///     assert_eq!(u32::MAX_DIGITS, 10);
+    /// }
+    /// ```
+    const MAX_DIGITS: usize;
+
+    /// The largest number of bytes [`IntoAscii::formatted_len`] returns for this type, sign included.
+    const MAX_FORMATTED_LEN: usize;
+
+    /// The array [`IntoAscii::itoa_array`] returns, which is a [`DigitBuf`] of [`IntoAscii::MAX_FORMATTED_LEN`] bytes.
+    type Digits: Copy + Deref<Target = [u8]> + AsRef<[u8]>;
+
+    /// Formats `self` into an array on the stack, with a `-` for negative numbers.
+    ///
+    /// Unlike [`IntoAscii::itoa`], this never allocates, so it doesn't need the `alloc` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let digits = (-1234i32).itoa_array();
+    ///
+    ///     assert_eq!(&*digits, b"-1234");
+    ///     assert_eq!(u64::MAX.itoa_array().len(), 20);
+    /// }
+    /// ```
+    fn itoa_array(self) -> Self::Digits;
+
     /// Returns the size of an integer. This is how many digits the integer has.
+    /// A `-` is not a digit, see [`IntoAscii::formatted_len`] for the number of bytes `self` is formatted in.
     fn digits10(self) -> usize;
 
-    /// Writes `self` into `buff`.
-    /// This function assumes `buff` has enough space to hold all digits of `self`. For the number of digits `self` has, see [`IntoAscii::digits10`].
-    fn int_to_bytes(self, buff: &mut [u8]);
+    /// Returns the number of bytes `self` is formatted in, which is [`IntoAscii::digits10`], and 1 more for negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     assert_eq!((-123i32).digits10(), 3);
+    ///     assert_eq!((-123i32).formatted_len(), 4);
+    ///     assert_eq!(123u32.formatted_len(), 3);
+    /// }
+    /// ```
+    #[inline]
+    fn formatted_len(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.digits10()
+    }
+
+    /// Writes `self` into the end of `buff`, and returns the number of bytes written.
+    /// The bytes before them are left untouched, so the number is `&buff[buff.len() - written..]`.
+    ///
+    /// # Panics
+    /// Panics if `buff` is too small to hold every byte of `self`, which is [`IntoAscii::digits10`] digits and a possible `-`.
+    /// For a version that doesn't panic, see [`IntoAscii::try_int_to_bytes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let mut buff = [b' '; 8];
+    ///     let written = (-42i32).int_to_bytes(&mut buff);
+    ///
+    ///     assert_eq!(written, 3);
+    ///     assert_eq!(&buff[buff.len() - written..], b"-42");
+    ///     assert_eq!(&buff, b"     -42");
+    /// }
+    /// ```
+    fn int_to_bytes(self, buff: &mut [u8]) -> usize;
+
+    /// Writes `self` into `buff`, filling the bytes that the number doesn't cover as `fill` says.
+    /// Returns the number of bytes of the number itself.
+    ///
+    /// # Panics
+    /// Panics if `buff` is too small to hold every byte of `self`, see [`IntoAscii::int_to_bytes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::{Fill, IntoAscii};
+    ///
+    /// fn main() {
+    ///     let mut buff = [0; 6];
+    ///
+    ///     assert_eq!((-42i32).int_to_bytes_fill(&mut buff, Fill::Right(b' ')), 3);
+    ///     assert_eq!(&buff, b"   -42");
+    ///
+    ///     (-42i32).int_to_bytes_fill(&mut buff, Fill::Left(b'*'));
+    ///     assert_eq!(&buff, b"-42***");
+    ///
+    ///     (-42i32).int_to_bytes_fill(&mut buff, Fill::Zero);
+    ///     assert_eq!(&buff, b"-00042");
+    /// }
+    /// ```
+    #[inline]
+    fn int_to_bytes_fill(self, buff: &mut [u8], fill: Fill) -> usize
+    where
+        Self: Copy,
+    {
+        let written = self.int_to_bytes(buff);
+        let start = buff.len() - written;
+
+        match fill {
+            Fill::Right(byte) => buff[..start].fill(byte),
+            Fill::Left(byte) => {
+                buff.copy_within(start.., 0);
+                buff[written..].fill(byte);
+            }
+            Fill::Zero if buff[start] == b'-' => {
+                buff[..=start].fill(b'0');
+                buff[0] = b'-';
+            }
+            Fill::Zero => buff[..start].fill(b'0'),
+        }
+
+        written
+    }
+
+    /// Like [`IntoAscii::int_to_bytes`], but with a `+` in front of numbers that aren't negative, like `{:+}`.
+    /// Returns the number of bytes written, sign included.
+    ///
+    /// # Panics
+    /// Panics if `buff` is too small to hold the sign and every digit of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let mut buff = [b' '; 4];
+    ///
+    ///     assert_eq!(42u8.int_to_bytes_with_sign(&mut buff), 3);
+    ///     assert_eq!(&buff, b" +42");
+    ///
+    ///     assert_eq!((-42i8).int_to_bytes_with_sign(&mut buff), 3);
+    ///     assert_eq!(&buff, b" -42");
+    /// }
+    /// ```
+    #[inline]
+    fn int_to_bytes_with_sign(self, buff: &mut [u8]) -> usize
+    where
+        Self: Copy,
+    {
+        let written = self.int_to_bytes(buff);
+        let start = buff.len() - written;
+
+        if buff[start] == b'-' {
+            written
+        } else {
+            buff[start - 1] = b'+';
+            written + 1
+        }
+    }
+
+    /// Writes `self` into the start of `buff`, which doesn't have to be initialized, and returns the written part.
+    ///
+    /// This suits writers that format straight into the spare capacity of their buffers, like [`Vec::spare_capacity_mut`].
+    ///
+    /// # Panics
+    /// Panics if `buff` is too small to hold every byte of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use byte_num::into_ascii::IntoAscii;
+    ///
+    /// fn main() {
+    ///     let mut buff = [MaybeUninit::uninit(); 8];
+    ///
+    ///     assert_eq!((-123i32).int_to_uninit_bytes(&mut buff), b"-123");
+    ///
+    ///     let mut out = Vec::with_capacity(16);
+    ///     let len = 4567u64.int_to_uninit_bytes(out.spare_capacity_mut()).len();
+    ///     unsafe { out.set_len(len) };
+    ///
+    ///     assert_eq!(out, b"4567");
+    /// }
+    /// ```
+    #[inline]
+    fn int_to_uninit_bytes(self, buff: &mut [MaybeUninit<u8>]) -> &[u8]
+    where
+        Self: Copy,
+    {
+        let mut digits = [0; MAX_INT_LEN];
+        let digits = format_digits(self, &mut digits);
+
+        let buff = match buff.get_mut(..digits.len()) {
+            Some(buff) => buff,
+            None => panic!("buffer of {} bytes is too small for {} bytes", buff.len(), digits.len()),
+        };
+
+        for (slot, digit) in buff.iter_mut().zip(digits) {
+            slot.write(*digit);
+        }
+
+        // Safety: every byte of `buff` was just initialized, and `MaybeUninit<u8>` has the layout of `u8`.
+        unsafe { &*(buff as *const [MaybeUninit<u8>] as *const [u8]) }
+    }
+
+    /// Writes `self` into the start of `buff`, and returns the number of bytes written.
+    /// Unlike [`IntoAscii::int_to_bytes`], this checks that `buff` is large enough to hold every byte of `self`,
+    /// returning [`FormatErr::BufferTooSmall`] if it isn't.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     into_ascii::IntoAscii,
+    ///     error::FormatErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     let mut buff = [0; 8];
+    ///
+    ///     assert_eq!((-123i32).try_int_to_bytes(&mut buff), Ok(4));
+    ///     assert_eq!(&buff[..4], b"-123");
+    ///
+    ///     assert_eq!(123_456_789u32.try_int_to_bytes(&mut buff), Err(FormatErr::BufferTooSmall(9)));
+    /// }
+    /// ```
+    #[inline]
+    fn try_int_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr>
+    where
+        Self: Copy,
+    {
+        let size = self.formatted_len();
+
+        match buff.get_mut(..size) {
+            Some(buff) => {
+                self.int_to_bytes(buff);
+                Ok(size)
+            }
+            None => Err(FormatErr::BufferTooSmall(size)),
+        }
+    }
+}
+
+/// The powers of 10 that fit a u64, indexed by their exponent.
+const POW10: [u64; 20] = {
+    let mut table = [1; 20];
+    let mut idx = 1;
+
+    while idx < 20 {
+        table[idx] = table[idx - 1] * 10;
+        idx += 1;
+    }
+
+    table
+};
+
+/// Writes the pair of digits of `n`, which is below 100.
+#[inline(always)]
+fn write_pair(n: u32, out: &mut [u8]) {
+    let idx = n as usize * 2;
+    out.copy_from_slice(&DIGIT_PAIRS[idx..idx + 2]);
+}
+
+/// Writes the `out.len()` low digits of `n`, which has at most 4 digits, without a loop.
+#[inline(always)]
+fn write_4(n: u32, out: &mut [u8]) {
+    match out {
+        [digit] => *digit = n as u8 + ASCII_TO_INT_FACTOR,
+        [_, _] => write_pair(n, out),
+        [first, rest @ ..] if rest.len() == 2 => {
+            *first = (n / 100) as u8 + ASCII_TO_INT_FACTOR;
+            write_pair(n % 100, rest);
+        }
+        [_, _, _, _] => {
+            let (high, low) = out.split_at_mut(2);
+            write_pair(n / 100, high);
+            write_pair(n % 100, low);
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Writes `n`, which has 5 to 8 digits, into `out`, which is as long as `n` has digits.
+#[inline(always)]
+fn write_8(n: u32, out: &mut [u8]) {
+    let (high, low) = out.split_at_mut(out.len() - 4);
+    write_4(n / 10_000, high);
+    write_4(n % 10_000, low);
+}
+
+/// Writes `n`, which has 9 to 12 digits, into `out`, which is as long as `n` has digits.
+#[inline(always)]
+fn write_12(n: u64, out: &mut [u8]) {
+    let (high, low) = out.split_at_mut(out.len() - 8);
+    let low_n = (n % 100_000_000) as u32;
+
+    write_4((n / 100_000_000) as u32, high);
+    write_4(low_n / 10_000, &mut low[..4]);
+    write_4(low_n % 10_000, &mut low[4..]);
+}
+
+/// Writes the digits of `n` at the end of `buff`, and returns where they start.
+///
+/// Unlike [`IntoAscii::int_to_bytes`] this doesn't need the number of digits up front,
+/// so formatting takes a single pass over `n`.
+#[cfg(feature = "alloc")]
+#[inline]
+fn write_tail(mut n: u64, buff: &mut [u8]) -> usize {
+    let mut start = buff.len();
+
+    while n >= 100 {
+        start -= 2;
+        write_pair((n % 100) as u32, &mut buff[start..start + 2]);
+        n /= 100;
+    }
+
+    if n >= 10 {
+        start -= 2;
+        write_pair(n as u32, &mut buff[start..start + 2]);
+    } else {
+        start -= 1;
+        buff[start] = n as u8 + ASCII_TO_INT_FACTOR;
+    }
+
+    start
+}
+
+/// Formats `n` as exactly two digits, with a leading zero below 10.
+///
+/// # Panics
+/// Panics if `n` is 100 or more.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::format2;
+///
+/// fn main() {
+///     assert_eq!(&format2(42), b"42");
+///     assert_eq!(&format2(7), b"07");
+/// }
+/// ```
+#[inline(always)]
+pub fn format2(n: u8) -> [u8; 2] {
+    assert!(n < 100, "{} doesn't fit in two digits", n);
+
+    let idx = n as usize * 2;
+    [DIGIT_PAIRS[idx], DIGIT_PAIRS[idx + 1]]
+}
+
+/// Formats `n` as exactly three digits, with leading zeroes below 100.
+///
+/// # Panics
+/// Panics if `n` is 1000 or more.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::format3;
+///
+/// fn main() {
+///     assert_eq!(&format3(404), b"404");
+///     assert_eq!(&format3(8), b"008");
+/// }
+/// ```
+#[inline(always)]
+pub fn format3(n: u16) -> [u8; 3] {
+    assert!(n < 1000, "{} doesn't fit in three digits", n);
+
+    let [b, c] = format2((n % 100) as u8);
+    [(n / 100) as u8 + ASCII_TO_INT_FACTOR, b, c]
 }
 
 #[rustfmt::skip]
 macro_rules! unsigned_into_ascii {
     ($int:ty) => {     
         impl IntoAscii for $int {
-            #[inline]    
-            fn digits10(mut self) -> usize {
-                let mut result = 1;
-                loop {
-                    if self < 10 { break result;}
-                    if self < 100 { break result + 1; }
-                    if self < 1000 { break result + 2; }
-                    if self < 10000 { break result + 3; }
-        
-                    self /= 10_000;
-                    result += 4;
-                }
+            const MAX_DIGITS: usize = <$int>::MAX.ilog10() as usize + 1;
+            const MAX_FORMATTED_LEN: usize = Self::MAX_DIGITS;
+
+            type Digits = DigitBuf<{ <$int as IntoAscii>::MAX_FORMATTED_LEN }>;
+
+            #[inline]
+            fn itoa_array(self) -> Self::Digits {
+                DigitBuf::format(self)
             }
-        
+
+            // floor(log10(self)) is estimated from the bit length, as bits * log10(2) ~= bits * 1233 / 4096,
+            // which is exact or 1 too small. A single compare with a power of 10 corrects it.
+            // `| 1` makes 0 a single digit, and never crosses a power of 10, since those are even.
             #[inline]
-            fn int_to_bytes(mut self, buff: &mut [u8]) {
-                let mut chunked = buff.rchunks_exact_mut(4);
-                for mut chunk in chunked.by_ref() {
-                    let q = self / 10;
-                    let q1 = self / 100;
-                    let q2 = self / 1000;
-        
-                    let r = (self % 10) as u8 + ASCII_TO_INT_FACTOR;
-                    let r1 = (q   % 10) as u8 + ASCII_TO_INT_FACTOR;
-                    let r2 = (q1  % 10) as u8 + ASCII_TO_INT_FACTOR;
-                    let r3 = (q2  % 10) as u8 + ASCII_TO_INT_FACTOR;
+            fn digits10(self) -> usize {
+                let n = self as u64 | 1;
+                let bits = u64::BITS - n.leading_zeros();
+                let log = ((bits * 1233) >> 12) as usize;
+
+                log + (n >= POW10[log]) as usize
+            }
+
+            // Formats into the tail of a buffer on the stack, instead of counting the digits first.
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn itoa(&self) -> Vec<u8> {
+                let mut buff = [0; <$int as IntoAscii>::MAX_DIGITS];
+                let start = write_tail(*self as u64, &mut buff);
+
+                buff[start..].to_vec()
+            }
         
-                    match &mut chunk {
-                        [b3, b2, b1, b] => {
-                            *b = r;
-                            *b1 = r1;
-                            *b2 = r2;
-                            *b3 = r3;
+            #[inline]
+            fn int_to_bytes(mut self, buff: &mut [u8]) -> usize {
+                let size = self.digits10();
+                let start = buff.len() - size;
+
+                // The short numbers that are most common get writers without loops.
+                // Longer numbers, which only fit in a u64, take two digits per division.
+                let out = &mut buff[start..];
+                match size {
+                    1..=4 => write_4(self as u32, out),
+                    5..=8 => write_8(self as u32, out),
+                    9..=12 => write_12(self as u64, out),
+                    _ => {
+                        let mut pairs = out.rchunks_exact_mut(2);
+                        for pair in pairs.by_ref() {
+                            let idx = (self % 100) as usize * 2;
+                            pair.copy_from_slice(&DIGIT_PAIRS[idx..idx + 2]);
+
+                            self /= 100;
+                        }
+
+                        // An odd number of digits leaves one, which is all that's left of `self`.
+                        if let [digit] = pairs.into_remainder() {
+                            *digit = self as u8 + ASCII_TO_INT_FACTOR;
                         }
-                        _ => unreachable!(),
-                    }
-        
-                    self /= 10_000;
-                }
-        
-                for byte in chunked.into_remainder().iter_mut().rev() {
-                    let q = self / 10;
-                    let r = (self % 10) as u8 + ASCII_TO_INT_FACTOR;
-                    *byte = r;
-        
-                    //there's nothing more to do.
-                    if q == 0 {
-                        break;
                     }
-        
-                    self = q;
                 }
+
+                size
             }
         }
     };
 
     (@u8) => {
         impl IntoAscii for u8 {
+            const MAX_DIGITS: usize = 3;
+            const MAX_FORMATTED_LEN: usize = 3;
+
+            type Digits = DigitBuf<3>;
+
+            #[inline]
+            fn itoa_array(self) -> Self::Digits {
+                DigitBuf::format(self)
+            }
+
             #[inline]
             fn digits10(self) -> usize {
                 if self < 10 {
@@ -110,18 +642,17 @@ macro_rules! unsigned_into_ascii {
             }
         
             #[inline]
-            fn int_to_bytes(mut self, buff: &mut [u8]) {
-                for byte in buff.iter_mut().rev() {
-                    let q = self / 10;
-                    let r = (self % 10) as u8 + ASCII_TO_INT_FACTOR;
-                    *byte = r;
-        
-                    if self == 0 {
-                        break;
-                    }
-        
-                    self = q;
+            fn int_to_bytes(self, buff: &mut [u8]) -> usize {
+                let size = self.digits10();
+                let start = buff.len() - size;
+
+                match size {
+                    1 => buff[start] = self + ASCII_TO_INT_FACTOR,
+                    2 => buff[start..].copy_from_slice(&format2(self)),
+                    _ => buff[start..].copy_from_slice(&format3(u16::from(self))),
                 }
+
+                size
             }
         }
     };
@@ -130,34 +661,52 @@ macro_rules! unsigned_into_ascii {
 macro_rules! signed_into_ascii {
     ($int:ty, $unsigned_version:ty) => {
         impl IntoAscii for $int {
+            const MAX_DIGITS: usize = <$int>::MIN.unsigned_abs().ilog10() as usize + 1;
+            const MAX_FORMATTED_LEN: usize = Self::MAX_DIGITS + 1;
+
+            type Digits = DigitBuf<{ <$int as IntoAscii>::MAX_FORMATTED_LEN }>;
+
             #[inline]
-            fn itoa(&self) -> Vec<u8>
-            where
-                Self: Copy,
-            {
-                let (n, size) = if self.is_negative() {
-                    (self * -1, self.digits10() + 1)
-                } else {
-                    (*self, self.digits10())
-                };
-        
-                let mut buff = vec![b'-'; size];
-                (n as $unsigned_version).int_to_bytes(&mut buff);
-                buff
+            fn itoa_array(self) -> Self::Digits {
+                DigitBuf::format(self)
             }
-        
+
+            // All paths go through the unsigned magnitude,
+            // since negating MIN overflows the signed type.
             #[inline]
             fn digits10(self) -> usize {
-                (self.abs() as $unsigned_version).digits10()
+                self.unsigned_abs().digits10()
+            }
+
+            #[inline]
+            fn formatted_len(self) -> usize {
+                self.unsigned_abs().digits10() + self.is_negative() as usize
+            }
+
+            #[cfg(feature = "alloc")]
+            #[inline]
+            fn itoa(&self) -> Vec<u8> {
+                let mut buff = [0; <$int as IntoAscii>::MAX_FORMATTED_LEN];
+                let mut start = write_tail(self.unsigned_abs() as u64, &mut buff);
+
+                if self.is_negative() {
+                    start -= 1;
+                    buff[start] = b'-';
+                }
+
+                buff[start..].to_vec()
             }
         
             #[inline]
-            fn int_to_bytes(self, buff: &mut [u8]) {
+            fn int_to_bytes(self, buff: &mut [u8]) -> usize {
+                let size = self.unsigned_abs().int_to_bytes(buff);
+
                 if self.is_negative() {
-                    (self.abs() as $unsigned_version).int_to_bytes(buff);
-                    buff[0] = b'-';
+                    let sign = buff.len() - size - 1;
+                    buff[sign] = b'-';
+                    size + 1
                 } else {
-                    (self as $unsigned_version).int_to_bytes(buff);
+                    size
                 }
             }
         }
@@ -176,54 +725,581 @@ signed_into_ascii!(i32, u32);
 signed_into_ascii!(i64, u64);
 signed_into_ascii!(isize, usize);
 
-impl<'a, N: Copy> IntoAscii for &'a N
+impl<N: Copy> IntoAscii for &N
 where
     N: IntoAscii,
 {
+    const MAX_DIGITS: usize = N::MAX_DIGITS;
+    const MAX_FORMATTED_LEN: usize = N::MAX_FORMATTED_LEN;
+
+    type Digits = N::Digits;
+
+    #[inline]
+    fn itoa_array(self) -> Self::Digits {
+        (*self).itoa_array()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        (**self).itoa()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa_into(&self, buff: &mut Vec<u8>)
+    where
+        Self: Copy,
+    {
+        (**self).itoa_into(buff);
+    }
+
     #[inline]
     fn digits10(self) -> usize {
         (*self).digits10()
     }
 
     #[inline]
-    fn int_to_bytes(self, buff: &mut [u8]) {
-        (*self).int_to_bytes(buff);
+    fn formatted_len(self) -> usize {
+        (*self).formatted_len()
+    }
+
+    #[inline]
+    fn int_to_bytes(self, buff: &mut [u8]) -> usize {
+        (*self).int_to_bytes(buff)
+    }
+
+    #[inline]
+    fn try_int_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr> {
+        (*self).try_int_to_bytes(buff)
     }
 }
 
-impl<'a, N: Copy> IntoAscii for &'a mut N
+impl<N: Copy> IntoAscii for &mut N
 where
     N: IntoAscii,
 {
+    const MAX_DIGITS: usize = N::MAX_DIGITS;
+    const MAX_FORMATTED_LEN: usize = N::MAX_FORMATTED_LEN;
+
+    type Digits = N::Digits;
+
+    #[inline]
+    fn itoa_array(self) -> Self::Digits {
+        (*self).itoa_array()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        (**self).itoa()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa_into(&self, buff: &mut Vec<u8>)
+    where
+        Self: Copy,
+    {
+        (**self).itoa_into(buff);
+    }
+
     #[inline]
     fn digits10(self) -> usize {
         (*self).digits10()
     }
 
     #[inline]
-    fn int_to_bytes(self, buff: &mut [u8]) {
-        (*self).int_to_bytes(buff);
+    fn formatted_len(self) -> usize {
+        (*self).formatted_len()
+    }
+
+    #[inline]
+    fn int_to_bytes(self, buff: &mut [u8]) -> usize {
+        (*self).int_to_bytes(buff)
+    }
+
+    #[inline]
+    fn try_int_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr> {
+        (*self).try_int_to_bytes(buff)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<N: Copy> IntoAscii for Box<N>
 where
     N: IntoAscii,
 {
+    const MAX_DIGITS: usize = N::MAX_DIGITS;
+    const MAX_FORMATTED_LEN: usize = N::MAX_FORMATTED_LEN;
+
+    type Digits = N::Digits;
+
+    #[inline]
+    fn itoa_array(self) -> Self::Digits {
+        (*self).itoa_array()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        (**self).itoa()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn itoa_into(&self, buff: &mut Vec<u8>)
+    where
+        Self: Copy,
+    {
+        (**self).itoa_into(buff);
+    }
+
     #[inline]
     fn digits10(self) -> usize {
         (*self).digits10()
     }
 
     #[inline]
-    fn int_to_bytes(self, buff: &mut [u8]) {
-        (*self).int_to_bytes(buff);
+    fn formatted_len(self) -> usize {
+        (*self).formatted_len()
+    }
+
+    #[inline]
+    fn int_to_bytes(self, buff: &mut [u8]) -> usize {
+        (*self).int_to_bytes(buff)
+    }
+
+    #[inline]
+    fn try_int_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr> {
+        (*self).try_int_to_bytes(buff)
+    }
+}
+
+/// Formats `n` into `digits`, and returns the part that holds it.
+#[inline(always)]
+pub(crate) fn format_digits<N: IntoAscii + Copy>(n: N, digits: &mut [u8; MAX_INT_LEN]) -> &[u8] {
+    match n.try_int_to_bytes(digits) {
+        Ok(len) => &digits[..len],
+        Err(_) => unreachable!("integer longer than {} bytes", MAX_INT_LEN),
+    }
+}
+
+/// A buffer on the stack, that holds the digits of the last integer formatted into it.
+///
+/// Formatting never touches the heap, and returns a `&str` right away.
+/// The buffer is large enough for every integer type that implements [`IntoAscii`].
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::Buffer;
+///
+/// fn main() {
+///     let mut buffer = Buffer::new();
+///
+///     assert_eq!(buffer.format(1234u16), "1234");
+///     assert_eq!(buffer.format(i64::MIN), "-9223372036854775808");
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Buffer {
+    digits: [u8; MAX_INT_LEN],
+}
+
+impl Buffer {
+    /// Creates a new buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            digits: [0; MAX_INT_LEN],
+        }
+    }
+
+    /// Formats `n` into this buffer, and returns the digits, with a `-` for negative numbers.
+    #[inline]
+    pub fn format<N: IntoAscii + Copy>(&mut self, n: N) -> &str {
+        let digits = format_digits(n, &mut self.digits);
+
+        // Safety: the digits, and the sign, are all ASCII.
+        unsafe { str::from_utf8_unchecked(digits) }
+    }
+
+    /// Like [`Buffer::format`], but with a `+` in front of numbers that aren't negative, like `{:+}`.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii::Buffer;
+    ///
+    /// fn main() {
+    ///     let mut buffer = Buffer::new();
+    ///
+    ///     assert_eq!(buffer.format_with_sign(0u32), "+0");
+    ///     assert_eq!(buffer.format_with_sign(-15i64), "-15");
+    /// }
+    /// ```
+    #[inline]
+    pub fn format_with_sign<N: IntoAscii + Copy>(&mut self, n: N) -> &str {
+        let written = n.int_to_bytes_with_sign(&mut self.digits);
+
+        // Safety: the digits, and the sign, are all ASCII.
+        unsafe { str::from_utf8_unchecked(&self.digits[MAX_INT_LEN - written..]) }
+    }
+}
+
+impl Default for Buffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Buffer { .. }")
+    }
+}
+
+/// The digits of an integer, in an array on the stack, returned by [`IntoAscii::itoa_array`].
+///
+/// `N` is the [`IntoAscii::MAX_FORMATTED_LEN`] of the integer type, so every value of that type fits.
+/// It dereferences to the digits, with a `-` for negative numbers.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::{DigitBuf, IntoAscii};
+///
+/// fn main() {
+///     let digits: DigitBuf<5> = 404u16.itoa_array();
+///
+///     assert_eq!(digits.as_str(), "404");
+///     assert_eq!(digits.as_ref(), b"404");
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct DigitBuf<const N: usize> {
+    bytes: [u8; N],
+    start: usize,
+}
+
+impl<const N: usize> DigitBuf<N> {
+    /// Formats `n` at the end of the array. `N` has to be at least the [`IntoAscii::formatted_len`] of `n`.
+    #[inline]
+    fn format<T: IntoAscii>(n: T) -> Self {
+        let mut bytes = [0; N];
+        let len = n.int_to_bytes(&mut bytes);
+
+        Self { bytes, start: N - len }
+    }
+
+    /// Returns the digits as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Safety: the digits, and the sign, are all ASCII.
+        unsafe { str::from_utf8_unchecked(self) }
+    }
+}
+
+impl<const N: usize> Deref for DigitBuf<N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.bytes[self.start..]
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for DigitBuf<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> fmt::Debug for DigitBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("DigitBuf").field(&self.as_str()).finish()
+    }
+}
+
+/// The units of [`itoa_si`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Units {
+    /// Powers of 1000, with the SI prefixes `k`, `M`, `G`, `T`, `P` and `E`.
+    Decimal,
+
+    /// Powers of 1024, with the binary prefixes `Ki`, `Mi`, `Gi`, `Ti`, `Pi` and `Ei`.
+    Binary,
+}
+
+#[cfg(feature = "alloc")]
+impl Units {
+    #[inline]
+    fn base(self) -> u128 {
+        match self {
+            Units::Decimal => 1000,
+            Units::Binary => 1024,
+        }
+    }
+
+    #[inline]
+    fn prefix(self, exp: u32) -> &'static [u8] {
+        const DECIMAL: [&[u8]; 7] = [b"", b"k", b"M", b"G", b"T", b"P", b"E"];
+        const BINARY: [&[u8]; 7] = [b"", b"Ki", b"Mi", b"Gi", b"Ti", b"Pi", b"Ei"];
+
+        match self {
+            Units::Decimal => DECIMAL[exp as usize],
+            Units::Binary => BINARY[exp as usize],
+        }
+    }
+}
+
+/// Formats `n` for humans, as a number below 1000 or 1024 with `precision` decimals, and a prefix like `k` or `Mi`.
+///
+/// The number is rounded half up. Numbers below the base are formatted without decimals or prefix.
+///
+/// # Panics
+/// Panics if `precision` is larger than 18.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::{itoa_si, Units};
+///
+/// fn main() {
+///     assert_eq!(itoa_si(1234, 1, Units::Decimal), b"1.2k");
+///     assert_eq!(itoa_si(3_450_000, 1, Units::Decimal), b"3.5M");
+///     assert_eq!(itoa_si(2_100_000_000, 2, Units::Decimal), b"2.10G");
+///     assert_eq!(itoa_si(1536, 1, Units::Binary), b"1.5Ki");
+///     assert_eq!(itoa_si(999, 1, Units::Decimal), b"999");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn itoa_si(n: u64, precision: u32, units: Units) -> Vec<u8> {
+    assert!(precision <= 18, "precision of {} is larger than 18", precision);
+
+    let base = units.base();
+
+    let mut exp = match units {
+        Units::Decimal => (n.digits10() as u32 - 1) / 3,
+        Units::Binary => (63 - (n | 1).leading_zeros()) / 10,
+    };
+
+    if exp == 0 {
+        return n.itoa();
+    }
+
+    let scale = 10u128.pow(precision);
+    let round = |exp: u32| {
+        let divisor = base.pow(exp);
+        (n as u128 * scale + divisor / 2) / divisor
+    };
+
+    let mut scaled = round(exp);
+
+    // Rounding up may reach the next prefix, like 999.96k to 1000.0k.
+    if scaled >= base * scale && exp < 6 {
+        exp += 1;
+        scaled = round(exp);
+    }
+
+    let mut buff = ((scaled / scale) as u64).itoa();
+
+    if precision > 0 {
+        let fraction = (scaled % scale) as u64;
+        let digits = fraction.digits10();
+
+        buff.push(b'.');
+        buff.resize(buff.len() + precision as usize - digits, b'0');
+        fraction.itoa_into(&mut buff);
+    }
+
+    buff.extend_from_slice(units.prefix(exp));
+    buff
+}
+
+/// Formats `n` in scientific notation, like `1.234e18`, rounded half up to `significant` digits.
+///
+/// # Panics
+/// Panics if `significant` is 0, or larger than 20.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::itoa_exp;
+///
+/// fn main() {
+///     assert_eq!(itoa_exp(1_234_000_000_000_000_000, 4), b"1.234e18");
+///     assert_eq!(itoa_exp(u64::MAX, 3), b"1.84e19");
+///     assert_eq!(itoa_exp(9_999, 2), b"1.0e4");
+///     assert_eq!(itoa_exp(42, 1), b"4e1");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn itoa_exp(n: u64, significant: u32) -> Vec<u8> {
+    assert!(
+        (1..=20).contains(&significant),
+        "{} significant digits is not in 1..=20",
+        significant
+    );
+
+    let digits = n.digits10() as u32;
+    let mut exp = digits - 1;
+
+    let mantissa = if digits > significant {
+        let divisor = 10u128.pow(digits - significant);
+        let mantissa = (n as u128 + divisor / 2) / divisor;
+
+        // Rounding up may add a digit, like 9.99 to 10.0.
+        if mantissa == 10u128.pow(significant) {
+            exp += 1;
+            mantissa / 10
+        } else {
+            mantissa
+        }
+    } else {
+        // The zeroes that pad `n` to `significant` digits are already in the buffer.
+        n as u128
+    };
+
+    let mut buff = Vec::with_capacity(significant as usize + 4);
+    buff.resize(significant as usize, b'0');
+
+    // Rounding leaves at most 19 digits, so the mantissa fits a u64.
+    let mantissa = mantissa as u64;
+    mantissa.int_to_bytes(&mut buff[..mantissa.digits10()]);
+
+    if significant > 1 {
+        buff.insert(1, b'.');
+    }
+
+    buff.push(b'e');
+    exp.itoa_into(&mut buff);
+    buff
+}
+
+/// Appends the digits of `n` to `s`, with a `-` for negative numbers.
+///
+/// Like [`IntoAscii::itoa_into`], but for a `String`, without a detour through [`String::from_utf8`].
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::push_int;
+///
+/// fn main() {
+///     let mut s = String::from("x = ");
+///     push_int(&mut s, -273i16);
+///
+///     assert_eq!(s, "x = -273");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn push_int<N: IntoAscii + Copy>(s: &mut String, n: N) {
+    // Safety: the digits, and the sign, are all ASCII, so `s` stays valid UTF-8.
+    n.itoa_into(unsafe { s.as_mut_vec() });
+}
+
+/// Writes `n` into `w`, like `write!(w, "{}", n)`, without going through the formatting machinery of [`core::fmt`].
+///
+/// The digits are formatted on the stack, and pushed as a single `&str`.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::write_int;
+///
+/// fn main() {
+///     let mut record = String::from("id=");
+///
+///     write_int(&mut record, 42u32).unwrap();
+///     record.push_str(",delta=");
+///     write_int(&mut record, -7i8).unwrap();
+///
+///     assert_eq!(record, "id=42,delta=-7");
+/// }
+/// ```
+#[inline]
+pub fn write_int<W: fmt::Write, N: IntoAscii + Copy>(w: &mut W, n: N) -> fmt::Result {
+    w.write_str(Buffer::new().format(n))
+}
+
+/// Writes `n` into `w`, and returns the number of bytes written.
+///
+/// The digits are formatted on the stack, and written with a single call to [`io::Write::write_all`],
+/// so writing numbers to a socket or file doesn't allocate a `Vec` per number.
+/// For many small writes, wrap `w` in a [`std::io::BufWriter`], or use [`crate::io::Printer`].
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::write_int_io;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut out = Vec::new();
+///
+///     assert_eq!(write_int_io(&mut out, -1234i32)?, 5);
+///     assert_eq!(write_int_io(&mut out, 5u8)?, 1);
+///
+///     assert_eq!(out, b"-12345");
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn write_int_io<W: io::Write, N: IntoAscii + Copy>(w: &mut W, n: N) -> io::Result<usize> {
+    let mut digits = [0; MAX_INT_LEN];
+    let digits = format_digits(n, &mut digits);
+
+    w.write_all(digits)?;
+    Ok(digits.len())
+}
+
+/// Formats every number of `values` back to back, and appends them to `buffer`.
+/// Returns the range of `buffer` that holds each number.
+///
+/// Unlike calling [`IntoAscii::itoa`] for every number, this doesn't allocate a `Vec` per number,
+/// which suits building CSV records or protocol frames.
+///
+/// # Examples
+/// ```
+/// use byte_num::into_ascii::itoa_batch;
+///
+/// fn main() {
+///     let mut buffer = Vec::new();
+///     let spans = itoa_batch(&[-1i32, 20, 300], &mut buffer);
+///
+///     assert_eq!(buffer, b"-120300");
+///     assert_eq!(spans, [0..2, 2..4, 4..7]);
+///     assert_eq!(&buffer[spans[2].clone()], b"300");
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn itoa_batch<N: IntoAscii + Copy>(values: &[N], buffer: &mut Vec<u8>) -> Vec<Range<usize>> {
+    let mut spans = Vec::with_capacity(values.len());
+    let mut digits = [0; MAX_INT_LEN];
+
+    buffer.reserve(values.len() * 4);
+
+    for n in values {
+        let start = buffer.len();
+
+        buffer.extend_from_slice(format_digits(*n, &mut digits));
+        spans.push(start..buffer.len());
     }
+
+    spans
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use super::IntoAscii;
+    use super::{format2, format3, itoa_batch, itoa_exp, itoa_si, push_int, Units, Buffer, Fill, Grouping, write_int, write_int_io, FormatErr, IntoAscii};
 
     #[test]
     fn itoa_usize() {
@@ -251,6 +1327,228 @@ mod tests {
         assert_eq!((-0isize).itoa(), vec![b'0']);
     }
 
+    #[test]
+    fn itoa_signed_min() {
+        assert_eq!(i8::MIN.itoa(), b"-128");
+        assert_eq!(i16::MIN.itoa(), b"-32768");
+        assert_eq!(i32::MIN.itoa(), b"-2147483648");
+        assert_eq!(i64::MIN.itoa(), b"-9223372036854775808");
+        assert_eq!(i64::MAX.itoa(), b"9223372036854775807");
+        assert_eq!((-1234567i32).itoa(), b"-1234567");
+
+        let mut buff = [0; 4];
+        assert_eq!(i8::MIN.int_to_bytes(&mut buff), 4);
+        assert_eq!(&buff, b"-128");
+    }
+
+    #[test]
+    fn int_to_bytes_written() {
+        let mut buff = [b'.'; 12];
+
+        assert_eq!(5u32.int_to_bytes(&mut buff), 1);
+        assert_eq!(&buff, b"...........5");
+
+        assert_eq!((-123_456i64).int_to_bytes(&mut buff), 7);
+        assert_eq!(&buff, b".....-123456");
+
+        assert_eq!(7u8.int_to_bytes(&mut buff[..3]), 1);
+        assert_eq!(&buff[..3], b"..7");
+    }
+
+    #[test]
+    fn int_to_bytes_fill() {
+        let mut buff = [0; 5];
+
+        assert_eq!(0u8.int_to_bytes_fill(&mut buff, Fill::Zero), 1);
+        assert_eq!(&buff, b"00000");
+
+        assert_eq!(12345u16.int_to_bytes_fill(&mut buff, Fill::Left(b' ')), 5);
+        assert_eq!(&buff, b"12345");
+
+        assert_eq!((-1i64).int_to_bytes_fill(&mut buff[..2], Fill::Zero), 2);
+        assert_eq!(&buff[..2], b"-1");
+
+        assert_eq!(i8::MIN.int_to_bytes_fill(&mut buff, Fill::Zero), 4);
+        assert_eq!(&buff, b"-0128");
+
+        assert_eq!(7usize.int_to_bytes_fill(&mut buff, Fill::Left(b'_')), 1);
+        assert_eq!(&buff, b"7____");
+    }
+
+    #[test]
+    fn itoa_grouped() {
+        assert_eq!(0u8.itoa_grouped(b',', Grouping::THOUSANDS), b"0");
+        assert_eq!(999i16.itoa_grouped(b',', Grouping::THOUSANDS), b"999");
+        assert_eq!((-1000i16).itoa_grouped(b',', Grouping::THOUSANDS), b"-1,000");
+        assert_eq!(i64::MIN.itoa_grouped(b'.', Grouping::THOUSANDS), b"-9.223.372.036.854.775.808");
+        assert_eq!(10_000_000u32.itoa_grouped(b',', Grouping::INDIAN), b"1,00,00,000");
+        assert_eq!(123456u32.itoa_grouped(b'_', Grouping::new(&[1, 2, 3])), b"123_45_6");
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_group() {
+        Grouping::new(&[3, 0]);
+    }
+
+    #[test]
+    fn si() {
+        assert_eq!(itoa_si(0, 1, Units::Decimal), b"0");
+        assert_eq!(itoa_si(1000, 0, Units::Decimal), b"1k");
+        assert_eq!(itoa_si(1050, 1, Units::Decimal), b"1.1k");
+        assert_eq!(itoa_si(1_001, 3, Units::Decimal), b"1.001k");
+        assert_eq!(itoa_si(999_960, 1, Units::Decimal), b"1.0M");
+        assert_eq!(itoa_si(u64::MAX, 1, Units::Decimal), b"18.4E");
+        assert_eq!(itoa_si(1023, 1, Units::Binary), b"1023");
+        assert_eq!(itoa_si(1024 * 1024 * 3 / 2, 2, Units::Binary), b"1.50Mi");
+        assert_eq!(itoa_si(u64::MAX, 0, Units::Binary), b"16Ei");
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!(itoa_exp(0, 3), b"0.00e0");
+        assert_eq!(itoa_exp(7, 1), b"7e0");
+        assert_eq!(itoa_exp(123, 5), b"1.2300e2");
+        assert_eq!(itoa_exp(1_250, 2), b"1.3e3");
+        assert_eq!(itoa_exp(u64::MAX, 20), b"1.8446744073709551615e19");
+        assert_eq!(itoa_exp(9_999_999_999_999_999_999, 1), b"1e19");
+        assert_eq!(itoa_exp(2, 20), b"2.0000000000000000000e0");
+        assert_eq!(itoa_exp(99, 20), b"9.9000000000000000000e1");
+        assert_eq!(itoa_exp(0, 20), b"0.0000000000000000000e0");
+        assert_eq!(itoa_exp(1_844_674_407_370_955_162, 20), b"1.8446744073709551620e18");
+    }
+
+    #[test]
+    fn with_sign() {
+        let mut buffer = Buffer::new();
+
+        for n in [0i64, 1, -1, i64::MIN, i64::MAX] {
+            assert_eq!(buffer.format_with_sign(n), format!("{:+}", n));
+        }
+        assert_eq!(buffer.format_with_sign(u64::MAX), format!("{:+}", u64::MAX));
+
+        let mut buff = [0; 3];
+        assert_eq!(99u8.int_to_bytes_with_sign(&mut buff), 3);
+        assert_eq!(&buff, b"+99");
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_sign_too_small() {
+        100u8.int_to_bytes_with_sign(&mut [0; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn int_to_bytes_too_small() {
+        (-10i32).int_to_bytes(&mut [0; 2]);
+    }
+
+    #[test]
+    fn try_int_to_bytes() {
+        let mut buff = [b' '; 5];
+
+        assert_eq!(0u8.try_int_to_bytes(&mut buff), Ok(1));
+        assert_eq!(&buff, b"0    ");
+
+        assert_eq!(12345u64.try_int_to_bytes(&mut buff), Ok(5));
+        assert_eq!(&buff, b"12345");
+
+        assert_eq!((-1234i16).try_int_to_bytes(&mut buff), Ok(5));
+        assert_eq!(&buff, b"-1234");
+
+        assert_eq!(i16::MIN.try_int_to_bytes(&mut buff), Err(FormatErr::BufferTooSmall(6)));
+        assert_eq!(123_456u32.try_int_to_bytes(&mut buff), Err(FormatErr::BufferTooSmall(6)));
+        assert_eq!((&255u8).try_int_to_bytes(&mut buff[..2]), Err(FormatErr::BufferTooSmall(3)));
+        assert_eq!((&-1234i32).try_int_to_bytes(&mut buff[..4]), Err(FormatErr::BufferTooSmall(5)));
+    }
+
+    #[test]
+    fn itoa_batch_spans() {
+        let mut buffer = b"x".to_vec();
+
+        assert!(itoa_batch::<u8>(&[], &mut buffer).is_empty());
+        assert_eq!(itoa_batch(&[0u64, u64::MAX], &mut buffer), [1..2, 2..22]);
+        assert_eq!(buffer, b"x018446744073709551615");
+        assert_eq!(itoa_batch(&[i8::MIN], &mut buffer)[0], 22..26);
+    }
+
+    #[test]
+    fn write_int_fmt() {
+        let mut s = String::new();
+
+        for n in [0i64, -1, i64::MIN, i64::MAX] {
+            write_int(&mut s, n).unwrap();
+            s.push(' ');
+        }
+        write_int(&mut s, u64::MAX).unwrap();
+
+        assert_eq!(s, format!("0 -1 {} {} {}", i64::MIN, i64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn write_int_to_io() {
+        let mut out = [0; 4];
+        let mut cursor = &mut out[..];
+
+        assert_eq!(write_int_io(&mut cursor, 12u8).unwrap(), 2);
+        assert_eq!(
+            write_int_io(&mut cursor, -100i16).unwrap_err().kind(),
+            std::io::ErrorKind::WriteZero
+        );
+        assert_eq!(&out[..2], b"12");
+    }
+
+    #[test]
+    fn itoa_into() {
+        let mut buff = b"x".to_vec();
+
+        0u8.itoa_into(&mut buff);
+        i64::MIN.itoa_into(&mut buff);
+        IntoAscii::itoa_into(&&-12i16, &mut buff);
+        usize::MAX.itoa_into(&mut buff);
+
+        assert_eq!(buff, format!("x0{}-12{}", i64::MIN, usize::MAX).into_bytes());
+    }
+
+    #[test]
+    fn push_int_string() {
+        let mut s = String::from("é");
+
+        push_int(&mut s, 0u64);
+        push_int(&mut s, i8::MIN);
+        push_int(&mut s, 42u16);
+
+        assert_eq!(s, "é0-12842");
+    }
+
+    #[test]
+    fn buffer() {
+        let mut buffer = Buffer::default();
+
+        assert_eq!(buffer.format(0u8), "0");
+        assert_eq!(buffer.format(u64::MAX), u64::MAX.to_string());
+        assert_eq!(buffer.format(-1isize), "-1");
+    }
+
+    #[test]
+    fn int_to_uninit_bytes() {
+        use std::mem::MaybeUninit;
+
+        let mut buff = [MaybeUninit::uninit(); 20];
+
+        assert_eq!(0u8.int_to_uninit_bytes(&mut buff), b"0");
+        assert_eq!(u64::MAX.int_to_uninit_bytes(&mut buff), u64::MAX.to_string().as_bytes());
+        assert_eq!(i8::MIN.int_to_uninit_bytes(&mut buff[..4]), b"-128");
+    }
+
+    #[test]
+    #[should_panic]
+    fn int_to_uninit_bytes_too_small() {
+        let mut buff = [std::mem::MaybeUninit::uninit(); 3];
+        (-100i32).int_to_uninit_bytes(&mut buff);
+    }
+
     #[test]
     fn digits10_usize() {
         assert_eq!(123456789usize.digits10(), 9);
@@ -266,6 +1564,99 @@ mod tests {
         assert_eq!(0usize.digits10(), 1);
     }
 
+    #[test]
+    fn formatted_len() {
+        assert_eq!(0i8.formatted_len(), 1);
+        assert_eq!(i8::MIN.formatted_len(), 4);
+        assert_eq!(i64::MIN.formatted_len(), 20);
+        assert_eq!(u64::MAX.formatted_len(), 20);
+        assert_eq!((&-5i32).formatted_len(), 2);
+        assert_eq!(IntoAscii::itoa(&&-5i32), b"-5");
+    }
+
+    #[test]
+    fn max_digits() {
+        assert_eq!(u8::MAX_DIGITS, u8::MAX.digits10());
+        assert_eq!(usize::MAX_DIGITS, usize::MAX.digits10());
+        assert_eq!(i8::MAX_FORMATTED_LEN, i8::MIN.formatted_len());
+        assert_eq!(i64::MAX_FORMATTED_LEN, i64::MIN.formatted_len());
+        assert_eq!(<&u16>::MAX_FORMATTED_LEN, 5);
+    }
+
+    #[test]
+    fn digits10_powers_of_10() {
+        for exp in 0..20 {
+            let pow = 10u64.pow(exp);
+
+            for n in [pow - 1, pow, pow + 1] {
+                assert_eq!(n.digits10(), n.to_string().len(), "{}", n);
+            }
+        }
+
+        assert_eq!(u64::MAX.digits10(), 20);
+        assert_eq!(u16::MAX.digits10(), 5);
+        assert_eq!(u32::MAX.digits10(), 10);
+        assert_eq!(0u32.digits10(), 1);
+    }
+
+    #[test]
+    fn int_to_bytes_all_lengths() {
+        let mut n = 0u64;
+        let mut buffer = Buffer::new();
+
+        for _ in 0..19 {
+            n = n * 10 + 9;
+            assert_eq!(buffer.format(n), n.to_string());
+            assert_eq!(buffer.format(n / 9 * 8 + 1), (n / 9 * 8 + 1).to_string());
+        }
+
+        for n in 0..=u16::MAX {
+            assert_eq!(buffer.format(n), n.to_string());
+        }
+    }
+
+    #[test]
+    fn itoa_single_pass() {
+        for &n in &[0, 7, 10, 99, 100, 12345, i64::MAX, i64::MIN, -1, -100] {
+            assert_eq!(n.itoa(), n.to_string().into_bytes());
+            assert_eq!(IntoAscii::itoa(&&n), n.to_string().into_bytes());
+        }
+
+        assert_eq!(u64::MAX.itoa(), u64::MAX.to_string().into_bytes());
+        assert_eq!(i8::MIN.itoa(), b"-128");
+        assert_eq!(u16::MAX.itoa(), b"65535");
+    }
+
+    #[test]
+    fn format_short() {
+        assert_eq!(&format2(0), b"00");
+        assert_eq!(&format2(99), b"99");
+        assert_eq!(&format3(0), b"000");
+        assert_eq!(&format3(120), b"120");
+        assert_eq!(&format3(999), b"999");
+
+        for n in 0..=u8::MAX {
+            assert_eq!(n.itoa(), n.to_string().into_bytes());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn format3_too_large() {
+        format3(1000);
+    }
+
+    #[test]
+    fn itoa_array() {
+        assert_eq!(&*0u8.itoa_array(), b"0");
+        assert_eq!(&*u8::MAX.itoa_array(), b"255");
+        assert_eq!(&*i8::MIN.itoa_array(), b"-128");
+        assert_eq!(i64::MIN.itoa_array().as_str(), i64::MIN.to_string());
+        assert_eq!(u64::MAX.itoa_array().as_str(), u64::MAX.to_string());
+        assert_eq!(IntoAscii::itoa_array(&-7isize).as_str(), "-7");
+        assert_eq!(format!("{:?}", 12u32.itoa_array()), r#"DigitBuf("12")"#);
+    }
+
     #[test]
     fn digits10_0isize() {
         assert_eq!((-0isize).digits10(), 1);
@@ -0,0 +1,609 @@
+//! Shortest round-trip formatting of floats.
+//!
+//! The digits are found with the Ryū algorithm (Ulf Adams, 2018):
+//! the interval of decimals that round to the float is scaled by a 128-bit approximation of a power of 5,
+//! and digits are removed for as long as the interval still contains a decimal without them.
+//! The result is the shortest decimal that parses back to the same float, closest to the exact value.
+use crate::{error::FormatErr, into_ascii::IntoAscii};
+
+/// This trait converts floats to bytes, using the fewest digits that still parse back to the same float.
+///
+/// Numbers with a decimal point close to the digits are written in decimal notation, always with a fraction,
+/// like `1.0` or `0.001`. Other numbers are written in scientific notation, like `1e30` or `1.5e-7`.
+/// Infinities are written as `inf` and `-inf`, and NaN as `NaN`.
+pub trait IntoAsciiFloat {
+    /// The maximum number of bytes a float of this type takes up.
+    const MAX_LEN: usize;
+
+    /// The function performing the convertion from a float to a Vec<u8>.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_float::IntoAsciiFloat;
+    ///
+    /// fn main() {
+    ///     assert_eq!(0.1f64.ftoa(), b"0.1");
+    ///     assert_eq!((-2.0f64).ftoa(), b"-2.0");
+    ///     assert_eq!(1e30f64.ftoa(), b"1e30");
+    ///     assert_eq!(1.5e-7f32.ftoa(), b"1.5e-7");
+    /// }
+    /// ```
+    fn ftoa(self) -> Vec<u8>;
+
+    /// Writes `self` into the start of `buff`, and returns the number of bytes written.
+    /// This function assumes `buff` has enough space to hold all bytes of `self`,
+    /// which is always the case when it is [`IntoAsciiFloat::MAX_LEN`] bytes long.
+    fn float_to_bytes(self, buff: &mut [u8]) -> usize;
+
+    /// Writes `self` into the start of `buff`, and returns the number of bytes written.
+    /// Unlike [`IntoAsciiFloat::float_to_bytes`], this checks that `buff` is large enough to hold every byte of `self`,
+    /// returning [`FormatErr::BufferTooSmall`] if it isn't.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::{
+    ///     into_ascii_float::IntoAsciiFloat,
+    ///     error::FormatErr,
+    /// };
+    ///
+    /// fn main() {
+    ///     let mut buff = [0; 8];
+    ///
+    ///     assert_eq!(3.25f64.try_float_to_bytes(&mut buff), Ok(4));
+    ///     assert_eq!(&buff[..4], b"3.25");
+    ///
+    ///     assert_eq!(0.1f32.try_float_to_bytes(&mut buff[..2]), Err(FormatErr::BufferTooSmall(3)));
+    /// }
+    /// ```
+    fn try_float_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr>;
+}
+
+/// The number of bits of the entries in [`POW5_SPLIT`] and [`POW5_INV_SPLIT`].
+const POW5_BITCOUNT: i32 = 125;
+const POW5_INV_BITCOUNT: i32 = 125;
+
+/// Returns the number of bits of `5^e`, for `e` in `0..=3528`.
+const fn pow5bits(e: i32) -> i32 {
+    (((e as u32) * 1_217_359) >> 19) as i32 + 1
+}
+
+/// Returns `floor(log10(2^e))`, for `e` in `0..=1650`.
+#[inline(always)]
+fn log10_pow2(e: i32) -> u32 {
+    ((e as u32) * 78_913) >> 18
+}
+
+/// Returns `floor(log10(5^e))`, for `e` in `0..=2620`.
+#[inline(always)]
+fn log10_pow5(e: i32) -> u32 {
+    ((e as u32) * 732_923) >> 20
+}
+
+/// Returns `limbs >> shift`, a little-endian big integer, truncated to 128 bits.
+const fn shr128(limbs: &[u64], shift: usize) -> u128 {
+    let (limb, offset) = (shift / 64, (shift % 64) as u32);
+
+    let w0 = if limb < limbs.len() { limbs[limb] } else { 0 };
+    let w1 = if limb + 1 < limbs.len() { limbs[limb + 1] } else { 0 };
+    let w2 = if limb + 2 < limbs.len() { limbs[limb + 2] } else { 0 };
+
+    let wide = (w0 as u128) | (w1 as u128) << 64;
+
+    if offset == 0 {
+        wide
+    } else {
+        (wide >> offset) | (w2 as u128) << (128 - offset)
+    }
+}
+
+/// `5^i`, normalized to [`POW5_BITCOUNT`] bits and truncated.
+const POW5_SPLIT: [u128; 326] = {
+    let mut table = [0; 326];
+
+    // 5^325 takes 755 bits.
+    let mut pow5 = [0u64; 12];
+    pow5[0] = 1;
+
+    let mut i = 0;
+    while i < table.len() {
+        let bits = pow5bits(i as i32);
+
+        table[i] = if bits <= POW5_BITCOUNT {
+            shr128(&pow5, 0) << (POW5_BITCOUNT - bits)
+        } else {
+            shr128(&pow5, (bits - POW5_BITCOUNT) as usize)
+        };
+
+        let mut carry = 0;
+        let mut idx = 0;
+        while idx < pow5.len() {
+            let v = pow5[idx] as u128 * 5 + carry;
+            pow5[idx] = v as u64;
+            carry = v >> 64;
+            idx += 1;
+        }
+
+        i += 1;
+    }
+
+    table
+};
+
+/// `2^(pow5bits(i) - 1 + POW5_INV_BITCOUNT) / 5^i`, rounded up.
+const POW5_INV_SPLIT: [u128; 342] = {
+    const SHIFT: i32 = 1024;
+
+    let mut table = [0; 342];
+
+    // floor(2^1024 / 5^i), divided by 5 once per step.
+    let mut quotient = [0u64; 17];
+    quotient[16] = 1;
+
+    let mut i = 0;
+    while i < table.len() {
+        let bits = pow5bits(i as i32) - 1 + POW5_INV_BITCOUNT;
+        table[i] = shr128(&quotient, (SHIFT - bits) as usize) + 1;
+
+        let mut rem = 0;
+        let mut idx = quotient.len();
+        while idx > 0 {
+            idx -= 1;
+            let v = (rem << 64) | quotient[idx] as u128;
+            quotient[idx] = (v / 5) as u64;
+            rem = v % 5;
+        }
+
+        i += 1;
+    }
+
+    table
+};
+
+#[inline(always)]
+fn pow5_factor(mut value: u64) -> u32 {
+    let mut count = 0;
+
+    while value.is_multiple_of(5) {
+        value /= 5;
+        count += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+fn multiple_of_power_of_5(value: u64, p: u32) -> bool {
+    pow5_factor(value) >= p
+}
+
+#[inline(always)]
+fn multiple_of_power_of_2(value: u64, p: u32) -> bool {
+    value & ((1 << p) - 1) == 0
+}
+
+/// Returns `(m * mul) >> j`, where `m` has at most 55 bits, and `j` is at least 64.
+#[inline(always)]
+fn mul_shift_64(m: u64, mul: u128, j: u32) -> u64 {
+    let b0 = u128::from(m) * (mul as u64 as u128);
+    let b2 = u128::from(m) * (mul >> 64);
+
+    (((b0 >> 64) + b2) >> (j - 64)) as u64
+}
+
+/// A finite, non-zero float as `mantissa * 10^exponent`.
+struct Decimal {
+    mantissa: u64,
+    exponent: i32,
+}
+
+/// Finds the shortest decimal that rounds to the float with the given bits.
+/// `mantissa_bits` and `bias` describe the float type, so this works for both `f32` and `f64`.
+#[inline]
+fn shortest(ieee_mantissa: u64, ieee_exponent: u32, mantissa_bits: u32, bias: i32) -> Decimal {
+    // Subtract 2 more, so the bounds of the interval are integers.
+    let (e2, m2) = if ieee_exponent == 0 {
+        (1 - bias - mantissa_bits as i32 - 2, ieee_mantissa)
+    } else {
+        (
+            ieee_exponent as i32 - bias - mantissa_bits as i32 - 2,
+            (1 << mantissa_bits) | ieee_mantissa,
+        )
+    };
+
+    // With an even mantissa, the bounds themselves round to this float as well.
+    let accept_bounds = m2 & 1 == 0;
+
+    // The interval is [mv - 1 - mm_shift, mv + 2] / 4. The lower bound is closer at a power of two.
+    let mv = 4 * m2;
+    let mm_shift = u64::from(ieee_mantissa != 0 || ieee_exponent <= 1);
+
+    // Scale the interval to decimal, as vm, vr, vp times 10^e10.
+    let (mut vr, mut vp, mut vm, e10);
+    let mut vm_is_trailing_zeros = false;
+    let mut vr_is_trailing_zeros = false;
+
+    if e2 >= 0 {
+        let q = log10_pow2(e2) - (e2 > 3) as u32;
+        e10 = q as i32;
+
+        let k = POW5_INV_BITCOUNT + pow5bits(q as i32) - 1;
+        let j = (-e2 + q as i32 + k) as u32;
+        let mul = POW5_INV_SPLIT[q as usize];
+
+        vr = mul_shift_64(mv, mul, j);
+        vp = mul_shift_64(mv + 2, mul, j);
+        vm = mul_shift_64(mv - 1 - mm_shift, mul, j);
+
+        // Only with small q, dividing by 10^q could have been exact.
+        if q <= 21 {
+            if mv.is_multiple_of(5) {
+                vr_is_trailing_zeros = multiple_of_power_of_5(mv, q);
+            } else if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mv - 1 - mm_shift, q);
+            } else {
+                vp -= multiple_of_power_of_5(mv + 2, q) as u64;
+            }
+        }
+    } else {
+        let q = log10_pow5(-e2) - (-e2 > 1) as u32;
+        e10 = q as i32 + e2;
+
+        let i = -e2 - q as i32;
+        let k = pow5bits(i) - POW5_BITCOUNT;
+        let j = (q as i32 - k) as u32;
+        let mul = POW5_SPLIT[i as usize];
+
+        vr = mul_shift_64(mv, mul, j);
+        vp = mul_shift_64(mv + 2, mul, j);
+        vm = mul_shift_64(mv - 1 - mm_shift, mul, j);
+
+        if q <= 1 {
+            // mv = 4 * m2 always has at least 2 trailing zero bits.
+            vr_is_trailing_zeros = true;
+
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        } else if q < 63 {
+            vr_is_trailing_zeros = multiple_of_power_of_2(mv, q);
+        }
+    }
+
+    // Remove digits while the interval still holds a shorter decimal.
+    let mut removed = 0;
+    let mut last_removed_digit = 0;
+
+    let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+        // The rare case, where the removed digits might be exactly a half, or a bound is exact.
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm.is_multiple_of(10);
+            vr_is_trailing_zeros &= last_removed_digit == 0;
+            last_removed_digit = vr % 10;
+
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+
+        if vm_is_trailing_zeros {
+            while vm.is_multiple_of(10) {
+                vr_is_trailing_zeros &= last_removed_digit == 0;
+                last_removed_digit = vr % 10;
+
+                vr /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+        }
+
+        // Exactly halfway, round to even.
+        if vr_is_trailing_zeros && last_removed_digit == 5 && vr.is_multiple_of(2) {
+            last_removed_digit = 4;
+        }
+
+        let round_up = (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5;
+        vr + round_up as u64
+    } else {
+        let mut round_up = false;
+
+        if vp / 100 > vm / 100 {
+            round_up = vr % 100 >= 50;
+
+            vr /= 100;
+            vp /= 100;
+            vm /= 100;
+            removed += 2;
+        }
+
+        while vp / 10 > vm / 10 {
+            round_up = vr % 10 >= 5;
+
+            vr /= 10;
+            vp /= 10;
+            vm /= 10;
+            removed += 1;
+        }
+
+        vr + (vr == vm || round_up) as u64
+    };
+
+    Decimal {
+        mantissa: output,
+        exponent: e10 + removed,
+    }
+}
+
+/// Writes `decimal` into `buff`, starting at `idx`, and returns the index after the last byte written.
+/// Decimal notation is used while the decimal point is at most `max_point` digits from the start.
+#[inline]
+fn write_decimal(decimal: Decimal, max_point: i32, buff: &mut [u8], mut idx: usize) -> usize {
+    let length = decimal.mantissa.digits10();
+    let k = decimal.exponent;
+    let point = length as i32 + k;
+
+    if 0 <= k && point <= max_point {
+        // 1234e7 -> 12340000000.0
+        decimal.mantissa.int_to_bytes(&mut buff[idx..idx + length]);
+        idx += length;
+
+        for byte in &mut buff[idx..idx + k as usize] {
+            *byte = b'0';
+        }
+        idx += k as usize;
+
+        buff[idx..idx + 2].copy_from_slice(b".0");
+        idx + 2
+    } else if 0 < point && point <= max_point {
+        // 1234e-2 -> 12.34
+        let point = point as usize;
+
+        decimal.mantissa.int_to_bytes(&mut buff[idx + 1..idx + 1 + length]);
+        buff.copy_within(idx + 1..idx + 1 + point, idx);
+        buff[idx + point] = b'.';
+
+        idx + length + 1
+    } else if -5 < point && point <= 0 {
+        // 1234e-6 -> 0.001234
+        let zeros = -point as usize;
+
+        buff[idx..idx + 2].copy_from_slice(b"0.");
+        idx += 2;
+
+        for byte in &mut buff[idx..idx + zeros] {
+            *byte = b'0';
+        }
+        idx += zeros;
+
+        decimal.mantissa.int_to_bytes(&mut buff[idx..idx + length]);
+        idx + length
+    } else {
+        // 1234e30 -> 1.234e33
+        decimal.mantissa.int_to_bytes(&mut buff[idx + 1..idx + 1 + length]);
+        buff[idx] = buff[idx + 1];
+
+        if length == 1 {
+            idx += 1;
+        } else {
+            buff[idx + 1] = b'.';
+            idx += length + 1;
+        }
+
+        buff[idx] = b'e';
+        idx += 1;
+
+        let exponent = point - 1;
+        let exponent_len = exponent.formatted_len();
+        exponent.int_to_bytes(&mut buff[idx..idx + exponent_len]);
+
+        idx + exponent_len
+    }
+}
+
+macro_rules! into_ascii_float {
+    ($float:ty, $max_len:expr, $mantissa_bits:expr, $exponent_bits:expr, $max_point:expr) => {
+        impl IntoAsciiFloat for $float {
+            const MAX_LEN: usize = $max_len;
+
+            #[inline]
+            fn ftoa(self) -> Vec<u8> {
+                let mut buff = [0; $max_len];
+                let len = self.float_to_bytes(&mut buff);
+
+                buff[..len].to_vec()
+            }
+
+            #[inline]
+            fn float_to_bytes(self, buff: &mut [u8]) -> usize {
+                if self.is_nan() {
+                    buff[..3].copy_from_slice(b"NaN");
+                    return 3;
+                }
+
+                let mut idx = 0;
+
+                if self.is_sign_negative() {
+                    buff[0] = b'-';
+                    idx = 1;
+                }
+
+                if self.is_infinite() {
+                    buff[idx..idx + 3].copy_from_slice(b"inf");
+                    return idx + 3;
+                }
+
+                if self == 0.0 {
+                    buff[idx..idx + 3].copy_from_slice(b"0.0");
+                    return idx + 3;
+                }
+
+                let bits = u64::from(self.to_bits());
+                let ieee_mantissa = bits & ((1 << $mantissa_bits) - 1);
+                let ieee_exponent = ((bits >> $mantissa_bits) & ((1 << $exponent_bits) - 1)) as u32;
+                let bias = (1 << ($exponent_bits - 1)) - 1;
+
+                let decimal = shortest(ieee_mantissa, ieee_exponent, $mantissa_bits, bias);
+                write_decimal(decimal, $max_point, buff, idx)
+            }
+
+            #[inline]
+            fn try_float_to_bytes(self, buff: &mut [u8]) -> Result<usize, FormatErr> {
+                let mut scratch = [0; $max_len];
+                let len = self.float_to_bytes(&mut scratch);
+
+                match buff.get_mut(..len) {
+                    Some(buff) => {
+                        buff.copy_from_slice(&scratch[..len]);
+                        Ok(len)
+                    }
+                    None => Err(FormatErr::BufferTooSmall(len)),
+                }
+            }
+        }
+    };
+}
+
+into_ascii_float!(f32, 16, 23, 8, 13);
+into_ascii_float!(f64, 24, 52, 11, 16);
+
+#[cfg(test)]
+mod tests {
+    use super::{shortest, IntoAsciiFloat, POW5_INV_SPLIT, POW5_SPLIT};
+
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    /// Checks the digits against the shortest digits of `{:e}`, and that the output parses back.
+    fn check_f64(f: f64) {
+        let formatted = f.ftoa();
+        let s = std::str::from_utf8(&formatted).unwrap();
+        assert!(formatted.len() <= f64::MAX_LEN);
+        assert_eq!(s.parse::<f64>().unwrap().to_bits(), f.to_bits(), "{}", s);
+
+        if f != 0.0 {
+            let bits = f.abs().to_bits();
+            let decimal = shortest(bits & ((1 << 52) - 1), (bits >> 52) as u32, 52, 1023);
+            let length = decimal.mantissa.to_string().len() as i32;
+
+            // On an exact tie between two shortest decimals, core rounds up instead of to even.
+            let expected = format!("{:e}", f.abs());
+            let (digits, exponent) = expected.split_once('e').unwrap();
+            let digits: u64 = digits.replace('.', "").parse().unwrap();
+
+            assert_eq!(decimal.exponent + length - 1, exponent.parse::<i32>().unwrap(), "{}", expected);
+            assert!(digits - decimal.mantissa <= 1, "{} {}", decimal.mantissa, expected);
+        }
+    }
+
+    fn check_f32(f: f32) {
+        let formatted = f.ftoa();
+        let s = std::str::from_utf8(&formatted).unwrap();
+        assert!(formatted.len() <= f32::MAX_LEN);
+        assert_eq!(s.parse::<f32>().unwrap().to_bits(), f.to_bits(), "{}", s);
+
+        if f != 0.0 {
+            let bits = u64::from(f.abs().to_bits());
+            let decimal = shortest(bits & ((1 << 23) - 1), (bits >> 23) as u32, 23, 127);
+            let length = decimal.mantissa.to_string().len() as i32;
+
+            // On an exact tie between two shortest decimals, core rounds up instead of to even.
+            let expected = format!("{:e}", f.abs());
+            let (digits, exponent) = expected.split_once('e').unwrap();
+            let digits: u64 = digits.replace('.', "").parse().unwrap();
+
+            assert_eq!(decimal.exponent + length - 1, exponent.parse::<i32>().unwrap(), "{}", expected);
+            assert!(digits - decimal.mantissa <= 1, "{} {}", decimal.mantissa, expected);
+        }
+    }
+
+    #[test]
+    fn tables() {
+        assert_eq!(POW5_SPLIT[0], 1 << 124);
+        assert_eq!(POW5_SPLIT[1], 5 << 122);
+        assert_eq!(POW5_INV_SPLIT[0], (1 << 125) + 1);
+        assert_eq!(POW5_INV_SPLIT[1], 1844674407370955161 << 64 | 11068046444225730970);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(0.0f64.ftoa(), b"0.0");
+        assert_eq!((-0.0f64).ftoa(), b"-0.0");
+        assert_eq!(1.0f64.ftoa(), b"1.0");
+        assert_eq!(123456.0f64.ftoa(), b"123456.0");
+        assert_eq!(12.34f64.ftoa(), b"12.34");
+        assert_eq!(0.0001f64.ftoa(), b"0.0001");
+        assert_eq!(0.00001f64.ftoa(), b"0.00001");
+        assert_eq!(0.000001f64.ftoa(), b"1e-6");
+        assert_eq!(1e15f64.ftoa(), b"1000000000000000.0");
+        assert_eq!(1e16f64.ftoa(), b"1e16");
+        assert_eq!(1.5e300f64.ftoa(), b"1.5e300");
+        assert_eq!(f64::MAX.ftoa(), b"1.7976931348623157e308");
+        assert_eq!(f64::MIN_POSITIVE.ftoa(), b"2.2250738585072014e-308");
+        assert_eq!(5e-324f64.ftoa(), b"5e-324");
+        assert_eq!(f64::INFINITY.ftoa(), b"inf");
+        assert_eq!(f64::NEG_INFINITY.ftoa(), b"-inf");
+        assert_eq!(f64::NAN.ftoa(), b"NaN");
+
+        assert_eq!(0.1f32.ftoa(), b"0.1");
+        assert_eq!(f32::MAX.ftoa(), b"3.4028235e38");
+        assert_eq!(1e-45f32.ftoa(), b"1e-45");
+        assert_eq!(16777216.0f32.ftoa(), b"16777216.0");
+        assert_eq!((-1e13f32).ftoa(), b"-1e13");
+    }
+
+    #[test]
+    fn max_len() {
+        let mut buff = [0; 24];
+
+        assert_eq!((-2.2250738585072014e-308f64).float_to_bytes(&mut buff), 24);
+        assert_eq!((-0.000012345678901234567f64).float_to_bytes(&mut buff), 24);
+        assert_eq!((-1234567900000.0f32).float_to_bytes(&mut buff), 16);
+    }
+
+    #[test]
+    fn ties_to_even() {
+        assert_eq!((15_156_857.0f32 / 4.0).ftoa(), b"3789214.2");
+        assert_eq!((1_612_498_777_800_701.0f64 / 8.0).ftoa(), b"201562347225087.62");
+    }
+
+    #[test]
+    fn random_f64() {
+        let mut state = 0x1234_5678_9abc_def0;
+
+        for _ in 0..50_000 {
+            let f = f64::from_bits(xorshift(&mut state));
+
+            if f.is_finite() {
+                check_f64(f);
+            }
+        }
+
+        for e in -324..=307 {
+            check_f64(format!("1e{}", e).parse().unwrap());
+            check_f64(format!("9.999999999999999e{}", e).parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn random_f32() {
+        let mut state = 0x0fed_cba9_8765_4321;
+
+        for _ in 0..50_000 {
+            let f = f32::from_bits(xorshift(&mut state) as u32);
+
+            if f.is_finite() {
+                check_f32(f);
+            }
+        }
+
+        for bits in (0..0x7F80_0000u32).step_by(0x1001) {
+            check_f32(f32::from_bits(bits));
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{
+    constants::{DIGITS_LOWER, DIGITS_UPPER},
+    from_ascii_radix::assert_radix,
+};
+
+/// The letter case of the digits above 9.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+impl Case {
+    #[inline(always)]
+    fn digits(self) -> &'static [u8; 36] {
+        match self {
+            Case::Lower => &DIGITS_LOWER,
+            Case::Upper => &DIGITS_UPPER,
+        }
+    }
+}
+
+/// This trait converts integers to bytes in any base from 2 up to and including 36,
+/// and is implemented on all integer types, except u128 and i128.
+///
+/// Digits above 9 are written as lowercase letters, `a-z`.
+/// Negative numbers also include a `-` when converted.
+pub trait IntoAsciiRadix {
+    /// The function performing the convertion from a number to a Vec<u8>, containing the digits of the number in base `radix`.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_radix::IntoAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(255u32.itoa_radix(16), b"ff");
+    ///     assert_eq!((-1295i32).itoa_radix(36), b"-zz");
+    ///     assert_eq!(5u8.itoa_radix(2), b"101");
+    /// }
+    /// ```
+    #[inline]
+    fn itoa_radix(&self, radix: u32) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        let size = self.formatted_len_radix(radix);
+        let mut buff = vec![0; size];
+
+        self.int_to_bytes_radix(&mut buff, radix);
+        buff
+    }
+
+    /// Returns how many digits the integer has in base `radix`, not counting the sign.
+    fn digits_radix(self, radix: u32) -> usize;
+
+    /// Returns how many bytes the integer takes up in base `radix`, including the sign.
+    fn formatted_len_radix(self, radix: u32) -> usize;
+
+    /// Writes `self` in base `radix` into `buff`.
+    /// Like [`crate::into_ascii::IntoAscii::int_to_bytes`],
+    /// this function assumes `buff` is exactly large enough to hold all bytes of `self`,
+    /// see [`IntoAsciiRadix::formatted_len_radix`].
+    fn int_to_bytes_radix(self, buff: &mut [u8], radix: u32);
+
+    /// Converts a number to a Vec<u8>, containing its lowercase hexadecimal digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_radix::IntoAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(0xdead_beefu32.itoa_hex(), b"deadbeef");
+    ///     assert_eq!(0xdead_beefu32.itoa_hex_upper(), b"DEADBEEF");
+    /// }
+    /// ```
+    #[inline]
+    fn itoa_hex(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        self.itoa_hex_with(Case::Lower, false)
+    }
+
+    /// Like [`IntoAsciiRadix::itoa_hex`], but with uppercase digits.
+    #[inline]
+    fn itoa_hex_upper(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        self.itoa_hex_with(Case::Upper, false)
+    }
+
+    /// Converts a number to a Vec<u8>, containing its hexadecimal digits in the given case,
+    /// preceded by `0x` if `prefix` is true. A `-` goes in front of the prefix.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_radix::{IntoAsciiRadix, Case};
+    ///
+    /// fn main() {
+    ///     assert_eq!(0x7ffeu16.itoa_hex_with(Case::Upper, true), b"0x7FFE");
+    ///     assert_eq!((-255i32).itoa_hex_with(Case::Lower, true), b"-0xff");
+    /// }
+    /// ```
+    #[inline]
+    fn itoa_hex_with(&self, case: Case, prefix: bool) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        let size = self.hex_len(prefix);
+        let mut buff = vec![0; size];
+
+        self.int_to_bytes_hex(&mut buff, case, prefix);
+        buff
+    }
+
+    /// Returns how many bytes the integer takes up in hexadecimal, including the sign and the `0x` prefix if requested.
+    #[inline]
+    fn hex_len(self, prefix: bool) -> usize
+    where
+        Self: Sized,
+    {
+        self.formatted_len_radix(16) + 2 * prefix as usize
+    }
+
+    /// Writes `self` in hexadecimal into `buff`, with digits in the given case,
+    /// preceded by `0x` if `prefix` is true.
+    /// This function assumes `buff` is exactly large enough, see [`IntoAsciiRadix::hex_len`].
+    fn int_to_bytes_hex(self, buff: &mut [u8], case: Case, prefix: bool);
+
+    /// Converts a number to a Vec<u8>, containing its binary digits.
+    /// This is the same as calling [`IntoAsciiRadix::itoa_radix`] with a radix of 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_radix::IntoAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(5u8.itoa_bin(), b"101");
+    ///     assert_eq!((-5i8).itoa_bin(), b"-101");
+    /// }
+    /// ```
+    #[inline]
+    fn itoa_bin(&self) -> Vec<u8>
+    where
+        Self: Copy,
+    {
+        self.itoa_radix(2)
+    }
+
+    /// Converts a number to a Vec<u8>, containing all of its bits, with one byte per bit of the type.
+    /// For negative numbers, these are the bits of their two's complement representation, without a sign.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::into_ascii_radix::IntoAsciiRadix;
+    ///
+    /// fn main() {
+    ///     assert_eq!(5u8.itoa_bin_padded(), b"00000101");
+    ///     assert_eq!((-1i16).itoa_bin_padded(), b"1111111111111111");
+    /// }
+    /// ```
+    fn itoa_bin_padded(&self) -> Vec<u8>;
+
+    /// Writes all bits of `self` into `buff`, like [`IntoAsciiRadix::itoa_bin_padded`].
+    /// `buff` should be exactly as long as the number of bits of the type, any bytes before that are filled with `0`.
+    fn int_to_bytes_bin_padded(self, buff: &mut [u8]);
+}
+
+macro_rules! unsigned_into_ascii_radix {
+    ($int:ty) => {
+        impl IntoAsciiRadix for $int {
+            #[inline]
+            fn digits_radix(mut self, radix: u32) -> usize {
+                assert_radix(radix);
+
+                let mut result = 1;
+
+                while self >= radix as Self {
+                    self /= radix as Self;
+                    result += 1;
+                }
+
+                result
+            }
+
+            #[inline]
+            fn formatted_len_radix(self, radix: u32) -> usize {
+                self.digits_radix(radix)
+            }
+
+            // Powers of two are written with shifts and masks, anything else needs a division per digit.
+            #[inline]
+            fn int_to_bytes_radix(mut self, buff: &mut [u8], radix: u32) {
+                assert_radix(radix);
+
+                if radix.is_power_of_two() {
+                    unsigned_into_ascii_radix!(@pow2 self, buff, radix.trailing_zeros(), &DIGITS_LOWER);
+                } else {
+                    for byte in buff.iter_mut().rev() {
+                        let q = self / radix as Self;
+                        *byte = DIGITS_LOWER[(self - q * radix as Self) as usize];
+                        self = q;
+
+                        if self == 0 {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn int_to_bytes_hex(mut self, buff: &mut [u8], case: Case, prefix: bool) {
+                let (head, digits) = buff.split_at_mut(2 * prefix as usize);
+                head.copy_from_slice(&b"0x"[..head.len()]);
+
+                unsigned_into_ascii_radix!(@pow2 self, digits, 4, case.digits());
+            }
+
+            #[inline]
+            fn itoa_bin_padded(&self) -> Vec<u8> {
+                let mut buff = vec![0; Self::BITS as usize];
+
+                self.int_to_bytes_bin_padded(&mut buff);
+                buff
+            }
+
+            #[inline]
+            fn int_to_bytes_bin_padded(mut self, buff: &mut [u8]) {
+                for byte in buff.iter_mut().rev() {
+                    *byte = b'0' + (self & 1) as u8;
+                    self >>= 1;
+                }
+            }
+        }
+    };
+
+    (@pow2 $n:ident, $buff:ident, $shift:expr, $digits:expr) => {
+        let shift = $shift;
+        let mask = (1 << shift) - 1;
+        let digits = $digits;
+
+        for byte in $buff.iter_mut().rev() {
+            *byte = digits[($n & mask) as usize];
+            $n >>= shift;
+
+            if $n == 0 {
+                break;
+            }
+        }
+    };
+}
+
+macro_rules! signed_into_ascii_radix {
+    ($int:ty, $unsigned_version:ty) => {
+        impl IntoAsciiRadix for $int {
+            #[inline]
+            fn digits_radix(self, radix: u32) -> usize {
+                self.unsigned_abs().digits_radix(radix)
+            }
+
+            #[inline]
+            fn formatted_len_radix(self, radix: u32) -> usize {
+                self.digits_radix(radix) + self.is_negative() as usize
+            }
+
+            #[inline]
+            fn int_to_bytes_radix(self, buff: &mut [u8], radix: u32) {
+                self.unsigned_abs().int_to_bytes_radix(buff, radix);
+
+                if self.is_negative() {
+                    buff[0] = b'-';
+                }
+            }
+
+            #[inline]
+            fn int_to_bytes_hex(self, buff: &mut [u8], case: Case, prefix: bool) {
+                let sign = self.is_negative() as usize;
+                self.unsigned_abs().int_to_bytes_hex(&mut buff[sign..], case, prefix);
+
+                if self.is_negative() {
+                    buff[0] = b'-';
+                }
+            }
+
+            #[inline]
+            fn itoa_bin_padded(&self) -> Vec<u8> {
+                (*self as $unsigned_version).itoa_bin_padded()
+            }
+
+            #[inline]
+            fn int_to_bytes_bin_padded(self, buff: &mut [u8]) {
+                (self as $unsigned_version).int_to_bytes_bin_padded(buff);
+            }
+        }
+    };
+}
+
+unsigned_into_ascii_radix!(u8);
+unsigned_into_ascii_radix!(u16);
+unsigned_into_ascii_radix!(u32);
+unsigned_into_ascii_radix!(u64);
+unsigned_into_ascii_radix!(usize);
+
+signed_into_ascii_radix!(i8, u8);
+signed_into_ascii_radix!(i16, u16);
+signed_into_ascii_radix!(i32, u32);
+signed_into_ascii_radix!(i64, u64);
+signed_into_ascii_radix!(isize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::{Case, IntoAsciiRadix};
+
+    #[test]
+    fn matches_std() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            assert_eq!(n.itoa_radix(16), format!("{:x}", n).as_bytes());
+            assert_eq!(n.itoa_radix(8), format!("{:o}", n).as_bytes());
+            assert_eq!(n.itoa_radix(2), format!("{:b}", n).as_bytes());
+            assert_eq!(n.itoa_radix(10), format!("{}", n).as_bytes());
+        }
+    }
+
+    #[test]
+    fn every_radix() {
+        for radix in 2..=36 {
+            for n in (i16::MIN..=i16::MAX).step_by(13) {
+                let mut expected = Vec::new();
+                let mut m = n.unsigned_abs();
+
+                loop {
+                    expected.push(std::char::from_digit(u32::from(m) % radix, radix).unwrap() as u8);
+                    m /= radix as u16;
+
+                    if m == 0 {
+                        break;
+                    }
+                }
+
+                if n < 0 {
+                    expected.push(b'-');
+                }
+
+                expected.reverse();
+
+                assert_eq!(n.itoa_radix(radix), expected);
+                assert_eq!(n.formatted_len_radix(radix), expected.len());
+            }
+        }
+    }
+
+    #[test]
+    fn signed_min() {
+        assert_eq!(i64::MIN.itoa_radix(16), b"-8000000000000000");
+        assert_eq!(i8::MIN.itoa_radix(2), b"-10000000");
+        assert_eq!(0i8.itoa_radix(36), b"0");
+    }
+
+    #[test]
+    fn hex() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            assert_eq!(n.itoa_hex(), format!("{:x}", n).as_bytes());
+            assert_eq!(n.itoa_hex_upper(), format!("{:X}", n).as_bytes());
+            assert_eq!(n.itoa_hex_with(Case::Lower, true), format!("{:#x}", n).as_bytes());
+            assert_eq!(n.itoa_hex_with(Case::Upper, true), format!("0x{:X}", n).as_bytes());
+        }
+
+        assert_eq!(0u8.itoa_hex_with(Case::Lower, true), b"0x0");
+        assert_eq!(i16::MIN.itoa_hex_with(Case::Upper, true), b"-0x8000");
+        assert_eq!((-10i8).itoa_hex_upper(), b"-A");
+        assert_eq!((-10i8).hex_len(true), 4);
+
+        let mut buff = [0; 10];
+        0xabc_u32.int_to_bytes_hex(&mut buff[..5], Case::Upper, true);
+        assert_eq!(&buff[..5], b"0xABC");
+    }
+
+    #[test]
+    fn bin_padded() {
+        for n in (0..u64::MAX).step_by(0x0123_4567_89ab_cdef).chain(Some(u64::MAX)) {
+            assert_eq!(n.itoa_bin(), format!("{:b}", n).as_bytes());
+            assert_eq!(n.itoa_bin_padded(), format!("{:064b}", n).as_bytes());
+        }
+
+        for n in i8::MIN..=i8::MAX {
+            assert_eq!(n.itoa_bin_padded(), format!("{:08b}", n).as_bytes());
+        }
+
+        let mut buff = [b' '; 10];
+        0b1011u8.int_to_bytes_bin_padded(&mut buff);
+        assert_eq!(&buff, b"0000001011");
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_radix() {
+        let _ = 1u32.itoa_radix(1);
+    }
+}
@@ -0,0 +1,532 @@
+//! Reading numbers from, and writing numbers to, the readers and writers of [`std::io`].
+use std::{
+    convert::TryFrom,
+    io::{self, BufRead, Read, Write},
+};
+
+use crate::{
+    constants::MAX_INT_LEN, from_ascii::FromAscii, into_ascii::IntoAscii, stream::StreamParser,
+    tokenize::find_whitespace,
+};
+
+/// Converts a parse error into an [`io::Error`] of kind [`io::ErrorKind::InvalidData`].
+#[inline(always)]
+pub(crate) fn invalid_data<E>(err: E) -> io::Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reads whitespace separated numbers from a buffered reader, like `stdin().lock()` in competitive programming.
+///
+/// Tokens are parsed straight from the buffer of the reader.
+/// Only a token that is split over two fills of that buffer is copied, into a buffer that is reused for every token.
+///
+/// # Examples
+/// ```
+/// use byte_num::io::Scanner;
+///
+/// fn main() {
+///     let input = "3\n10 -20 30\n";
+///     let mut scanner = Scanner::new(input.as_bytes());
+///
+///     let n = scanner.next::<usize>().unwrap();
+///     let sum = (0..n).map(|_| scanner.next::<i64>().unwrap()).sum::<i64>();
+///
+///     assert_eq!(sum, 20);
+///     assert!(scanner.next::<i64>().is_err());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Scanner<R> {
+    reader: R,
+    token: Vec<u8>,
+}
+
+impl<R: BufRead> Scanner<R> {
+    /// Creates a scanner reading from `reader`.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            token: Vec::new(),
+        }
+    }
+
+    /// Returns the reader, with the input after the last token still in it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Skips whitespace, and returns whether there is a token after it.
+    fn skip_whitespace(&mut self) -> io::Result<bool> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+
+            if buf.is_empty() {
+                return Ok(false);
+            }
+
+            match buf.iter().position(|b| !b.is_ascii_whitespace()) {
+                Some(start) => {
+                    self.reader.consume(start);
+                    return Ok(true);
+                }
+                None => {
+                    let len = buf.len();
+                    self.reader.consume(len);
+                }
+            }
+        }
+    }
+
+    /// Returns the next whitespace separated token, or `None` at the end of the input.
+    ///
+    /// The token is always copied, so prefer [`Scanner::next`] for numbers.
+    pub fn next_token(&mut self) -> io::Result<Option<&[u8]>> {
+        self.token.clear();
+
+        if !self.skip_whitespace()? {
+            return Ok(None);
+        }
+
+        loop {
+            let buf = self.reader.fill_buf()?;
+            let end = find_whitespace(buf);
+
+            // Either the token ends in this buffer, or the input ended.
+            let done = end < buf.len() || end == 0;
+
+            self.token.extend_from_slice(&buf[..end]);
+            self.reader.consume(end);
+
+            if done {
+                return Ok(Some(&self.token));
+            }
+        }
+    }
+
+    /// Parses the next whitespace separated token with [`FromAscii::atoi`].
+    ///
+    /// At the end of the input, the error is of kind [`io::ErrorKind::UnexpectedEof`].
+    /// A token that isn't a number is an error of kind [`io::ErrorKind::InvalidData`], holding the [`crate::error::ParseIntErr`].
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: FromAscii>(&mut self) -> io::Result<T> {
+        if !self.skip_whitespace()? {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        // Most tokens end within the buffer, and are parsed without copying them.
+        let buf = self.reader.fill_buf()?;
+        let end = find_whitespace(buf);
+
+        if end < buf.len() {
+            let n = T::bytes_to_int(&buf[..end]);
+
+            self.reader.consume(end);
+            return n.map_err(invalid_data);
+        }
+
+        match self.next_token()? {
+            Some(token) => T::bytes_to_int(token).map_err(invalid_data),
+            None => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+/// The size of the buffer of a [`Printer`].
+const PRINTER_CAPACITY: usize = 1 << 13;
+
+/// Writes numbers to a writer, formatting them straight into a buffer that is written out in large chunks.
+///
+/// The buffer is written out when it fills up, on [`Printer::flush`], and when the printer is dropped.
+/// Errors while dropping are ignored, so call [`Printer::flush`] to see them.
+///
+/// # Examples
+/// ```
+/// use byte_num::io::Printer;
+///
+/// fn main() {
+///     let mut out = Vec::new();
+///
+///     {
+///         let mut printer = Printer::new(&mut out);
+///
+///         for n in 1..=3 {
+///             printer.write_int(n).unwrap();
+///             printer.write_sep(b' ').unwrap();
+///         }
+///         printer.writeln_int(-4).unwrap();
+///     }
+///
+///     assert_eq!(out, b"1 2 3 -4\n");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Printer<W: Write> {
+    writer: W,
+    buf: Box<[u8]>,
+    len: usize,
+}
+
+impl<W: Write> Printer<W> {
+    /// Creates a printer writing to `writer`.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buf: vec![0; PRINTER_CAPACITY].into_boxed_slice(),
+            len: 0,
+        }
+    }
+
+    /// Returns a reference to the writer. Anything still in the buffer has not been written to it yet.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Makes sure there is room for `n` more bytes in the buffer.
+    #[inline(always)]
+    fn reserve(&mut self, n: usize) -> io::Result<()> {
+        if self.len + n > self.buf.len() {
+            self.flush_buf()?;
+        }
+
+        Ok(())
+    }
+
+    fn flush_buf(&mut self) -> io::Result<()> {
+        let len = self.len;
+
+        // Whatever happens, the buffer is not written twice.
+        self.len = 0;
+        self.writer.write_all(&self.buf[..len])
+    }
+
+    /// Writes `n` in decimal.
+    #[inline]
+    pub fn write_int<N: IntoAscii + Copy>(&mut self, n: N) -> io::Result<()> {
+        self.reserve(MAX_INT_LEN)?;
+
+        // The buffer always has room for any integer, after reserving.
+        match n.try_int_to_bytes(&mut self.buf[self.len..]) {
+            Ok(written) => self.len += written,
+            Err(_) => unreachable!("integer longer than {} bytes", MAX_INT_LEN),
+        }
+
+        Ok(())
+    }
+
+    /// Writes `n` in decimal, followed by a `\n`.
+    #[inline]
+    pub fn writeln_int<N: IntoAscii + Copy>(&mut self, n: N) -> io::Result<()> {
+        self.write_int(n)?;
+        self.write_sep(b'\n')
+    }
+
+    /// Writes a single separator byte, like a space or a comma.
+    #[inline]
+    pub fn write_sep(&mut self, sep: u8) -> io::Result<()> {
+        self.reserve(1)?;
+
+        self.buf[self.len] = sep;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Writes `bytes` as they are. Anything larger than the buffer goes straight to the writer.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.reserve(bytes.len())?;
+
+        if bytes.len() > self.buf.len() {
+            return self.writer.write_all(bytes);
+        }
+
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    /// Writes out the buffer, and flushes the writer.
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for Printer<W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+/// The bytes that are ASCII whitespace.
+pub(crate) const WHITESPACE: &[u8] = b" \t\n\x0C\r";
+
+/// Returns a lookup table that is `true` for every byte in `separators`.
+#[inline]
+pub(crate) fn separator_set(separators: &[u8]) -> [bool; 256] {
+    let mut set = [false; 256];
+
+    for &sep in separators {
+        set[sep as usize] = true;
+    }
+
+    set
+}
+
+/// The size of the buffer of a [`NumberReader`].
+const READER_CAPACITY: usize = 1 << 13;
+
+/// Reads numbers separated by any of a set of separator bytes from any reader, like the output of `seq`.
+///
+/// Bytes are read in large chunks into an internal buffer, and numbers that are split over two reads
+/// are put together with a [`StreamParser`], so no number is ever copied.
+/// Runs of separators, and separators at the start and end, are skipped.
+///
+/// A number that fails to parse is an error of kind [`io::ErrorKind::InvalidData`], holding the [`crate::error::ParseIntErr`].
+/// Reading carries on after it, with the next number.
+///
+/// # Examples
+/// ```
+/// use byte_num::io::NumberReader;
+///
+/// fn main() {
+///     let input = "1\n2\n3\n";
+///     let numbers = NumberReader::<_, u32>::new(input.as_bytes()).collect::<std::io::Result<Vec<_>>>();
+///     assert_eq!(numbers.unwrap(), [1, 2, 3]);
+///
+///     let input = "-4;5,,6";
+///     let numbers = NumberReader::<_, i8>::with_separators(input.as_bytes(), b",;").collect::<std::io::Result<Vec<_>>>();
+///     assert_eq!(numbers.unwrap(), [-4, 5, 6]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NumberReader<R, T> {
+    reader: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    filled: usize,
+    separators: [bool; 256],
+    parser: StreamParser<T>,
+}
+
+impl<R: Read, T: TryFrom<i128>> NumberReader<R, T> {
+    /// Creates a reader for numbers separated by ASCII whitespace.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self::with_separators(reader, WHITESPACE)
+    }
+
+    /// Creates a reader for numbers separated by any of the bytes in `separators`.
+    #[inline]
+    pub fn with_separators(reader: R, separators: &[u8]) -> Self {
+        Self {
+            reader,
+            buf: vec![0; READER_CAPACITY].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+            separators: separator_set(separators),
+            parser: StreamParser::new(),
+        }
+    }
+
+    /// Refills the buffer once it has been used up, and returns whether the reader has reached its end.
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.pos < self.filled {
+            return Ok(false);
+        }
+
+        loop {
+            match self.reader.read(&mut self.buf) {
+                Ok(n) => {
+                    self.pos = 0;
+                    self.filled = n;
+                    return Ok(n == 0);
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+impl<R: Read, T: TryFrom<i128>> Iterator for NumberReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.fill() {
+                Ok(true) => return None,
+                Ok(false) => {}
+                Err(err) => return Some(Err(err)),
+            }
+
+            let separators = &self.separators;
+            let chunk = &self.buf[self.pos..self.filled];
+
+            match chunk.iter().position(|b| !separators[*b as usize]) {
+                Some(start) => {
+                    self.pos += start;
+                    break;
+                }
+                None => self.pos = self.filled,
+            }
+        }
+
+        self.parser.reset();
+        let mut result = Ok(());
+
+        loop {
+            match self.fill() {
+                Ok(true) => break,
+                Ok(false) => {}
+                Err(err) => return Some(Err(err)),
+            }
+
+            let separators = &self.separators;
+            let chunk = &self.buf[self.pos..self.filled];
+            let end = chunk.iter().position(|b| separators[*b as usize]).unwrap_or(chunk.len());
+
+            // After an error, the rest of the number is skipped.
+            if result.is_ok() {
+                result = self.parser.feed(&chunk[..end]);
+            }
+
+            self.pos += end;
+
+            if self.pos < self.filled {
+                break;
+            }
+        }
+
+        Some(result.and_then(|()| self.parser.finish()).map_err(invalid_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberReader, Printer, Scanner, PRINTER_CAPACITY};
+    use crate::error::ParseIntErr;
+    use std::io::{self, BufReader, Read};
+
+    #[test]
+    fn scanner() {
+        let mut scanner = Scanner::new(&b"  1\t22\r\n-333 x 4"[..]);
+
+        assert_eq!(scanner.next::<u8>().unwrap(), 1);
+        assert_eq!(scanner.next::<u16>().unwrap(), 22);
+        assert_eq!(scanner.next::<i32>().unwrap(), -333);
+
+        let err = scanner.next::<u32>().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.get_ref().and_then(|err| err.downcast_ref::<ParseIntErr>()),
+            Some(&ParseIntErr::with_byte_at(b'x', 0))
+        );
+
+        assert_eq!(scanner.next_token().unwrap(), Some(&b"4"[..]));
+        assert_eq!(scanner.next_token().unwrap(), None);
+        assert_eq!(scanner.next::<u32>().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn split_tokens() {
+        // A tiny buffer splits most tokens over several fills.
+        let input = b"123456789 -42\n\n  987654321\t 7";
+        let mut scanner = Scanner::new(BufReader::with_capacity(4, &input[..]));
+
+        assert_eq!(scanner.next::<u64>().unwrap(), 123_456_789);
+        assert_eq!(scanner.next::<i8>().unwrap(), -42);
+        assert_eq!(scanner.next_token().unwrap(), Some(&b"987654321"[..]));
+        assert_eq!(scanner.next::<u8>().unwrap(), 7);
+        assert!(scanner.next_token().unwrap().is_none());
+    }
+
+    #[test]
+    fn printer() {
+        let mut out = std::vec::Vec::new();
+        let mut expected = std::vec::Vec::new();
+
+        {
+            let mut printer = Printer::new(&mut out);
+
+            // Enough to flush the buffer several times.
+            for n in -5_000i64..5_000 {
+                printer.write_int(n * 1_000_003).unwrap();
+                printer.write_sep(b',').unwrap();
+                expected.extend_from_slice(std::format!("{},", n * 1_000_003).as_bytes());
+            }
+
+            printer.writeln_int(u64::MAX).unwrap();
+            printer.writeln_int(i8::MIN).unwrap();
+            printer.write_bytes(&[b'x'; PRINTER_CAPACITY + 1]).unwrap();
+            printer.write_bytes(b"end").unwrap();
+            printer.flush().unwrap();
+        }
+
+        expected.extend_from_slice(b"18446744073709551615\n-128\n");
+        expected.extend_from_slice(&[b'x'; PRINTER_CAPACITY + 1]);
+        expected.extend_from_slice(b"end");
+
+        assert!(out == expected);
+    }
+
+    /// A reader that hands out at most 3 bytes at a time, and is interrupted in between.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+
+            let n = self.bytes.len().min(buf.len()).min(3);
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn number_reader() {
+        let input = Trickle {
+            bytes: b"  123456789,-42,,x1, 300 ,7,",
+            interrupt: false,
+        };
+        let numbers = NumberReader::<_, i16>::with_separators(input, b", ").collect::<std::vec::Vec<_>>();
+        let results = numbers
+            .iter()
+            .map(|result| match result {
+                Ok(n) => Ok(*n),
+                Err(err) => Err(*err.get_ref().unwrap().downcast_ref::<ParseIntErr>().unwrap()),
+            })
+            .collect::<std::vec::Vec<_>>();
+
+        assert_eq!(
+            results,
+            [
+                Err(ParseIntErr::PosOverflow),
+                Ok(-42),
+                Err(ParseIntErr::with_byte_at(b'x', 0)),
+                Ok(300),
+                Ok(7)
+            ]
+        );
+
+        let mut empty = NumberReader::<_, u8>::new(&b" \n\t "[..]);
+        assert!(empty.next().is_none());
+        assert!(empty.next().is_none());
+    }
+}
@@ -0,0 +1,400 @@
+//! Iterator adapters that parse the items of other iterators.
+use core::{convert::TryInto, iter::FusedIterator, marker::PhantomData, ops::Range};
+
+use crate::{
+    constants::{HIGH, ONES},
+    error::{ParseFieldErr, ParseIntErr},
+    from_ascii::FromAscii,
+    from_ascii_radix::FromAsciiRadix,
+};
+
+/// An iterator that converts every item of `I` with [`FromAscii::atoi`].
+///
+/// Created with [`ParsedIter::new`], or with [`ParseIterExt::parsed`].
+/// The length of the inner iterator is passed through, so collecting the numbers reserves the right amount of space.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     iter::ParsedIter,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let mut numbers = ParsedIter::<_, u32>::new(b"10 20 x".split(|b| *b == b' '));
+///
+///     assert_eq!(numbers.next(), Some(Ok(10)));
+///     assert_eq!(numbers.next(), Some(Ok(20)));
+///     assert_eq!(numbers.next(), Some(Err(ParseIntErr::with_byte_at(b'x', 0))));
+///     assert_eq!(numbers.next(), None);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParsedIter<I, T> {
+    iter: I,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<I, T> ParsedIter<I, T>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+    T: FromAscii,
+{
+    /// Wraps `iter`, so its items are parsed as they are yielded.
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, T> Iterator for ParsedIter<I, T>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+    T: FromAscii,
+{
+    type Item = Result<T, ParseIntErr>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(T::atoi)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for ParsedIter<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<[u8]>,
+    T: FromAscii,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(T::atoi)
+    }
+}
+
+impl<I, T> ExactSizeIterator for ParsedIter<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<[u8]>,
+    T: FromAscii,
+{
+}
+
+impl<I, T> FusedIterator for ParsedIter<I, T>
+where
+    I: FusedIterator,
+    I::Item: AsRef<[u8]>,
+    T: FromAscii,
+{
+}
+
+/// Adds [`ParseIterExt::parsed`] to every iterator over bytes, or anything else that implements `AsRef<[u8]>`.
+pub trait ParseIterExt: Iterator + Sized
+where
+    Self::Item: AsRef<[u8]>,
+{
+    /// Parses every item of this iterator, see [`ParsedIter`].
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::iter::ParseIterExt;
+    ///
+    /// fn main() {
+    ///     let sum = "1,2,3".split(',').parsed::<u64>().sum::<Result<u64, _>>();
+    ///     assert_eq!(sum, Ok(6));
+    /// }
+    /// ```
+    #[inline]
+    fn parsed<T: FromAscii>(self) -> ParsedIter<Self, T> {
+        ParsedIter::new(self)
+    }
+}
+
+impl<I> ParseIterExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+/// Returns the index of the first digit in `bytes`, or its length if there is none.
+// Xor-ing with '0' maps exactly the digits below 10, which are then found with a borrow,
+// 8 bytes at a time. A borrow can only spill into the bytes after a digit, so the first one found is exact.
+#[inline(always)]
+fn find_digit(bytes: &[u8]) -> usize {
+    let mut idx = 0;
+
+    while let Some(chunk) = bytes.get(idx..idx + 8) {
+        let v = u64::from_le_bytes(chunk.try_into().unwrap());
+        let x = v ^ (ONES * u64::from(b'0'));
+        let found = x.wrapping_sub(ONES * 10) & !x & HIGH;
+
+        if found != 0 {
+            return idx + found.trailing_zeros() as usize / 8;
+        }
+
+        idx += 8;
+    }
+
+    idx + bytes[idx..]
+        .iter()
+        .position(u8::is_ascii_digit)
+        .unwrap_or(bytes.len() - idx)
+}
+
+/// An iterator over every number embedded in a haystack of bytes, created with [`scan_numbers`].
+#[derive(Clone, Debug)]
+pub struct ScanNumbers<'a, T> {
+    haystack: &'a [u8],
+    idx: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Finds every maximal run of digits in `haystack`, like the regex `-?\d+`, and converts it.
+///
+/// A `-` right before the digits is part of the number, unless it directly follows another number,
+/// so a date like `2024-05-17` is three positive numbers.
+///
+/// Every item is the range of the number within `haystack`, with the converted number.
+/// Numbers are converted exactly, so a number that doesn't fit in `T` is reported as an overflow,
+/// and scanning carries on after it. Unsigned types report negative numbers as [`ParseIntErr::NegOverflow`].
+///
+/// The gaps between the numbers are skipped 8 bytes at a time.
+///
+/// # Examples
+/// ```
+/// use byte_num::iter::scan_numbers;
+///
+/// fn main() {
+///     let mut numbers = scan_numbers::<i32>(b"GET /items/42 took -3ms, status=200");
+///
+///     assert_eq!(numbers.next(), Some((11..13, Ok(42))));
+///     assert_eq!(numbers.next(), Some((19..21, Ok(-3))));
+///     assert_eq!(numbers.next(), Some((32..35, Ok(200))));
+///     assert_eq!(numbers.next(), None);
+/// }
+/// ```
+#[inline]
+pub fn scan_numbers<T: FromAsciiRadix>(haystack: &[u8]) -> ScanNumbers<'_, T> {
+    ScanNumbers {
+        haystack,
+        idx: 0,
+        marker: PhantomData,
+    }
+}
+
+impl<'a, T: FromAsciiRadix> Iterator for ScanNumbers<'a, T> {
+    type Item = (Range<usize>, Result<T, ParseIntErr>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let haystack = self.haystack;
+        let digits = self.idx + find_digit(&haystack[self.idx..]);
+
+        if digits == haystack.len() {
+            self.idx = digits;
+            return None;
+        }
+
+        let end = digits
+            + haystack[digits..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+
+        let start = match digits.checked_sub(1).map(|idx| haystack[idx]) {
+            Some(b'-') if digits < 2 || !haystack[digits - 2].is_ascii_digit() => digits - 1,
+            _ => digits,
+        };
+
+        self.idx = end;
+        Some((start..end, T::bytes_to_int_radix(&haystack[start..end], 10)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every number takes at least one digit, and is followed by a byte that isn't one.
+        let left = self.haystack.len() - self.idx;
+        (0, Some(left.div_ceil(2)))
+    }
+}
+
+impl<'a, T: FromAsciiRadix> FusedIterator for ScanNumbers<'a, T> {}
+
+/// An iterator over the numbers of a buffer with one number per line, created with [`parse_lines`].
+#[derive(Clone, Debug)]
+pub struct ParseLines<'a, T> {
+    rest: &'a [u8],
+    line: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Converts every `\n` separated line of `bytes` with [`FromAscii::atoi`].
+///
+/// Lines may end in `\r\n` as well, and a newline at the end of the buffer doesn't start another line.
+/// Errors carry the index of the line, counting from 0, in [`ParseFieldErr::field`].
+/// An empty line is [`ParseIntErr::Empty`].
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     iter::parse_lines,
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let mut lines = parse_lines::<u32>(b"10\r\n20\n3x\n");
+///
+///     assert_eq!(lines.next(), Some(Ok(10)));
+///     assert_eq!(lines.next(), Some(Ok(20)));
+///     assert_eq!(lines.next(), Some(Err(ParseFieldErr { field: 2, error: ParseIntErr::with_byte_at(b'x', 1) })));
+///     assert_eq!(lines.next(), None);
+/// }
+/// ```
+#[inline]
+pub fn parse_lines<T: FromAscii>(bytes: &[u8]) -> ParseLines<'_, T> {
+    ParseLines {
+        rest: bytes,
+        line: 0,
+        marker: PhantomData,
+    }
+}
+
+impl<'a, T: FromAscii> Iterator for ParseLines<'a, T> {
+    type Item = Result<T, ParseFieldErr>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = match self.rest.iter().position(|b| *b == b'\n') {
+            Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+            None => (self.rest, &[][..]),
+        };
+
+        let line = match line {
+            [line @ .., b'\r'] => line,
+            line => line,
+        };
+
+        let field = self.line;
+
+        self.rest = rest;
+        self.line += 1;
+
+        Some(T::bytes_to_int(line).map_err(|error| ParseFieldErr { field, error }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        ((!self.rest.is_empty()) as usize, Some(self.rest.len()))
+    }
+}
+
+impl<'a, T: FromAscii> FusedIterator for ParseLines<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_digit, parse_lines, scan_numbers, ParseFieldErr, ParseIntErr, ParseIterExt, ParsedIter};
+
+    #[test]
+    fn parsed() {
+        let fields: [&[u8]; 4] = [b"1", b"-2", b"", b"40000"];
+        let mut numbers = fields.iter().parsed::<i16>();
+
+        assert_eq!(numbers.len(), 4);
+        assert_eq!(numbers.next_back(), Some(Err(ParseIntErr::PosOverflow)));
+        assert_eq!(numbers.size_hint(), (3, Some(3)));
+        assert_eq!(numbers.next(), Some(Ok(1)));
+        assert_eq!(numbers.next(), Some(Ok(-2)));
+        assert_eq!(numbers.next(), Some(Err(ParseIntErr::Empty)));
+        assert_eq!(numbers.next(), None);
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut numbers = ParsedIter::<_, u8>::new("1 2 3".split(' '));
+
+        assert_eq!(numbers.next(), Some(Ok(1)));
+        assert_eq!(numbers.into_inner().next(), Some("2"));
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(find_digit(b""), 0);
+        assert_eq!(find_digit(b"abc"), 3);
+
+        for idx in 0..20 {
+            let mut haystack = [b'/'; 20];
+            haystack[idx] = b'0' + (idx % 10) as u8;
+
+            // A digit right after the first one, and bytes just outside the digits after xor-ing with '0' before it.
+            if idx + 1 < haystack.len() {
+                haystack[idx + 1] = b'9';
+            }
+            haystack[..idx].iter_mut().step_by(2).for_each(|b| *b = b':');
+            haystack[..idx].iter_mut().skip(1).step_by(3).for_each(|b| *b = 0xB0);
+
+            assert_eq!(find_digit(&haystack), idx);
+        }
+    }
+
+    #[test]
+    fn scan() {
+        let collect = |haystack: &[u8]| scan_numbers::<i8>(haystack).collect::<std::vec::Vec<_>>();
+
+        assert_eq!(collect(b""), []);
+        assert_eq!(collect(b"no numbers here"), []);
+        assert_eq!(collect(b"7"), [(0..1, Ok(7))]);
+        assert_eq!(collect(b"-7"), [(0..2, Ok(-7))]);
+        assert_eq!(collect(b"--7-"), [(1..3, Ok(-7))]);
+        assert_eq!(collect(b"1-2"), [(0..1, Ok(1)), (2..3, Ok(2))]);
+        assert_eq!(
+            collect(b"a 300, b 0012 and -128"),
+            [(2..5, Err(ParseIntErr::PosOverflow)), (9..13, Ok(12)), (18..22, Ok(-128))]
+        );
+        assert_eq!(
+            collect(b"over a chunk boundary: 123"),
+            [(23..26, Ok(123))]
+        );
+
+        let dates = scan_numbers::<u16>(b"2024-05-17, -1").collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            dates,
+            [(0..4, Ok(2024)), (5..7, Ok(5)), (8..10, Ok(17)), (12..14, Err(ParseIntErr::NegOverflow))]
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let collect = |bytes: &[u8]| parse_lines::<i64>(bytes).collect::<std::vec::Vec<_>>();
+        let err = |field, error| Err(ParseFieldErr { field, error });
+
+        assert_eq!(collect(b""), []);
+        assert_eq!(collect(b"1"), [Ok(1)]);
+        assert_eq!(collect(b"1\n"), [Ok(1)]);
+        assert_eq!(collect(b"-1\r\n2\r\n"), [Ok(-1), Ok(2)]);
+        assert_eq!(collect(b"\n"), [err(0, ParseIntErr::Empty)]);
+        assert_eq!(collect(b"1\n\n2\n\n"), [Ok(1), err(1, ParseIntErr::Empty), Ok(2), err(3, ParseIntErr::Empty)]);
+        assert_eq!(collect(b"1\r\r\n"), [err(0, ParseIntErr::with_byte_at(b'\r', 1))]);
+        assert_eq!(collect(b" 1\n"), [err(0, ParseIntErr::with_byte_at(b' ', 0))]);
+    }
+}
@@ -0,0 +1,223 @@
+//! Parsing of numbers in the exact JSON grammar.
+//!
+//! A JSON number is `-? (0 | [1-9][0-9]*) (\.[0-9]+)? ([eE][+-]?[0-9]+)?`.
+//! The number is parsed from the start of the input, and the input may continue after it,
+//! so a JSON parser can hand over the rest of its buffer, and continue at [`JsonNumber::consumed`].
+use crate::{
+    error::{ParseFloatErr, ParseIntErr},
+    from_ascii::FromAscii,
+    from_ascii_float::FromAsciiFloat,
+};
+
+/// A JSON number, split into its parts. All parts borrow from the input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct JsonNumber<'a> {
+    /// Whether the number starts with a `-`.
+    pub negative: bool,
+
+    /// The digits before the decimal point. Never empty, and without leading zeros unless it is just `0`.
+    pub integer: &'a [u8],
+
+    /// The digits after the decimal point, or an empty slice if there is no fraction.
+    pub fraction: &'a [u8],
+
+    /// Whether the exponent starts with a `-`.
+    pub exponent_negative: bool,
+
+    /// The digits of the exponent, or an empty slice if there is no exponent.
+    pub exponent: &'a [u8],
+
+    /// The number of bytes the number takes up in the input.
+    pub consumed: usize,
+
+    raw: &'a [u8],
+}
+
+impl<'a> JsonNumber<'a> {
+    /// Returns the bytes of the whole number, as they appeared in the input.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Returns whether the number has neither a fraction nor an exponent.
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.fraction.is_empty() && self.exponent.is_empty()
+    }
+
+    /// Converts the number to the closest `f64`.
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        // Every JSON number is valid float syntax.
+        match f64::bytes_to_float(self.raw) {
+            Ok(f) => f,
+            Err(_) => unreachable!("JSON number is not a valid float"),
+        }
+    }
+
+    /// Converts the number to an `i64`, if it has neither a fraction nor an exponent.
+    /// Otherwise, the error points at the `.`, `e` or `E`.
+    #[inline]
+    pub fn to_i64(&self) -> Result<i64, ParseIntErr> {
+        i64::bytes_to_int(self.raw)
+    }
+}
+
+/// Parses a JSON number from the start of `bytes`.
+///
+/// The number ends at the first byte that can't continue it.
+/// Input that can't be valid JSON at that point, like a digit after a leading `0`,
+/// a `.` without digits after it, or an exponent without digits, is an error.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     json_num,
+///     error::ParseFloatErr,
+/// };
+///
+/// fn main() {
+///     let number = json_num::parse(b"-12.5e+3, 7]").unwrap();
+///
+///     assert!(number.negative);
+///     assert_eq!(number.integer, b"12");
+///     assert_eq!(number.fraction, b"5");
+///     assert_eq!(number.exponent, b"3");
+///     assert_eq!(number.consumed, 8);
+///     assert_eq!(number.to_f64(), -12_500.0);
+///
+///     assert_eq!(json_num::parse(b"012"), Err(ParseFloatErr::with_byte_at(b'1', 1)));
+/// }
+/// ```
+pub fn parse(bytes: &[u8]) -> Result<JsonNumber<'_>, ParseFloatErr> {
+    if bytes.is_empty() {
+        return Err(ParseFloatErr::Empty);
+    }
+
+    let digits_from = |start: usize| {
+        start
+            + bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+    };
+
+    let negative = bytes[0] == b'-';
+    let mut idx = negative as usize;
+
+    let integer = match bytes.get(idx) {
+        Some(b'0') => {
+            if let Some(b'0'..=b'9') = bytes.get(idx + 1) {
+                return Err(ParseFloatErr::at_or_last(bytes, idx + 1));
+            }
+
+            idx..idx + 1
+        }
+        Some(b'1'..=b'9') => idx..digits_from(idx),
+        _ => return Err(ParseFloatErr::at_or_last(bytes, idx)),
+    };
+    idx = integer.end;
+
+    let fraction = match bytes.get(idx) {
+        Some(b'.') => {
+            let start = idx + 1;
+            let end = digits_from(start);
+
+            if end == start {
+                return Err(ParseFloatErr::at_or_last(bytes, end));
+            }
+
+            idx = end;
+            start..end
+        }
+        _ => idx..idx,
+    };
+
+    let mut exponent_negative = false;
+
+    let exponent = match bytes.get(idx) {
+        Some(b'e') | Some(b'E') => {
+            let mut start = idx + 1;
+
+            match bytes.get(start) {
+                Some(b'-') => {
+                    exponent_negative = true;
+                    start += 1;
+                }
+                Some(b'+') => start += 1,
+                _ => {}
+            }
+
+            let end = digits_from(start);
+
+            if end == start {
+                return Err(ParseFloatErr::at_or_last(bytes, end));
+            }
+
+            idx = end;
+            start..end
+        }
+        _ => idx..idx,
+    };
+
+    Ok(JsonNumber {
+        negative,
+        integer: &bytes[integer],
+        fraction: &bytes[fraction],
+        exponent_negative,
+        exponent: &bytes[exponent],
+        consumed: idx,
+        raw: &bytes[..idx],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, ParseFloatErr, ParseIntErr};
+
+    #[test]
+    fn parts() {
+        let number = parse(b"0").unwrap();
+        assert_eq!((number.negative, number.integer, number.consumed), (false, &b"0"[..], 1));
+        assert!(number.is_integer());
+        assert_eq!(number.to_i64(), Ok(0));
+
+        let number = parse(b"-9223372036854775808}").unwrap();
+        assert_eq!(number.as_bytes(), b"-9223372036854775808");
+        assert_eq!(number.to_i64(), Ok(i64::MIN));
+
+        let number = parse(b"1.25E-2 ").unwrap();
+        assert_eq!(number.integer, b"1");
+        assert_eq!(number.fraction, b"25");
+        assert!(number.exponent_negative);
+        assert_eq!(number.exponent, b"2");
+        assert_eq!(number.consumed, 7);
+        assert_eq!(number.to_f64(), 0.0125);
+        assert_eq!(number.to_i64(), Err(ParseIntErr::with_byte_at(b'.', 1)));
+
+        let number = parse(b"-12345678901234567890123,").unwrap();
+        assert_eq!(number.to_f64(), -1.2345678901234568e22);
+
+        let number = parse(b"0e0").unwrap();
+        assert_eq!((number.fraction, number.exponent, number.consumed), (&b""[..], &b"0"[..], 3));
+
+        // A number ends at anything that can't continue it.
+        assert_eq!(parse(b"12abc").unwrap().consumed, 2);
+        assert_eq!(parse(b"-0,").unwrap().consumed, 2);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse(b""), Err(ParseFloatErr::Empty));
+        assert_eq!(parse(b"-"), Err(ParseFloatErr::with_byte_at(b'-', 0)));
+        assert_eq!(parse(b"+1"), Err(ParseFloatErr::with_byte_at(b'+', 0)));
+        assert_eq!(parse(b".5"), Err(ParseFloatErr::with_byte_at(b'.', 0)));
+        assert_eq!(parse(b"-01"), Err(ParseFloatErr::with_byte_at(b'1', 2)));
+        assert_eq!(parse(b"1."), Err(ParseFloatErr::with_byte_at(b'.', 1)));
+        assert_eq!(parse(b"1.e5"), Err(ParseFloatErr::with_byte_at(b'e', 2)));
+        assert_eq!(parse(b"1e"), Err(ParseFloatErr::with_byte_at(b'e', 1)));
+        assert_eq!(parse(b"1e+,"), Err(ParseFloatErr::with_byte_at(b',', 3)));
+        assert_eq!(parse(b"inf"), Err(ParseFloatErr::with_byte_at(b'i', 0)));
+    }
+}
@@ -1,12 +1,95 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(test))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
 
 //! This crate provides functions to convert from and into bytes, in base 10.
 //! The functions are based on the fastware talks of Andrei Alexandrescu ([Talk](https://www.youtube.com/watch?v=o4-CwDo2zpg)).
 //!
 //! To convert from bytes, to integers, use the [`from_ascii`] module.
+//! For bases other than 10, use the [`from_ascii_radix`] module,
+//! and for floats the [`from_ascii_float`] module.
+//! Decimal numbers can be parsed into scaled integers with the [`from_ascii_fixed`] module,
+//! and numbers in the JSON grammar with the [`json_num`] module.
+//! A fixed number of delimited numbers is parsed into arrays and tuples with the [`from_ascii_delim`] module,
+//! and the items of other iterators are parsed with the [`iter`] module.
+//! Whitespace separated numbers are split and parsed with the [`tokenize`] module,
+//! or read from any [`std::io::BufRead`] with the [`io`] module.
+//! Numbers that arrive in pieces are parsed incrementally with the [`stream`] module,
+//! and read from asynchronous readers with the `async_io` module, behind the `tokio` feature.
+//! Files are memory mapped and scanned for numbers with the `mmap` module, behind the `mmap` feature.
+//! Environment variables are read as integers with the [`env`] module.
+//! With the `serde` feature, the `serde` module (de)serializes integers as strings of digits,
+//! and with the `bytes` feature, the `buf` module parses from and formats into the buffers of the `bytes` crate.
+//! With the `heapless` feature, the `heapless` module formats into the fixed capacity containers of the `heapless` crate.
+//! Fixed-width fields, like dates and times, are parsed with the [`fixed`] module.
+//! The numbers in HTTP header values are parsed with the [`http`] module,
+//! and the integer lines of the Redis protocol with the [`resp`] module.
+//! Network addresses are parsed with the [`net`] module, and color codes with the [`color`] module.
+//! Integer fields of delimited records, like NMEA sentences, are parsed with the [`record`] module,
+//! and the numeric columns of CSV records with the [`csv`] module.
+//! Large inputs are converted on all cores with the `par` module, behind the `rayon` feature.
+//! Whole columns are parsed at once, into values and a validity bitmap, with the [`column`] module.
 //!
 //! To convert from integers, to bytes, use the [`into_ascii`] module.
+//! For bases other than 10, use the [`into_ascii_radix`] module,
+//! and for floats the [`into_ascii_float`] module.
+//!
+//! Code written against [`str::parse`] and `format!` switches to this crate with the wrappers of the [`ascii`] module.
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std`,
+//! and only the parsing modules, the [`error`] module, the [`ascii`] module
+//! and the parts of [`into_ascii`] that format into a buffer, like [`into_ascii::IntoAscii::int_to_bytes`], are available.
+//! With the `alloc` feature, which `std` enables, the parts of [`into_ascii`] that return a `Vec`, like `IntoAscii::itoa`,
+//! are available as well, for `no_std` targets that do have an allocator.
+//!
+//! The `portable_simd` feature, which needs a nightly compiler, converts long runs of digits 16 at a time with `core::simd`.
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+pub mod ascii;
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "bytes")]
+pub mod buf;
+pub mod color;
+#[cfg(feature = "std")]
+pub mod column;
 mod constants;
+pub mod csv;
+#[cfg(feature = "std")]
+pub mod env;
 pub mod error;
+pub mod fixed;
 pub mod from_ascii;
+pub mod from_ascii_delim;
+pub mod from_ascii_fixed;
+pub mod from_ascii_float;
+pub mod from_ascii_radix;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+pub mod http;
+#[cfg(feature = "std")]
+pub mod ids;
 pub mod into_ascii;
+#[cfg(feature = "std")]
+pub mod into_ascii_float;
+#[cfg(feature = "std")]
+pub mod into_ascii_radix;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
+pub mod json_num;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod net;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod record;
+pub mod resp;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod simd;
+pub mod stream;
+pub mod tokenize;
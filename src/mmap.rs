@@ -0,0 +1,338 @@
+//! Parsing the numbers of a memory mapped file, without reading it into a buffer first.
+//!
+//! This module is only available with the `mmap` feature.
+use std::{fs::File, io, ops::Deref, path::Path};
+
+use memmap2::{Mmap, MmapOptions};
+
+use crate::{
+    from_ascii::FromAscii,
+    from_ascii_radix::FromAsciiRadix,
+    iter::{parse_lines, scan_numbers, ParseLines, ParsedIter, ScanNumbers},
+    tokenize::{tokenize, Tokens},
+};
+
+/// A memory mapped file, with the iterators of the [`tokenize`](crate::tokenize) and [`iter`](crate::iter) modules over its bytes.
+///
+/// The whole file is mapped at once. For files that should be walked through a window at a time, see [`FileScanner::chunks`].
+///
+/// # Examples
+/// ```
+/// use byte_num::mmap::FileScanner;
+///
+/// fn main() -> std::io::Result<()> {
+///     let path = std::env::temp_dir().join("byte_num_file_scanner_example");
+///     std::fs::write(&path, "1 2\n3 -4\n")?;
+///
+///     // Safety: nothing else writes to the file while it's mapped.
+///     let scanner = unsafe { FileScanner::open(&path)? };
+///     let sum = scanner.tokenize::<i64>().map(Result::unwrap).sum::<i64>();
+///
+///     assert_eq!(sum, 2);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FileScanner {
+    file: File,
+    map: Mmap,
+}
+
+impl FileScanner {
+    /// Opens the file at `path`, and maps it.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while it's mapped, by this process or any other,
+    /// see [`Mmap::map`].
+    #[inline]
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_file(File::open(path)?)
+    }
+
+    /// Maps `file`, which must be opened for reading.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while it's mapped, by this process or any other,
+    /// see [`Mmap::map`].
+    #[inline]
+    pub unsafe fn from_file(file: File) -> io::Result<Self> {
+        let map = Mmap::map(&file)?;
+        Ok(Self { file, map })
+    }
+
+    /// Returns the bytes of the file.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// Converts every whitespace separated token of the file, see [`tokenize`].
+    #[inline]
+    pub fn tokenize<T: FromAscii>(&self) -> ParsedIter<Tokens<'_>, T> {
+        tokenize(&self.map)
+    }
+
+    /// Converts every line of the file, see [`parse_lines`].
+    #[inline]
+    pub fn lines<T: FromAscii>(&self) -> ParseLines<'_, T> {
+        parse_lines(&self.map)
+    }
+
+    /// Finds and converts the numbers in the file, see [`scan_numbers`].
+    #[inline]
+    pub fn scan_numbers<T: FromAsciiRadix>(&self) -> ScanNumbers<'_, T> {
+        scan_numbers(&self.map)
+    }
+
+    /// Returns an iterator that maps the file one window of about `window` bytes at a time.
+    ///
+    /// Every [`Chunk`] but the last ends right after a `\n`, so no line, and no number, is split over two chunks.
+    /// A window without a newline is doubled until it has one, or reaches the end of the file.
+    ///
+    /// # Panics
+    /// Panics if `window` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use byte_num::mmap::FileScanner;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("byte_num_file_scanner_chunks_example");
+    ///     std::fs::write(&path, "10\n20\n30\n")?;
+    ///
+    ///     // Safety: nothing else writes to the file while it's mapped.
+    ///     let scanner = unsafe { FileScanner::open(&path)? };
+    ///     let mut sum = 0;
+    ///
+    ///     for chunk in scanner.chunks(4) {
+    ///         let chunk = chunk?;
+    ///         assert_eq!(chunk.len(), 3);
+    ///
+    ///         sum += chunk.lines::<u32>().map(Result::unwrap).sum::<u32>();
+    ///     }
+    ///
+    ///     assert_eq!(sum, 60);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn chunks(&self, window: usize) -> Chunks<'_> {
+        assert!(window > 0, "window must be larger than 0");
+
+        Chunks {
+            file: &self.file,
+            offset: 0,
+            len: self.map.len() as u64,
+            window,
+        }
+    }
+}
+
+/// An iterator over windows of a [`FileScanner`], created with [`FileScanner::chunks`].
+#[derive(Debug)]
+pub struct Chunks<'a> {
+    file: &'a File,
+    offset: u64,
+    len: u64,
+    window: usize,
+}
+
+impl<'a> Chunks<'a> {
+    fn map_window(&self, window: usize) -> io::Result<Mmap> {
+        // Safety: the caller of `FileScanner::open` or `FileScanner::from_file` promised
+        // the file isn't modified while the scanner, which `self` borrows, is alive.
+        unsafe {
+            MmapOptions::new()
+                .offset(self.offset)
+                .len(window)
+                .map(self.file)
+        }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = io::Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.len - self.offset;
+        if remaining == 0 {
+            return None;
+        }
+
+        let mut window = self.window;
+
+        let chunk = loop {
+            let window_len = remaining.min(window as u64) as usize;
+
+            let map = match self.map_window(window_len) {
+                Ok(map) => map,
+                Err(e) => {
+                    self.offset = self.len;
+                    return Some(Err(e));
+                }
+            };
+
+            if window_len as u64 == remaining {
+                break Chunk {
+                    map,
+                    len: window_len,
+                    offset: self.offset,
+                };
+            }
+
+            if let Some(newline) = map.iter().rposition(|&b| b == b'\n') {
+                break Chunk {
+                    map,
+                    len: newline + 1,
+                    offset: self.offset,
+                };
+            }
+
+            window = window.saturating_mul(2);
+        };
+
+        self.offset += chunk.len as u64;
+        Some(Ok(chunk))
+    }
+}
+
+/// A window of a memory mapped file, that dereferences to its bytes.
+#[derive(Debug)]
+pub struct Chunk {
+    map: Mmap,
+    len: usize,
+    offset: u64,
+}
+
+impl Chunk {
+    /// Returns the position of the first byte of this chunk in the file.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Converts every whitespace separated token of this chunk, see [`tokenize`].
+    #[inline]
+    pub fn tokenize<T: FromAscii>(&self) -> ParsedIter<Tokens<'_>, T> {
+        tokenize(self)
+    }
+
+    /// Converts every line of this chunk, see [`parse_lines`].
+    /// The lines in errors count from the start of this chunk.
+    #[inline]
+    pub fn lines<T: FromAscii>(&self) -> ParseLines<'_, T> {
+        parse_lines(self)
+    }
+
+    /// Finds and converts the numbers in this chunk, see [`scan_numbers`].
+    /// The ranges count from the start of this chunk.
+    #[inline]
+    pub fn scan_numbers<T: FromAsciiRadix>(&self) -> ScanNumbers<'_, T> {
+        scan_numbers(self)
+    }
+}
+
+impl Deref for Chunk {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.map[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileScanner;
+    use crate::error::{ParseFieldErr, ParseIntErr};
+    use std::{fs, path::PathBuf};
+
+    fn scanner(name: &str, contents: &[u8]) -> (PathBuf, FileScanner) {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, contents).unwrap();
+
+        let scanner = unsafe { FileScanner::open(&path).unwrap() };
+        (path, scanner)
+    }
+
+    #[test]
+    fn file_scanner() {
+        let (path, scanner) = scanner("byte_num_test_file_scanner", b"1\n-2\nx3\n");
+
+        assert_eq!(scanner.as_bytes(), b"1\n-2\nx3\n");
+        assert_eq!(
+            scanner
+                .lines::<i32>()
+                .take(2)
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(vec![1, -2])
+        );
+        assert_eq!(
+            scanner.lines::<i32>().last(),
+            Some(Err(ParseFieldErr {
+                field: 2,
+                error: ParseIntErr::with_byte_at(b'x', 0)
+            }))
+        );
+        assert_eq!(
+            scanner
+                .scan_numbers::<i32>()
+                .map(|(_, n)| n.unwrap())
+                .sum::<i32>(),
+            2
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn chunks() {
+        let contents = (0..1000).map(|n| format!("{}\n", n)).collect::<String>();
+        let (path, scanner) = scanner("byte_num_test_file_scanner_chunks", contents.as_bytes());
+
+        let mut expected_offset = 0;
+        let mut numbers = Vec::new();
+
+        for chunk in scanner.chunks(64) {
+            let chunk = chunk.unwrap();
+
+            assert_eq!(chunk.offset(), expected_offset);
+            assert!(chunk.ends_with(b"\n"));
+            expected_offset += chunk.len() as u64;
+
+            numbers.extend(chunk.tokenize::<u32>().map(Result::unwrap));
+        }
+
+        assert_eq!(expected_offset, contents.len() as u64);
+        assert_eq!(numbers, (0..1000).collect::<Vec<_>>());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn chunks_long_line() {
+        let (path, scanner) = scanner(
+            "byte_num_test_file_scanner_long_line",
+            b"1 2 3 4 5 6\n7 8 9 10 11 12 13",
+        );
+
+        let chunks = scanner
+            .chunks(2)
+            .map(|chunk| chunk.unwrap().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, [&b"1 2 3 4 5 6\n"[..], b"7 8 9 10 11 12 13"]);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn empty_file() {
+        let (path, scanner) = scanner("byte_num_test_file_scanner_empty", b"");
+
+        assert!(scanner.as_bytes().is_empty());
+        assert_eq!(scanner.tokenize::<u8>().count(), 0);
+        assert!(scanner.chunks(16).next().is_none());
+
+        fs::remove_file(path).unwrap();
+    }
+}
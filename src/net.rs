@@ -0,0 +1,171 @@
+//! Parsing of network addresses.
+use crate::{
+    constants::{ASCII_TO_INT_FACTOR, DIGIT_VALUES},
+    error::ParseIntErr,
+};
+
+/// Parses a decimal octet of 1 up to 3 digits from the start of `bytes`, and returns it with its length.
+/// Leading zeros are rejected, so `0` is the only octet starting with a `0`.
+#[inline(always)]
+fn parse_octet(bytes: &[u8], offset: usize) -> Result<(u8, usize), ParseIntErr> {
+    let digit = |idx: usize| match bytes.get(idx) {
+        Some(b @ b'0'..=b'9') => Some(u16::from(b - ASCII_TO_INT_FACTOR)),
+        _ => None,
+    };
+
+    let (value, len) = match (digit(0), digit(1), digit(2)) {
+        (Some(0), Some(_), _) => return Err(ParseIntErr::with_byte_at(b'0', offset)),
+        (Some(_), Some(_), Some(_)) if digit(3).is_some() => {
+            return Err(ParseIntErr::with_byte_at(bytes[3], offset + 3))
+        }
+        (Some(a), Some(b), Some(c)) => (a * 100 + b * 10 + c, 3),
+        (Some(a), Some(b), None) => (a * 10 + b, 2),
+        (Some(a), None, _) => (a, 1),
+        (None, ..) => return Err(ParseIntErr::with_byte_at(bytes[0], offset)),
+    };
+
+    if value > u16::from(u8::MAX) {
+        return Err(ParseIntErr::PosOverflow);
+    }
+
+    Ok((value as u8, len))
+}
+
+/// Parses an IPv4 address in dotted-quad notation, like `192.168.0.1`.
+///
+/// Every octet is 1 up to 3 decimal digits, without leading zeros, so `192.168.000.001` is rejected
+/// rather than being read as octal, like some other parsers do.
+/// Octets above 255 are reported as [`ParseIntErr::PosOverflow`].
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     net,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(net::parse_ipv4(b"192.168.0.1"), Ok([192, 168, 0, 1]));
+///     assert_eq!(net::parse_ipv4(b"10.01.0.1"), Err(ParseIntErr::with_byte_at(b'0', 3)));
+///     assert_eq!(net::parse_ipv4(b"10.0.0.256"), Err(ParseIntErr::PosOverflow));
+/// }
+/// ```
+#[inline]
+pub fn parse_ipv4(bytes: &[u8]) -> Result<[u8; 4], ParseIntErr> {
+    let last = match bytes.len() {
+        0 => return Err(ParseIntErr::Empty),
+        len => len - 1,
+    };
+
+    let mut octets = [0; 4];
+    let mut idx = 0;
+
+    for (n, octet) in octets.iter_mut().enumerate() {
+        if n > 0 {
+            match bytes.get(idx) {
+                Some(b'.') => idx += 1,
+                _ => return Err(ParseIntErr::at_or_last(bytes, idx)),
+            }
+        }
+
+        if idx > last {
+            return Err(ParseIntErr::at_or_last(bytes, idx));
+        }
+
+        let (value, len) = parse_octet(&bytes[idx..], idx)?;
+
+        *octet = value;
+        idx += len;
+    }
+
+    if idx <= last {
+        return Err(ParseIntErr::at_or_last(bytes, idx));
+    }
+
+    Ok(octets)
+}
+
+/// Parses a MAC address of 6 hexadecimal pairs, separated by `separator`, like `aa:bb:cc:dd:ee:ff`.
+/// The separator is usually `:` or `-`, and the digits may be either case.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     net,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(net::parse_mac(b"00:1A:2b:3c:4d:5E", b':'), Ok([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]));
+///     assert_eq!(net::parse_mac(b"00-1a-2b-3c-4d-5e", b'-'), Ok([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]));
+///     assert_eq!(net::parse_mac(b"00-1a-2b-3c-4d-5e", b':'), Err(ParseIntErr::with_byte_at(b'-', 2)));
+/// }
+/// ```
+#[inline]
+pub fn parse_mac(bytes: &[u8], separator: u8) -> Result<[u8; 6], ParseIntErr> {
+    // Every third byte is a separator, the others are hexadecimal digits.
+    let fits = |idx: usize, byte: u8| {
+        if idx % 3 == 2 {
+            byte == separator
+        } else {
+            DIGIT_VALUES[byte as usize] < 16
+        }
+    };
+
+    if bytes.len() == 17 && bytes.iter().enumerate().all(|(idx, byte)| fits(idx, *byte)) {
+        let mut mac = [0; 6];
+
+        for (octet, pair) in mac.iter_mut().zip(bytes.chunks(3)) {
+            *octet = DIGIT_VALUES[pair[0] as usize] << 4 | DIGIT_VALUES[pair[1] as usize];
+        }
+
+        return Ok(mac);
+    }
+
+    // Bytes past the address are reported as the length.
+    Err(ParseIntErr::first_misfit(bytes, 17, |idx, byte| idx >= 17 || fits(idx, byte)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_ipv4, parse_mac, ParseIntErr};
+
+    #[test]
+    fn ipv4() {
+        assert_eq!(parse_ipv4(b"0.0.0.0"), Ok([0, 0, 0, 0]));
+        assert_eq!(parse_ipv4(b"255.255.255.255"), Ok([255; 4]));
+        assert_eq!(parse_ipv4(b"1.22.203.4"), Ok([1, 22, 203, 4]));
+        assert_eq!(parse_ipv4(b"100.10.250.99"), Ok([100, 10, 250, 99]));
+    }
+
+    #[test]
+    fn ipv4_errors() {
+        assert_eq!(parse_ipv4(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_ipv4(b"1.2.3"), Err(ParseIntErr::with_byte_at(b'3', 4)));
+        assert_eq!(parse_ipv4(b"1.2.3."), Err(ParseIntErr::with_byte_at(b'.', 5)));
+        assert_eq!(parse_ipv4(b"1.2.3.4."), Err(ParseIntErr::with_byte_at(b'.', 7)));
+        assert_eq!(parse_ipv4(b"1.2.3.4 "), Err(ParseIntErr::with_byte_at(b' ', 7)));
+        assert_eq!(parse_ipv4(b"1..3.4"), Err(ParseIntErr::with_byte_at(b'.', 2)));
+        assert_eq!(parse_ipv4(b"1.2,3.4"), Err(ParseIntErr::with_byte_at(b',', 3)));
+        assert_eq!(parse_ipv4(b"1.2.3.04"), Err(ParseIntErr::with_byte_at(b'0', 6)));
+        assert_eq!(parse_ipv4(b"1.2.3.1000"), Err(ParseIntErr::with_byte_at(b'0', 9)));
+        assert_eq!(parse_ipv4(b"1.2.3.-4"), Err(ParseIntErr::with_byte_at(b'-', 6)));
+        assert_eq!(parse_ipv4(b"1.2.3.4a"), Err(ParseIntErr::with_byte_at(b'a', 7)));
+        assert_eq!(parse_ipv4(b"1.2.300.4"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(parse_ipv4(b"1.2.256.4"), Err(ParseIntErr::PosOverflow));
+    }
+
+    #[test]
+    fn mac() {
+        assert_eq!(parse_mac(b"ff:ff:ff:ff:ff:ff", b':'), Ok([0xff; 6]));
+        assert_eq!(parse_mac(b"01.23.45.67.89.AB", b'.'), Ok([0x01, 0x23, 0x45, 0x67, 0x89, 0xab]));
+
+        assert_eq!(parse_mac(b"", b':'), Err(ParseIntErr::Empty));
+        assert_eq!(parse_mac(b"ff:ff:ff:ff:ff:f", b':'), Err(ParseIntErr::with_byte_at(b'f', 15)));
+        assert_eq!(parse_mac(b"ff:ff:ff:ff:ff:ff:", b':'), Err(ParseIntErr::with_byte_at(b':', 17)));
+        assert_eq!(parse_mac(b"ff:ff:ff:ff:fg:ff", b':'), Err(ParseIntErr::with_byte_at(b'g', 13)));
+        assert_eq!(parse_mac(b"ff:ff-ff:ff:ff:ff", b':'), Err(ParseIntErr::with_byte_at(b'-', 5)));
+        assert_eq!(parse_mac(b"f:ff:ff:ff:ff:ff", b':'), Err(ParseIntErr::with_byte_at(b':', 1)));
+        assert_eq!(parse_mac(b"ffff.ffff.ffff", b'.'), Err(ParseIntErr::with_byte_at(b'f', 2)));
+    }
+}
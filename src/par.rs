@@ -0,0 +1,203 @@
+//! Converting large inputs on all cores, with [`rayon`].
+//!
+//! The input is split on record boundaries into a few pieces per thread,
+//! every piece is converted on its own, and the results are merged in order.
+//!
+//! This module is only available with the `rayon` feature.
+use core::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::{
+    error::ParseFieldErr,
+    from_ascii::FromAscii,
+    into_ascii::{itoa_batch, IntoAscii},
+    iter::parse_lines,
+};
+
+/// The number of pieces per thread, so a slow piece doesn't leave the other threads idle.
+const PIECES_PER_THREAD: usize = 4;
+
+/// The smallest piece worth sending to another thread.
+const MIN_PIECE_LEN: usize = 64 * 1024;
+
+/// Splits `bytes` into about `pieces` pieces, each of which but the last ends right after a `\n`.
+fn split_lines(bytes: &[u8], pieces: usize) -> Vec<&[u8]> {
+    let target = (bytes.len() / pieces.max(1)).max(MIN_PIECE_LEN);
+    let mut split = Vec::with_capacity(pieces);
+    let mut rest = bytes;
+
+    while rest.len() > target {
+        match rest[target..].iter().position(|b| *b == b'\n') {
+            Some(newline) => {
+                let (piece, tail) = rest.split_at(target + newline + 1);
+                split.push(piece);
+                rest = tail;
+            }
+            None => break,
+        }
+    }
+
+    if !rest.is_empty() {
+        split.push(rest);
+    }
+
+    split
+}
+
+/// Converts every line of `bytes` in parallel, like collecting [`parse_lines`] into a `Result<Vec<T>, _>`.
+///
+/// If several lines fail to parse, the error is the one of the first of them,
+/// with the index of that line counting from the start of `bytes`.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     par::par_parse_lines,
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let input = (0..100_000).map(|n| format!("{}\n", n)).collect::<String>();
+///     let numbers = par_parse_lines::<u32>(input.as_bytes()).unwrap();
+///
+///     assert_eq!(numbers.len(), 100_000);
+///     assert_eq!(numbers[99_999], 99_999);
+///
+///     let input = input.replace("99998\n", "99x98\n");
+///     assert_eq!(
+///         par_parse_lines::<u32>(input.as_bytes()),
+///         Err(ParseFieldErr { field: 99_998, error: ParseIntErr::with_byte_at(b'x', 2) })
+///     );
+/// }
+/// ```
+pub fn par_parse_lines<T: FromAscii + Send>(bytes: &[u8]) -> Result<Vec<T>, ParseFieldErr> {
+    let pieces = split_lines(bytes, rayon::current_num_threads() * PIECES_PER_THREAD);
+
+    let parsed = pieces
+        .par_iter()
+        .map(|piece| parse_lines::<T>(piece).collect::<Result<Vec<_>, _>>())
+        .collect::<Vec<_>>();
+
+    let mut numbers = Vec::with_capacity(
+        parsed
+            .iter()
+            .map(|piece| piece.as_ref().map_or(0, Vec::len))
+            .sum(),
+    );
+
+    for piece in parsed {
+        match piece {
+            Ok(piece) => numbers.extend(piece),
+            Err(ParseFieldErr { field, error }) => {
+                return Err(ParseFieldErr {
+                    field: numbers.len() + field,
+                    error,
+                })
+            }
+        }
+    }
+
+    Ok(numbers)
+}
+
+/// Formats every number of `values` in parallel, like [`itoa_batch`].
+///
+/// # Examples
+/// ```
+/// use byte_num::par::par_itoa_batch;
+///
+/// fn main() {
+///     let mut buffer = b"numbers:".to_vec();
+///     let spans = par_itoa_batch(&[12u32, 3, 456], &mut buffer);
+///
+///     assert_eq!(buffer, b"numbers:123456");
+///     assert_eq!(spans, [8..10, 10..11, 11..14]);
+/// }
+/// ```
+pub fn par_itoa_batch<N: IntoAscii + Copy + Sync>(
+    values: &[N],
+    buffer: &mut Vec<u8>,
+) -> Vec<Range<usize>> {
+    let pieces = rayon::current_num_threads() * PIECES_PER_THREAD;
+    let piece_len = values.len().div_ceil(pieces).max(MIN_PIECE_LEN / 8);
+
+    let formatted = values
+        .par_chunks(piece_len)
+        .map(|piece| {
+            let mut bytes = Vec::new();
+            let spans = itoa_batch(piece, &mut bytes);
+
+            (bytes, spans)
+        })
+        .collect::<Vec<_>>();
+
+    let mut spans = Vec::with_capacity(values.len());
+
+    for (bytes, piece_spans) in formatted {
+        let offset = buffer.len();
+
+        spans.extend(
+            piece_spans
+                .into_iter()
+                .map(|span| span.start + offset..span.end + offset),
+        );
+        buffer.extend_from_slice(&bytes);
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{par_itoa_batch, par_parse_lines, split_lines, MIN_PIECE_LEN};
+    use crate::error::{ParseFieldErr, ParseIntErr};
+
+    #[test]
+    fn split() {
+        let input = "1234567\n".repeat(MIN_PIECE_LEN / 2);
+        let pieces = split_lines(input.as_bytes(), 3);
+
+        assert_eq!(pieces.len(), 3);
+        assert!(pieces[0].ends_with(b"\n") && pieces[0].len() >= MIN_PIECE_LEN);
+        assert_eq!(pieces.concat(), input.as_bytes());
+
+        assert!(split_lines(b"", 4).is_empty());
+        assert_eq!(split_lines(b"1\n2", 4), [b"1\n2"]);
+    }
+
+    #[test]
+    fn parse_lines() {
+        let input = (0..200_000i64)
+            .map(|n| format!("{}\r\n", -n))
+            .collect::<String>();
+        let numbers = par_parse_lines::<i64>(input.as_bytes()).unwrap();
+
+        assert_eq!(numbers, (0..200_000).map(|n| -n).collect::<Vec<_>>());
+
+        let input = input
+            .replace("-150000\r\n", "\r\n")
+            .replace("-190000\r\n", "x\r\n");
+        assert_eq!(
+            par_parse_lines::<i64>(input.as_bytes()),
+            Err(ParseFieldErr {
+                field: 150_000,
+                error: ParseIntErr::Empty
+            })
+        );
+    }
+
+    #[test]
+    fn itoa_batch() {
+        let values = (0..100_000)
+            .map(|n| n * 7919 - 50_000)
+            .collect::<Vec<i32>>();
+        let mut buffer = Vec::new();
+        let spans = par_itoa_batch(&values, &mut buffer);
+
+        assert_eq!(spans.len(), values.len());
+        for (n, span) in values.iter().zip(spans) {
+            assert_eq!(buffer[span], n.to_string().into_bytes()[..]);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Parsing of delimited records where specific fields are integers,
+//! like the comma separated sentences of NMEA GPS receivers.
+//!
+//! A [`Record`] hands out the fields from left to right, and every error tells which field it was found in.
+use crate::{
+    error::{ParseFieldErr, ParseIntErr},
+    from_ascii::FromAscii,
+    from_ascii_radix::FromAsciiRadix,
+};
+
+/// A cursor over the fields of a record, separated by a single delimiter byte.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     record::Record,
+///     error::{ParseFieldErr, ParseIntErr},
+/// };
+///
+/// fn main() {
+///     let mut record = Record::new(b"GPGGA,123519,4807.038,N,01131.000,E,1,08", b',');
+///
+///     assert_eq!(record.field(), Ok(&b"GPGGA"[..]));
+///     assert_eq!(record.int_n::<2, u8>(), Err(ParseFieldErr { field: 1, error: ParseIntErr::with_byte_at(b'3', 2) }));
+///
+///     record.skip(4).unwrap();
+///     assert_eq!(record.int::<u8>(), Ok(1));
+///     assert_eq!(record.int_max::<u8>(2), Ok(8));
+///     assert_eq!(record.int::<u8>(), Err(ParseFieldErr { field: 8, error: ParseIntErr::Empty }));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Record<'a> {
+    rest: Option<&'a [u8]>,
+    delimiter: u8,
+    index: usize,
+}
+
+impl<'a> Record<'a> {
+    /// Creates a cursor at the first field of `bytes`.
+    /// Empty input is a record with a single, empty field.
+    #[inline]
+    pub fn new(bytes: &'a [u8], delimiter: u8) -> Self {
+        Self {
+            rest: Some(bytes),
+            delimiter,
+            index: 0,
+        }
+    }
+
+    /// Returns the index of the next field.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns whether all fields have been handed out.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.rest.is_none()
+    }
+
+    #[inline(always)]
+    fn error(&self, error: ParseIntErr) -> ParseFieldErr {
+        ParseFieldErr {
+            field: self.index,
+            error,
+        }
+    }
+
+    /// Returns the next field as it is, without the delimiter.
+    /// If there are no fields left, the error is [`ParseIntErr::Empty`].
+    #[inline]
+    pub fn field(&mut self) -> Result<&'a [u8], ParseFieldErr> {
+        let bytes = self.rest.ok_or_else(|| self.error(ParseIntErr::Empty))?;
+
+        let field = match bytes.iter().position(|b| *b == self.delimiter) {
+            Some(end) => {
+                self.rest = Some(&bytes[end + 1..]);
+                &bytes[..end]
+            }
+            None => {
+                self.rest = None;
+                bytes
+            }
+        };
+
+        self.index += 1;
+        Ok(field)
+    }
+
+    /// Skips the next `n` fields.
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Result<(), ParseFieldErr> {
+        for _ in 0..n {
+            self.field()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns everything after the previous field as one last field, delimiters included.
+    /// If there are no fields left, the error is [`ParseIntErr::Empty`].
+    #[inline]
+    pub fn rest(&mut self) -> Result<&'a [u8], ParseFieldErr> {
+        let bytes = self.rest.take().ok_or_else(|| self.error(ParseIntErr::Empty))?;
+
+        self.index += 1;
+        Ok(bytes)
+    }
+
+    /// Runs `f` on the field returned by `take`, and adds the index of the field to its error.
+    #[inline(always)]
+    pub(crate) fn parse_with<T>(
+        &mut self,
+        take: fn(&mut Self) -> Result<&'a [u8], ParseFieldErr>,
+        f: impl FnOnce(&[u8]) -> Result<T, ParseIntErr>,
+    ) -> Result<T, ParseFieldErr> {
+        let index = self.index;
+
+        take(self).and_then(|field| {
+            f(field).map_err(|error| ParseFieldErr {
+                field: index,
+                error,
+            })
+        })
+    }
+
+    /// Parses the next field as a decimal integer of any width.
+    /// Unlike [`FromAscii::atoi`], this never wraps around.
+    #[inline]
+    pub fn int<N: FromAsciiRadix>(&mut self) -> Result<N, ParseFieldErr> {
+        self.parse_with(Self::field, |field| N::bytes_to_int_radix(field, 10))
+    }
+
+    /// Parses the next field as a decimal integer of at most `max_width` bytes, including any sign.
+    /// A longer field is reported at its first byte too many.
+    #[inline]
+    pub fn int_max<N: FromAsciiRadix>(&mut self, max_width: usize) -> Result<N, ParseFieldErr> {
+        self.parse_with(Self::field, |field| match field.get(max_width) {
+            Some(&c) => Err(ParseIntErr::with_byte_at(c, max_width)),
+            None => N::bytes_to_int_radix(field, 10),
+        })
+    }
+
+    /// Parses the next field as exactly `W` digits, see [`FromAscii::atoi_n`].
+    /// A longer field is reported at its first byte too many.
+    #[inline]
+    pub fn int_n<const W: usize, N: FromAscii>(&mut self) -> Result<N, ParseFieldErr> {
+        self.parse_with(Self::field, |field| match N::bytes_to_int_n::<W>(field)? {
+            (n, []) => Ok(n),
+            (_, [c, ..]) => Err(ParseIntErr::with_byte_at(*c, W)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseFieldErr, ParseIntErr, Record};
+
+    fn err(field: usize, error: ParseIntErr) -> ParseFieldErr {
+        ParseFieldErr { field, error }
+    }
+
+    #[test]
+    fn fields() {
+        let mut record = Record::new(b"a,,b,", b',');
+
+        assert_eq!(record.field(), Ok(&b"a"[..]));
+        assert_eq!(record.field(), Ok(&b""[..]));
+        assert_eq!(record.index(), 2);
+        assert_eq!(record.field(), Ok(&b"b"[..]));
+        assert!(!record.is_done());
+        assert_eq!(record.field(), Ok(&b""[..]));
+        assert!(record.is_done());
+        assert_eq!(record.field(), Err(err(4, ParseIntErr::Empty)));
+        assert_eq!(record.skip(0), Ok(()));
+
+        let mut record = Record::new(b"", b',');
+        assert_eq!(record.field(), Ok(&b""[..]));
+        assert_eq!(record.skip(1), Err(err(1, ParseIntErr::Empty)));
+
+        let mut record = Record::new(b"1,2,3", b',');
+        assert_eq!(record.field(), Ok(&b"1"[..]));
+        assert_eq!(record.rest(), Ok(&b"2,3"[..]));
+        assert!(record.is_done());
+        assert_eq!(record.rest(), Err(err(2, ParseIntErr::Empty)));
+    }
+
+    #[test]
+    fn ints() {
+        let mut record = Record::new(b"$GPRMC|225446|-12|0007|300|1x|18446744073709551616", b'|');
+
+        record.skip(1).unwrap();
+        assert_eq!(record.int_n::<6, u32>(), Ok(225_446));
+        assert_eq!(record.int_max::<i8>(3), Ok(-12));
+        assert_eq!(record.int_n::<4, u16>(), Ok(7));
+        assert_eq!(record.int_max::<u16>(2), Err(err(4, ParseIntErr::with_byte_at(b'0', 2))));
+        assert_eq!(record.int::<u8>(), Err(err(5, ParseIntErr::with_byte_at(b'x', 1))));
+        assert_eq!(record.int::<u64>(), Err(err(6, ParseIntErr::PosOverflow)));
+        assert_eq!(record.int::<u64>(), Err(err(7, ParseIntErr::Empty)));
+
+        let mut record = Record::new(b"123,12,", b',');
+        assert_eq!(record.int_n::<2, u8>(), Err(err(0, ParseIntErr::with_byte_at(b'3', 2))));
+        assert_eq!(record.int_n::<3, u8>(), Err(err(1, ParseIntErr::with_byte_at(b'2', 1))));
+        assert_eq!(record.int::<u8>(), Err(err(2, ParseIntErr::Empty)));
+    }
+}
@@ -0,0 +1,99 @@
+//! Parsing of the integer lines in the Redis serialization protocol (RESP).
+//!
+//! Integers (`:`), and the lengths of bulk strings (`$`) and arrays (`*`) are all sent
+//! as a type byte, a decimal number, and a `\r\n` terminator.
+use crate::{error::ParseIntErr, from_ascii_radix::FromAsciiRadix};
+
+/// Parses an integer line like `:123\r\n` or `$-1\r\n` from the start of `bytes`,
+/// and returns the number together with the number of bytes taken up by the line, including the `\r\n`.
+///
+/// The line must start with `:`, `$` or `*`, and the number may be negative, as in the null bulk string `$-1\r\n`.
+/// If the input ends before the `\r\n`, and everything up to there is valid, the last byte is reported.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     resp,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     assert_eq!(resp::parse_integer_line(b":1000\r\n"), Ok((1000, 7)));
+///     assert_eq!(resp::parse_integer_line(b"$-1\r\n"), Ok((-1, 5)));
+///     assert_eq!(resp::parse_integer_line(b"*2\r\n$3\r\nfoo\r\n"), Ok((2, 4)));
+///     assert_eq!(resp::parse_integer_line(b":12\n"), Err(ParseIntErr::with_byte_at(b'\n', 3)));
+/// }
+/// ```
+#[inline]
+pub fn parse_integer_line(bytes: &[u8]) -> Result<(i64, usize), ParseIntErr> {
+    match bytes.first() {
+        Some(b':') | Some(b'$') | Some(b'*') => {}
+        Some(&c) => return Err(ParseIntErr::with_byte_at(c, 0)),
+        None => return Err(ParseIntErr::Empty),
+    }
+
+    let end = match bytes.iter().position(|b| *b == b'\r') {
+        Some(end) => end,
+        None => {
+            let last = bytes.len() - 1;
+
+            // A digit or sign followed by the end of the input is only a partial line,
+            // anything else is invalid no matter what comes after it.
+            return Err(match bytes[1..].iter().position(|b| !(b.is_ascii_digit() || *b == b'-')) {
+                Some(idx) => ParseIntErr::with_byte_at(bytes[idx + 1], idx + 1),
+                None => ParseIntErr::with_byte_at(bytes[last], last),
+            });
+        }
+    };
+
+    let digits = &bytes[1..end];
+
+    // A number without any digits is reported at the `\r`.
+    if digits.is_empty() || digits == b"-" {
+        return Err(ParseIntErr::with_byte_at(b'\r', end));
+    }
+
+    let n = i64::bytes_to_int_radix(digits, 10).map_err(|err| match err {
+        ParseIntErr::InvalidDigit([c], position) => ParseIntErr::with_byte_at(c, position + 1),
+        err => err,
+    })?;
+
+    match bytes.get(end + 1) {
+        Some(b'\n') => Ok((n, end + 2)),
+        Some(&c) => Err(ParseIntErr::with_byte_at(c, end + 1)),
+        None => Err(ParseIntErr::with_byte_at(b'\r', end)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_integer_line, ParseIntErr};
+
+    #[test]
+    fn integer_lines() {
+        assert_eq!(parse_integer_line(b":0\r\n"), Ok((0, 4)));
+        assert_eq!(parse_integer_line(b":-9223372036854775808\r\n"), Ok((i64::MIN, 23)));
+        assert_eq!(parse_integer_line(b":9223372036854775807\r\n+OK\r\n"), Ok((i64::MAX, 22)));
+        assert_eq!(parse_integer_line(b"*-1\r\n"), Ok((-1, 5)));
+        assert_eq!(parse_integer_line(b"$0\r\n\r\n"), Ok((0, 4)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse_integer_line(b""), Err(ParseIntErr::Empty));
+        assert_eq!(parse_integer_line(b"+OK\r\n"), Err(ParseIntErr::with_byte_at(b'+', 0)));
+        assert_eq!(parse_integer_line(b":\r\n"), Err(ParseIntErr::with_byte_at(b'\r', 1)));
+        assert_eq!(parse_integer_line(b":-\r\n"), Err(ParseIntErr::with_byte_at(b'\r', 2)));
+        assert_eq!(parse_integer_line(b":+1\r\n"), Err(ParseIntErr::with_byte_at(b'+', 1)));
+        assert_eq!(parse_integer_line(b":1 2\r\n"), Err(ParseIntErr::with_byte_at(b' ', 2)));
+        assert_eq!(parse_integer_line(b":9223372036854775808\r\n"), Err(ParseIntErr::PosOverflow));
+        assert_eq!(parse_integer_line(b":-9223372036854775809\r\n"), Err(ParseIntErr::NegOverflow));
+        assert_eq!(parse_integer_line(b":12\rx"), Err(ParseIntErr::with_byte_at(b'x', 4)));
+
+        // Partial lines.
+        assert_eq!(parse_integer_line(b":"), Err(ParseIntErr::with_byte_at(b':', 0)));
+        assert_eq!(parse_integer_line(b":12"), Err(ParseIntErr::with_byte_at(b'2', 2)));
+        assert_eq!(parse_integer_line(b":12\r"), Err(ParseIntErr::with_byte_at(b'\r', 3)));
+        assert_eq!(parse_integer_line(b":1x"), Err(ParseIntErr::with_byte_at(b'x', 2)));
+    }
+}
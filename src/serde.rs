@@ -0,0 +1,192 @@
+//! Modules for `#[serde(with = "...")]`, that (de)serialize integers as strings of decimal digits.
+//!
+//! JSON has no room for integers above 2<sup>53</sup> in most consumers, so APIs often send them as strings, like `"12345"`.
+//! The modules in here parse such strings straight from the input with [`FromAsciiRadix`],
+//! and format them on the stack with [`IntoAscii`], without an intermediate `String`.
+//! Parsing is exact, and never wraps around.
+//!
+//! Serializers of text formats can format their integers with a [`SerializeInt`] buffer.
+//!
+//! This module is only available with the `serde` feature.
+//!
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Deserialize, Serialize)]
+//! struct Tweet {
+//!     #[serde(with = "byte_num::serde::str_u64")]
+//!     id: u64,
+//!     #[serde(with = "byte_num::serde::str_i32")]
+//!     offset: i32,
+//! }
+//!
+//! fn main() {
+//!     let json = r#"{"id":"18446744073709551615","offset":"-7"}"#;
+//!     let tweet = serde_json::from_str::<Tweet>(json).unwrap();
+//!
+//!     assert_eq!(tweet, Tweet { id: u64::MAX, offset: -7 });
+//!     assert_eq!(serde_json::to_string(&tweet).unwrap(), json);
+//!
+//!     assert!(serde_json::from_str::<Tweet>(r#"{"id":"12x","offset":"0"}"#).is_err());
+//! }
+//! ```
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{self, Deserializer, Visitor},
+    Serializer,
+};
+
+use crate::{
+    from_ascii_radix::FromAsciiRadix,
+    into_ascii::{Buffer, IntoAscii},
+};
+
+/// A buffer on the stack, that formats integers with [`IntoAscii`] for a custom [`Serializer`].
+///
+/// The implementations of `serialize_u64` and friends in a text format's serializer
+/// can delegate the conversion to this buffer, instead of going through `core::fmt`.
+/// Every integer type that implements [`IntoAscii`] can be formatted.
+/// This is the [`Buffer`] of the [`into_ascii`](crate::into_ascii) module, under a name that says what it's for here.
+///
+/// # Examples
+/// ```
+/// use byte_num::serde::SerializeInt;
+///
+/// // A serializer of a line based text format would write the result of `format` to its output.
+/// fn write_u64(out: &mut String, v: u64) {
+///     out.push_str(SerializeInt::new().format(v));
+///     out.push('\n');
+/// }
+///
+/// fn main() {
+///     let mut out = String::new();
+///     write_u64(&mut out, 12345);
+///
+///     let mut buffer = SerializeInt::new();
+///     assert_eq!(buffer.format(-128i8), "-128");
+///     assert_eq!(buffer.format(u64::MAX), "18446744073709551615");
+///     assert_eq!(out, "12345\n");
+/// }
+/// ```
+pub type SerializeInt = Buffer;
+
+/// Serializes `n` as a string of its decimal digits.
+#[inline]
+fn serialize_str<N, S>(n: N, serializer: S) -> Result<S::Ok, S::Error>
+where
+    N: IntoAscii + Copy,
+    S: Serializer,
+{
+    serializer.serialize_str(SerializeInt::new().format(n))
+}
+
+struct StrVisitor<N>(PhantomData<fn() -> N>);
+
+impl<'de, N: FromAsciiRadix> Visitor<'de> for StrVisitor<N> {
+    type Value = N;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string of decimal digits")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<N, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<N, E> {
+        N::bytes_to_int_radix(v, 10).map_err(E::custom)
+    }
+}
+
+/// Deserializes a string of decimal digits, borrowed or not.
+#[inline]
+fn deserialize_str<'de, N, D>(deserializer: D) -> Result<N, D::Error>
+where
+    N: FromAsciiRadix,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor(PhantomData))
+}
+
+macro_rules! str_int {
+    ($($name:ident => $int:ty),*) => {
+        $(
+            #[doc = concat!("(De)serializes a `", stringify!($int), "` as a string of decimal digits.")]
+            pub mod $name {
+                use ::serde::{Deserializer, Serializer};
+
+                /// Serializes `n` as a string of decimal digits.
+                #[inline]
+                pub fn serialize<S: Serializer>(n: &$int, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize_str(*n, serializer)
+                }
+
+                #[doc = concat!("Deserializes a `", stringify!($int), "` from a string of decimal digits.")]
+                #[inline]
+                pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<$int, D::Error> {
+                    super::deserialize_str(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+str_int!(
+    str_u8 => u8,
+    str_u16 => u16,
+    str_u32 => u32,
+    str_u64 => u64,
+    str_usize => usize,
+    str_i8 => i8,
+    str_i16 => i16,
+    str_i32 => i32,
+    str_i64 => i64,
+    str_isize => isize
+);
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Ids {
+        #[serde(with = "super::str_u8")]
+        small: u8,
+        #[serde(with = "super::str_i64")]
+        signed: i64,
+    }
+
+    #[test]
+    fn round_trip() {
+        let ids = Ids {
+            small: 255,
+            signed: i64::MIN,
+        };
+        let json = r#"{"small":"255","signed":"-9223372036854775808"}"#;
+
+        assert_eq!(serde_json::to_string(&ids).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Ids>(json).unwrap(), ids);
+    }
+
+    #[test]
+    fn serialize_int() {
+        let mut buffer = super::SerializeInt::default();
+
+        assert_eq!(buffer.format(0u8), "0");
+        assert_eq!(buffer.format(i64::MIN), "-9223372036854775808");
+        assert_eq!(buffer.format(-1isize), "-1");
+    }
+
+    #[test]
+    fn errors() {
+        let overflow = serde_json::from_str::<Ids>(r#"{"small":"256","signed":"0"}"#).unwrap_err();
+        assert!(overflow.to_string().starts_with("ParseIntErr::PosOverflow"));
+
+        assert!(serde_json::from_str::<Ids>(r#"{"small":"","signed":"0"}"#).is_err());
+        assert!(serde_json::from_str::<Ids>(r#"{"small":1,"signed":"0"}"#).is_err());
+    }
+}
@@ -0,0 +1,507 @@
+//! Kernels that validate and convert 16 digits at once, and validate 32 at once.
+//!
+//! With the `portable_simd` feature, these use the vectors of `core::simd`, which needs a nightly compiler.
+//! Otherwise, x86_64 uses SSE and AVX2 through `core::arch` when the CPU supports them, AArch64 always uses NEON,
+//! WebAssembly uses simd128 when it's enabled at compile time, and everything else handles each block as two halves with the SWAR code of [`crate::from_ascii`].
+use crate::error::ParseIntErr;
+
+#[cfg(feature = "portable_simd")]
+pub(crate) use self::portable::{non_digits16, non_digits32, parse16};
+
+#[cfg(not(feature = "portable_simd"))]
+pub(crate) use self::dispatch::{non_digits16, non_digits32, parse16};
+
+/// Reports the first invalid byte of `block`, given the mask of its invalid bytes.
+#[cold]
+fn invalid_digit(block: [u8; 16], invalid: u16) -> ParseIntErr {
+    ParseIntErr::with_byte(block[invalid.trailing_zeros() as usize])
+}
+
+#[cfg(feature = "portable_simd")]
+mod portable {
+    use core::simd::{prelude::*, simd_swizzle};
+
+    use super::invalid_digit;
+    use crate::error::ParseIntErr;
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    #[inline(always)]
+    pub(crate) fn non_digits16(block: [u8; 16]) -> u16 {
+        let digits = u8x16::from_array(block) - u8x16::splat(b'0');
+
+        digits.simd_gt(u8x16::splat(9)).to_bitmask() as u16
+    }
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    #[inline(always)]
+    pub(crate) fn non_digits32(block: [u8; 32]) -> u32 {
+        let digits = u8x32::from_array(block) - u8x32::splat(b'0');
+
+        digits.simd_gt(u8x32::splat(9)).to_bitmask() as u32
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    // 1) Subtract '0' from every lane, and check none of them went above 9.
+    // 2) Combine neighbouring lanes, halving their number each step: pairs, groups of 4, groups of 8,
+    //    until the two groups of 8 make up the result.
+    #[inline(always)]
+    pub(crate) fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        let invalid = non_digits16(block);
+
+        if invalid != 0 {
+            return Err(invalid_digit(block, invalid));
+        }
+
+        let digits = u8x16::from_array(block) - u8x16::splat(b'0');
+
+        let high = simd_swizzle!(digits, [0, 2, 4, 6, 8, 10, 12, 14]).cast::<u32>();
+        let low = simd_swizzle!(digits, [1, 3, 5, 7, 9, 11, 13, 15]).cast::<u32>();
+        let pairs = high * u32x8::splat(10) + low;
+
+        let high = simd_swizzle!(pairs, [0, 2, 4, 6]);
+        let low = simd_swizzle!(pairs, [1, 3, 5, 7]);
+        let quads = high * u32x4::splat(100) + low;
+
+        let high = simd_swizzle!(quads, [0, 2]);
+        let low = simd_swizzle!(quads, [1, 3]);
+        let [first, second] = (high * u32x2::splat(10_000) + low).to_array();
+
+        Ok(u64::from(first) * 100_000_000 + u64::from(second))
+    }
+}
+
+/// Picks the fastest kernel the target supports, on stable Rust.
+#[cfg(not(feature = "portable_simd"))]
+mod dispatch {
+    use crate::error::ParseIntErr;
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    #[inline(always)]
+    pub(crate) fn non_digits16(block: [u8; 16]) -> u16 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            // Safety: SSE2 is part of x86_64, so every CPU supports it.
+            unsafe { super::x86::non_digits16(block) }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            // Safety: NEON is part of AArch64, so every CPU supports it.
+            unsafe { super::neon::non_digits16(block) }
+        }
+
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        {
+            super::wasm::non_digits16(block)
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            all(target_arch = "wasm32", target_feature = "simd128")
+        )))]
+        {
+            super::swar::non_digits16(block)
+        }
+    }
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    #[inline(always)]
+    pub(crate) fn non_digits32(block: [u8; 32]) -> u32 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if super::x86::has_avx2() {
+                // Safety: the CPU supports AVX2.
+                return unsafe { super::x86::non_digits32(block) };
+            }
+        }
+
+        let mut low = [0; 16];
+        let mut high = [0; 16];
+        low.copy_from_slice(&block[..16]);
+        high.copy_from_slice(&block[16..]);
+
+        u32::from(non_digits16(low)) | u32::from(non_digits16(high)) << 16
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    #[inline(always)]
+    pub(crate) fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        #[cfg(target_arch = "aarch64")]
+        {
+            // Safety: NEON is part of AArch64, so every CPU supports it.
+            unsafe { super::neon::parse16(block) }
+        }
+
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        {
+            super::wasm::parse16(block)
+        }
+
+        #[cfg(not(any(
+            target_arch = "aarch64",
+            all(target_arch = "wasm32", target_feature = "simd128")
+        )))]
+        {
+            #[cfg(target_arch = "x86_64")]
+            {
+                if super::x86::has_ssse3() {
+                    // Safety: the CPU supports SSSE3.
+                    return unsafe { super::x86::parse16(block) };
+                }
+            }
+
+            super::swar::parse16(block)
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable_simd")))]
+mod x86 {
+    use core::arch::x86_64::*;
+
+    use super::invalid_digit;
+    use crate::error::ParseIntErr;
+
+    /// Whether the CPU supports SSSE3, for `_mm_maddubs_epi16`.
+    /// Without `std` there is no runtime detection, so it has to be enabled at compile time.
+    #[inline(always)]
+    pub(crate) fn has_ssse3() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("ssse3")
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "ssse3")
+        }
+    }
+
+    /// Whether the CPU supports AVX2, for validating 32 bytes at once.
+    #[inline(always)]
+    pub(crate) fn has_avx2() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx2")
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avx2")
+        }
+    }
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    // A byte is a digit if subtracting '0' leaves it at most 9, as an unsigned byte.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(crate) unsafe fn non_digits16(block: [u8; 16]) -> u16 {
+        let bytes = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let digits = _mm_sub_epi8(bytes, _mm_set1_epi8(b'0' as i8));
+        let nine = _mm_set1_epi8(9);
+        let valid = _mm_cmpeq_epi8(_mm_max_epu8(digits, nine), nine);
+
+        !(_mm_movemask_epi8(valid) as u16)
+    }
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    // The same check as `non_digits16`, on twice the lanes.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn non_digits32(block: [u8; 32]) -> u32 {
+        let bytes = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+        let digits = _mm256_sub_epi8(bytes, _mm256_set1_epi8(b'0' as i8));
+        let nine = _mm256_set1_epi8(9);
+        let valid = _mm256_cmpeq_epi8(_mm256_max_epu8(digits, nine), nine);
+
+        !(_mm256_movemask_epi8(valid) as u32)
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    // 1) `_mm_maddubs_epi16` multiplies each digit by 10 or 1, and adds neighbours into 8 pairs.
+    // 2) `_mm_madd_epi16` does the same for the pairs with 100 and 1, giving 4 groups of 4 digits.
+    // 3) The groups are packed back into 16 bit lanes, and combined with 10000 and 1 into two groups of 8.
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    pub(crate) unsafe fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        let invalid = non_digits16(block);
+
+        if invalid != 0 {
+            return Err(invalid_digit(block, invalid));
+        }
+
+        let bytes = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let digits = _mm_sub_epi8(bytes, _mm_set1_epi8(b'0' as i8));
+
+        let pairs = _mm_maddubs_epi16(
+            digits,
+            _mm_setr_epi8(10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1),
+        );
+        let quads = _mm_madd_epi16(pairs, _mm_setr_epi16(100, 1, 100, 1, 100, 1, 100, 1));
+        let quads = _mm_packs_epi32(quads, quads);
+        let octs = _mm_madd_epi16(
+            quads,
+            _mm_setr_epi16(10_000, 1, 10_000, 1, 10_000, 1, 10_000, 1),
+        );
+
+        let first = _mm_cvtsi128_si32(octs) as u32;
+        let second = _mm_cvtsi128_si32(_mm_srli_si128(octs, 4)) as u32;
+
+        Ok(u64::from(first) * 100_000_000 + u64::from(second))
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", not(feature = "portable_simd")))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use super::invalid_digit;
+    use crate::error::ParseIntErr;
+
+    /// The bit of each lane in the mask of its half, for gathering the lanes into a `u16`.
+    const LANE_BITS: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    // NEON has no movemask, so every flagged lane keeps only its own bit, and each half is summed into a byte.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    pub(crate) unsafe fn non_digits16(block: [u8; 16]) -> u16 {
+        let digits = vsubq_u8(vld1q_u8(block.as_ptr()), vdupq_n_u8(b'0'));
+        let invalid = vcgtq_u8(digits, vdupq_n_u8(9));
+        let bits = vandq_u8(invalid, vld1q_u8(LANE_BITS.as_ptr()));
+
+        u16::from(vaddv_u8(vget_low_u8(bits))) | u16::from(vaddv_u8(vget_high_u8(bits))) << 8
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    // 1) Split the even and odd lanes, and multiply-accumulate them into 8 pairs of 16 bits.
+    // 2) Do the same for the pairs with 100, giving 4 groups of 4 digits in 32 bits,
+    //    and for those with 10000, giving the two groups of 8.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    pub(crate) unsafe fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        let invalid = non_digits16(block);
+
+        if invalid != 0 {
+            return Err(invalid_digit(block, invalid));
+        }
+
+        let digits = vsubq_u8(vld1q_u8(block.as_ptr()), vdupq_n_u8(b'0'));
+
+        let high = vget_low_u8(vuzp1q_u8(digits, digits));
+        let low = vget_low_u8(vuzp2q_u8(digits, digits));
+        let pairs = vmlal_u8(vmovl_u8(low), high, vdup_n_u8(10));
+
+        let high = vget_low_u16(vuzp1q_u16(pairs, pairs));
+        let low = vget_low_u16(vuzp2q_u16(pairs, pairs));
+        let quads = vmlal_u16(vmovl_u16(low), high, vdup_n_u16(100));
+
+        let high = vget_low_u32(vuzp1q_u32(quads, quads));
+        let low = vget_low_u32(vuzp2q_u32(quads, quads));
+        let octs = vmla_u32(low, high, vdup_n_u32(10_000));
+
+        let first = vget_lane_u32::<0>(octs);
+        let second = vget_lane_u32::<1>(octs);
+
+        Ok(u64::from(first) * 100_000_000 + u64::from(second))
+    }
+}
+
+/// Without runtime detection on WebAssembly, this is only compiled when simd128 is enabled for the whole build.
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "portable_simd")
+))]
+mod wasm {
+    use core::arch::wasm32::*;
+
+    use super::invalid_digit;
+    use crate::error::ParseIntErr;
+
+    #[inline(always)]
+    fn digits(block: &[u8; 16]) -> v128 {
+        // Safety: `block` is 16 bytes, and loads don't need to be aligned.
+        let bytes = unsafe { v128_load(block.as_ptr() as *const v128) };
+
+        u8x16_sub(bytes, u8x16_splat(b'0'))
+    }
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    #[inline(always)]
+    pub(crate) fn non_digits16(block: [u8; 16]) -> u16 {
+        u8x16_bitmask(u8x16_gt(digits(&block), u8x16_splat(9)))
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    // 1) Shuffle the even lanes into the low half and the odd lanes into the high half,
+    //    then widen both halves and combine them into 8 pairs.
+    // 2) Repeat that for the pairs with 100, giving 4 groups of 4 digits.
+    // 3) Line up each group with its neighbour, and combine them with 10000 into the two groups of 8.
+    #[inline(always)]
+    pub(crate) fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        let invalid = non_digits16(block);
+
+        if invalid != 0 {
+            return Err(invalid_digit(block, invalid));
+        }
+
+        let digits = digits(&block);
+
+        let split =
+            u8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15>(digits, digits);
+        let high = u16x8_extend_low_u8x16(split);
+        let low = u16x8_extend_high_u8x16(split);
+        let pairs = u16x8_add(u16x8_mul(high, u16x8_splat(10)), low);
+
+        let split = u16x8_shuffle::<0, 2, 4, 6, 1, 3, 5, 7>(pairs, pairs);
+        let high = u32x4_extend_low_u16x8(split);
+        let low = u32x4_extend_high_u16x8(split);
+        let quads = u32x4_add(u32x4_mul(high, u32x4_splat(100)), low);
+
+        let high = u32x4_shuffle::<0, 2, 0, 2>(quads, quads);
+        let low = u32x4_shuffle::<1, 3, 1, 3>(quads, quads);
+        let octs = u32x4_add(u32x4_mul(high, u32x4_splat(10_000)), low);
+
+        let first = u32x4_extract_lane::<0>(octs);
+        let second = u32x4_extract_lane::<1>(octs);
+
+        Ok(u64::from(first) * 100_000_000 + u64::from(second))
+    }
+}
+
+/// The fallback for targets without vectors, built out of two 8 byte halves.
+#[cfg(not(feature = "portable_simd"))]
+mod swar {
+    use crate::{
+        error::ParseIntErr,
+        from_ascii::{swar_non_digits, swar_parse8},
+    };
+
+    /// Returns a mask with bit `i` set if byte `i` of `block` isn't an ASCII digit.
+    // The flag of each byte is its high bit, which a multiplication gathers into the top byte.
+    #[cfg_attr(
+        any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            all(target_arch = "wasm32", target_feature = "simd128")
+        ),
+        allow(dead_code)
+    )]
+    #[inline(always)]
+    pub(crate) fn non_digits16(block: [u8; 16]) -> u16 {
+        let mask = |half: &[u8]| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(half);
+
+            let flags = swar_non_digits(u64::from_le_bytes(bytes)) >> 7;
+            (flags.wrapping_mul(0x0102_0408_1020_4080) >> 56) as u16
+        };
+
+        mask(&block[..8]) | mask(&block[8..]) << 8
+    }
+
+    /// Converts 16 digits, with the first digit in the first byte.
+    #[cfg_attr(
+        any(
+            target_arch = "aarch64",
+            all(target_arch = "wasm32", target_feature = "simd128")
+        ),
+        allow(dead_code)
+    )]
+    #[inline(always)]
+    pub(crate) fn parse16(block: [u8; 16]) -> Result<u64, ParseIntErr> {
+        let mut high = [0; 8];
+        let mut low = [0; 8];
+        high.copy_from_slice(&block[..8]);
+        low.copy_from_slice(&block[8..]);
+
+        let high = swar_parse8(high)?;
+        let low = swar_parse8(low)?;
+
+        Ok(u64::from(high) * 100_000_000 + u64::from(low))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{non_digits16, non_digits32, parse16};
+    use crate::error::ParseIntErr;
+
+    #[test]
+    fn blocks() {
+        assert_eq!(parse16(*b"1234567890123456"), Ok(1_234_567_890_123_456));
+        assert_eq!(parse16(*b"9999999999999999"), Ok(9_999_999_999_999_999));
+        assert_eq!(parse16(*b"0000000000000000"), Ok(0));
+        assert_eq!(
+            parse16(*b"123456789012345/"),
+            Err(ParseIntErr::with_byte(b'/'))
+        );
+        assert_eq!(
+            parse16(*b"12345:7890123456"),
+            Err(ParseIntErr::with_byte(b':'))
+        );
+    }
+
+    #[test]
+    fn masks() {
+        assert_eq!(non_digits16(*b"0123456789012345"), 0);
+        assert_eq!(non_digits16(*b"a12345678901234\xff"), 0x8001);
+        assert_eq!(non_digits16(*b"0123 56789-12345"), 0b0000_0100_0001_0000);
+    }
+
+    // The kernels load through unaligned loads from copies of the input,
+    // so every offset into a buffer has to give the same result.
+    #[test]
+    fn misaligned() {
+        use crate::from_ascii::{first_non_digit, FromAscii};
+
+        let digits = b"12345678901234567890123456789012345678901234567890";
+        let mut buffer = [b'x'; 128];
+
+        for offset in 0..64 {
+            buffer[offset..offset + digits.len()].copy_from_slice(digits);
+            let input = &buffer[offset..];
+
+            assert_eq!(first_non_digit(input), Some(digits.len()));
+            assert_eq!(u64::atoi(&input[..20]), Ok(12_345_678_901_234_567_890));
+            assert_eq!(u64::atoi(&input[..16]), Ok(1_234_567_890_123_456));
+
+            let mut block = [0; 16];
+            block.copy_from_slice(&input[..16]);
+            assert_eq!(parse16(block), Ok(1_234_567_890_123_456));
+
+            buffer = [b'x'; 128];
+        }
+    }
+
+    #[test]
+    fn masks32() {
+        let mut block = *b"01234567890123456789012345678901";
+        assert_eq!(non_digits32(block), 0);
+
+        block[0] = b'/';
+        block[17] = b':';
+        block[31] = 0xB0;
+        assert_eq!(non_digits32(block), 1 | 1 << 17 | 1 << 31);
+    }
+
+    #[cfg(not(feature = "portable_simd"))]
+    #[test]
+    fn swar_fallback() {
+        use super::swar;
+
+        let blocks = [
+            *b"1234567890123456",
+            *b"a12345678901234\xff",
+            *b"0123 56789-12345",
+            *b"9999999999999999",
+        ];
+
+        for block in &blocks {
+            assert_eq!(swar::non_digits16(*block), non_digits16(*block));
+            assert_eq!(swar::parse16(*block), parse16(*block));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Incremental parsing of numbers that arrive in pieces, like a number split over two network reads.
+use core::{convert::TryFrom, marker::PhantomData};
+
+use crate::{constants::ASCII_TO_INT_FACTOR, error::ParseIntErr};
+
+/// A push parser for a single decimal number, fed one chunk at a time.
+///
+/// The state is just the magnitude so far, the number of bytes seen, and the sign.
+/// Numbers are converted exactly: once the magnitude leaves the u64 range, [`StreamParser::feed`] reports the overflow,
+/// and [`StreamParser::finish`] checks the final number against the range of `T`.
+/// Error positions count every byte fed since the start of the number.
+///
+/// After an error, the parser must be [`StreamParser::reset`] before feeding it another number.
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     stream::StreamParser,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let mut parser = StreamParser::<i32>::new();
+///
+///     parser.feed(b"-12").unwrap();
+///     parser.feed(b"34").unwrap();
+///     assert_eq!(parser.finish(), Ok(-1234));
+///
+///     let mut parser = StreamParser::<u8>::new();
+///     parser.feed(b"2").unwrap();
+///     assert_eq!(parser.feed(b"5x"), Err(ParseIntErr::with_byte_at(b'x', 2)));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct StreamParser<T> {
+    magnitude: u64,
+    len: usize,
+    negative: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: TryFrom<i128>> Default for StreamParser<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: TryFrom<i128>> StreamParser<T> {
+    /// Creates a parser that hasn't seen any bytes yet.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            magnitude: 0,
+            len: 0,
+            negative: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Forgets everything fed so far, to start on a new number.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of bytes fed since the start of the number.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no bytes were fed since the start of the number.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    fn overflow(&self) -> ParseIntErr {
+        if self.negative {
+            ParseIntErr::NegOverflow
+        } else {
+            ParseIntErr::PosOverflow
+        }
+    }
+
+    /// Feeds the next chunk of the number. Every byte must belong to the number:
+    /// a `-` as the very first byte, and digits after that.
+    #[inline]
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), ParseIntErr> {
+        let mut bytes = bytes;
+
+        if self.len == 0 {
+            if let [b'-', rest @ ..] = bytes {
+                self.negative = true;
+                self.len = 1;
+                bytes = rest;
+            }
+        }
+
+        for byte in bytes {
+            let d = byte.wrapping_sub(ASCII_TO_INT_FACTOR);
+
+            if d > 9 {
+                return Err(ParseIntErr::with_byte_at(*byte, self.len));
+            }
+
+            self.magnitude = self
+                .magnitude
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(u64::from(d)))
+                .ok_or_else(|| self.overflow())?;
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number fed so far, checked against the range of `T`.
+    #[inline]
+    pub fn finish(&self) -> Result<T, ParseIntErr> {
+        match (self.len, self.negative) {
+            (0, _) => return Err(ParseIntErr::Empty),
+            (1, true) => return Err(ParseIntErr::with_byte_at(b'-', 0)),
+            _ => {}
+        }
+
+        let magnitude = i128::from(self.magnitude);
+        let n = if self.negative { -magnitude } else { magnitude };
+
+        T::try_from(n).map_err(|_| self.overflow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseIntErr, StreamParser};
+
+    fn parse<T: core::convert::TryFrom<i128>>(chunks: &[&[u8]]) -> Result<T, ParseIntErr> {
+        let mut parser = StreamParser::new();
+
+        for chunk in chunks {
+            parser.feed(chunk)?;
+        }
+
+        parser.finish()
+    }
+
+    #[test]
+    fn chunks() {
+        assert_eq!(parse::<u16>(&[b"12", b"", b"34"]), Ok(1234));
+        assert_eq!(parse::<i8>(&[b"-", b"128"]), Ok(-128));
+        assert_eq!(parse::<i64>(&[b"-9223372036854775", b"808"]), Ok(i64::MIN));
+        assert_eq!(parse::<u64>(&[b"1844674407370955161", b"5"]), Ok(u64::MAX));
+        assert_eq!(parse::<u8>(&[b"-0"]), Ok(0));
+
+        let mut parser = StreamParser::<u32>::new();
+        parser.feed(b"77").unwrap();
+        assert_eq!(parser.len(), 2);
+        parser.reset();
+        assert!(parser.is_empty());
+        parser.feed(b"8").unwrap();
+        assert_eq!(parser.finish(), Ok(8));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse::<u8>(&[]), Err(ParseIntErr::Empty));
+        assert_eq!(parse::<u8>(&[b"", b""]), Err(ParseIntErr::Empty));
+        assert_eq!(parse::<i8>(&[b"-"]), Err(ParseIntErr::with_byte_at(b'-', 0)));
+        assert_eq!(parse::<i8>(&[b"1", b"-2"]), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(parse::<i8>(&[b"-", b"-2"]), Err(ParseIntErr::with_byte_at(b'-', 1)));
+        assert_eq!(parse::<u8>(&[b"25", b"6"]), Err(ParseIntErr::PosOverflow));
+        assert_eq!(parse::<u8>(&[b"-1"]), Err(ParseIntErr::NegOverflow));
+        assert_eq!(parse::<i8>(&[b"-129"]), Err(ParseIntErr::NegOverflow));
+        assert_eq!(parse::<u64>(&[b"1844674407370955161", b"6"]), Err(ParseIntErr::PosOverflow));
+        assert_eq!(parse::<i64>(&[b"-99999999999999999999"]), Err(ParseIntErr::NegOverflow));
+    }
+}
@@ -0,0 +1,150 @@
+//! Splitting of whitespace separated numbers, feeding the tokens straight into [`FromAscii`].
+//!
+//! Runs of non-whitespace are skipped 8 bytes at a time, which matters more than the parsing itself
+//! when the numbers are short.
+use core::{convert::TryInto, iter::FusedIterator};
+
+use crate::{
+    constants::{HIGH, ONES},
+    from_ascii::FromAscii,
+    iter::ParsedIter,
+};
+
+/// Returns the index of the first ASCII whitespace byte in `bytes`, or its length if there is none.
+// Every whitespace byte is at most a space, so a chunk without bytes below 0x21 is skipped whole.
+// Otherwise, the first such byte is exact, but may be some other control byte, so scanning resumes after it.
+#[inline(always)]
+pub(crate) fn find_whitespace(bytes: &[u8]) -> usize {
+    let mut idx = 0;
+
+    while let Some(chunk) = bytes.get(idx..idx + 8) {
+        let v = u64::from_le_bytes(chunk.try_into().unwrap());
+        let found = v.wrapping_sub(ONES * 0x21) & !v & HIGH;
+
+        if found == 0 {
+            idx += 8;
+            continue;
+        }
+
+        idx += found.trailing_zeros() as usize / 8;
+
+        if bytes[idx].is_ascii_whitespace() {
+            return idx;
+        }
+
+        idx += 1;
+    }
+
+    idx + bytes[idx..]
+        .iter()
+        .position(u8::is_ascii_whitespace)
+        .unwrap_or(bytes.len() - idx)
+}
+
+/// An iterator over the tokens between runs of ASCII whitespace, created with [`tokens`].
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    rest: &'a [u8],
+}
+
+/// Splits `bytes` on runs of ASCII whitespace, skipping the whitespace at the start and end.
+///
+/// # Examples
+/// ```
+/// use byte_num::tokenize;
+///
+/// fn main() {
+///     let mut tokens = tokenize::tokens(b"  12\t-3\r\nabc ");
+///
+///     assert_eq!(tokens.next(), Some(&b"12"[..]));
+///     assert_eq!(tokens.next(), Some(&b"-3"[..]));
+///     assert_eq!(tokens.next(), Some(&b"abc"[..]));
+///     assert_eq!(tokens.next(), None);
+/// }
+/// ```
+#[inline]
+pub fn tokens(bytes: &[u8]) -> Tokens<'_> {
+    Tokens { rest: bytes }
+}
+
+/// Converts every whitespace separated token of `bytes` with [`FromAscii::atoi`].
+///
+/// # Examples
+/// ```
+/// use byte_num::{
+///     tokenize,
+///     error::ParseIntErr,
+/// };
+///
+/// fn main() {
+///     let numbers = tokenize::tokenize::<i32>(b"3 -1\n4 1 5\n").collect::<Result<Vec<_>, _>>();
+///     assert_eq!(numbers, Ok(vec![3, -1, 4, 1, 5]));
+///
+///     let mut numbers = tokenize::tokenize::<u8>(b"1 x");
+///     assert_eq!(numbers.nth(1), Some(Err(ParseIntErr::with_byte_at(b'x', 0))));
+/// }
+/// ```
+#[inline]
+pub fn tokenize<T: FromAscii>(bytes: &[u8]) -> ParsedIter<Tokens<'_>, T> {
+    ParsedIter::new(tokens(bytes))
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.rest.iter().position(|b| !b.is_ascii_whitespace())?;
+        let rest = &self.rest[start..];
+        let end = find_whitespace(rest);
+
+        self.rest = &rest[end..];
+        Some(&rest[..end])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every token but the last is followed by whitespace.
+        (0, Some(self.rest.len().div_ceil(2)))
+    }
+}
+
+impl<'a> FusedIterator for Tokens<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_whitespace, tokenize, tokens};
+    use crate::error::ParseIntErr;
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(find_whitespace(b""), 0);
+        assert_eq!(find_whitespace(b"123456789"), 9);
+
+        for &ws in b" \t\n\x0C\r" {
+            for idx in 0..20 {
+                // Control bytes that aren't whitespace come before, and bytes just above a space around it.
+                let mut haystack = [b'!'; 20];
+                haystack[..idx].iter_mut().step_by(3).for_each(|b| *b = 0x01);
+                haystack[idx] = ws;
+
+                assert_eq!(find_whitespace(&haystack), idx);
+            }
+        }
+    }
+
+    #[test]
+    fn tokenized() {
+        assert_eq!(tokens(b"").next(), None);
+        assert_eq!(tokens(b" \n\t ").next(), None);
+        assert_eq!(tokens(b"a\x0bb").collect::<std::vec::Vec<_>>(), [&b"a\x0bb"[..]]);
+        assert_eq!(
+            tokens(b"one two\tthree\r\nfour five six seven").collect::<std::vec::Vec<_>>(),
+            [&b"one"[..], b"two", b"three", b"four", b"five", b"six", b"seven"]
+        );
+
+        let numbers = tokenize::<u64>(b"18446744073709551615 0 -0\n").collect::<std::vec::Vec<_>>();
+        assert_eq!(numbers, [Ok(u64::MAX), Ok(0), Ok(0)]);
+        assert_eq!(tokenize::<i8>(b"1,2").next(), Some(Err(ParseIntErr::with_byte_at(b',', 1))));
+    }
+}